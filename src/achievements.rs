@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use crate::stats::{Counter, Stats};
+
+/// 一项成就，条件只看统计，所以不需要单独保存解锁状态
+pub struct Achievement {
+    pub id: &'static str,
+    // 第二个参数是所有已注册游戏的 id
+    unlocked: fn(&Stats, &[&str]) -> bool,
+}
+
+impl Achievement {
+    pub fn is_unlocked(&self, stats: &Stats, games: &[&str]) -> bool {
+        (self.unlocked)(stats, games)
+    }
+
+    /// 成就名字的翻译键
+    pub fn title_key(&self) -> String {
+        format!("achievement_{}", self.id)
+    }
+
+    /// 解锁条件的翻译键
+    pub fn description_key(&self) -> String {
+        format!("achievement_{}_desc", self.id)
+    }
+}
+
+/// 成就界面按这个顺序列出所有成就
+pub const ACHIEVEMENTS: [Achievement; 10] = [
+    Achievement {
+        id: "explorer",
+        unlocked: |stats, games| {
+            !games.is_empty() && games.iter().all(|id| stats.game(id).played > 0)
+        },
+    },
+    Achievement {
+        id: "tetris",
+        unlocked: |stats, _| stats.counter("tetris", Counter::Tetrises) >= 1,
+    },
+    Achievement {
+        id: "line_collector",
+        unlocked: |stats, _| stats.counter("tetris", Counter::LinesCleared) >= 100,
+    },
+    Achievement {
+        id: "reach_2048",
+        unlocked: |stats, _| stats.counter("2048", Counter::MaxTile) >= 2048,
+    },
+    Achievement {
+        id: "minesweeper_win",
+        unlocked: |stats, _| stats.counter("minesweeper", Counter::Wins) >= 1,
+    },
+    Achievement {
+        id: "minesweeper_expert",
+        unlocked: |stats, _| stats.counter("minesweeper", Counter::HardWins) >= 1,
+    },
+    Achievement {
+        id: "gold_rush",
+        unlocked: |stats, _| stats.counter("goldminer", Counter::GoldCollected) >= 50,
+    },
+    Achievement {
+        id: "hungry_snake",
+        unlocked: |stats, _| stats.counter("snake", Counter::FoodEaten) >= 100,
+    },
+    Achievement {
+        id: "veteran",
+        unlocked: |stats, _| stats.total_played() >= 100,
+    },
+    Achievement {
+        id: "marathon",
+        unlocked: |stats, _| stats.total_time() >= Duration::from_secs(60 * 60),
+    },
+];
+
+/// 记住哪些成就已经解锁，统计变化后找出刚刚解锁的成就
+#[derive(Default)]
+pub struct Achievements {
+    unlocked: Vec<&'static str>,
+    // 已注册游戏的 id，“每个游戏都玩过”按它判断
+    games: Vec<&'static str>,
+}
+
+impl Achievements {
+    /// 已经满足条件的成就直接算作解锁，不会再提示
+    pub fn new(stats: &Stats, games: Vec<&'static str>) -> Self {
+        let mut achievements = Self {
+            unlocked: Vec::new(),
+            games,
+        };
+        achievements.update(stats);
+        achievements
+    }
+
+    /// 统计变化后调用，返回这次新解锁的成就
+    pub fn update(&mut self, stats: &Stats) -> Vec<&'static Achievement> {
+        let unlocked: Vec<&'static Achievement> = ACHIEVEMENTS
+            .iter()
+            .filter(|a| !self.is_unlocked(a.id) && a.is_unlocked(stats, &self.games))
+            .collect();
+        self.unlocked.extend(unlocked.iter().map(|a| a.id));
+        unlocked
+    }
+
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains(&id)
+    }
+
+    pub fn unlocked_count(&self) -> usize {
+        self.unlocked.len()
+    }
+}
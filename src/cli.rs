@@ -0,0 +1,171 @@
+use std::fmt;
+
+use crate::game_manager::CompileLanguage;
+use crate::glyphs::GlyphSet;
+use crate::translation::Language;
+
+pub const USAGE: &str = "\
+Usage: terminal-games [OPTIONS] [GAME]
+
+Arguments:
+  [GAME]                         Start this game directly instead of the main menu
+
+Options:
+  -l, --lang <LANG>              Interface language: en, zh
+  -c, --compile-lang <LANG>      Pretend-compile language: rust, go, cmake
+  -s, --seed <SEED>              Seed for the random number generators
+  -g, --glyphs <SET>             Board symbols: emoji, unicode, ascii (default: detected)
+      --record <FILE>            Record the session's input to FILE
+      --replay <FILE>            Play back a recorded session
+      --speed <SPEED>            Replay speed, e.g. 0.5, 2 or 4 (default 1)
+      --boss-title <TITLE>       Window title while the boss key (B) is active
+      --list                     List the available games and exit
+  -h, --help                     Print help
+  -V, --version                  Print version";
+
+/// 命令行参数
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    pub game: Option<String>,
+    pub language: Option<Language>,
+    pub compile_language: Option<CompileLanguage>,
+    pub seed: Option<u64>,
+    pub glyphs: Option<GlyphSet>,
+    pub record: Option<String>,
+    pub replay: Option<String>,
+    pub speed: Option<f64>,
+    pub boss_title: Option<String>,
+    pub list: bool,
+    pub help: bool,
+    pub version: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    MissingValue(String),
+    InvalidValue {
+        option: String,
+        value: String,
+    },
+    UnknownOption(String),
+    UnexpectedArgument(String),
+    UnknownGame {
+        name: String,
+        available: Vec<&'static str>,
+    },
+    Conflict(&'static str, &'static str),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::MissingValue(option) => write!(f, "missing value for '{}'", option),
+            CliError::InvalidValue { option, value } => {
+                write!(f, "invalid value '{}' for '{}'", value, option)
+            }
+            CliError::UnknownOption(option) => write!(f, "unknown option '{}'", option),
+            CliError::UnexpectedArgument(arg) => write!(f, "unexpected argument '{}'", arg),
+            CliError::UnknownGame { name, available } => write!(
+                f,
+                "unknown game '{}'\navailable games: {}",
+                name,
+                available.join(", ")
+            ),
+            CliError::Conflict(a, b) => write!(f, "'{}' cannot be used with '{}'", a, b),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// 解析命令行参数（不包括程序名），支持 `--lang zh` 和 `--lang=zh` 两种写法
+pub fn parse<I>(args: I) -> Result<Options, CliError>
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().map(Into::into);
+
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => {
+                (name.to_string(), Some(value.to_string()))
+            }
+            _ => (arg.clone(), None),
+        };
+
+        match name.as_str() {
+            "-h" | "--help" => options.help = true,
+            "-V" | "--version" => options.version = true,
+            "--list" => options.list = true,
+            "-l" | "--lang" | "-c" | "--compile-lang" | "-s" | "--seed" | "-g" | "--glyphs"
+            | "--record" | "--replay" | "--speed" | "--boss-title" => {
+                let value = match inline_value.or_else(|| args.next()) {
+                    Some(value) => value,
+                    None => return Err(CliError::MissingValue(name)),
+                };
+                let invalid = || CliError::InvalidValue {
+                    option: name.clone(),
+                    value: value.clone(),
+                };
+                match name.as_str() {
+                    "-l" | "--lang" => {
+                        options.language = Some(parse_language(&value).ok_or_else(invalid)?)
+                    }
+                    "-c" | "--compile-lang" => {
+                        options.compile_language =
+                            Some(parse_compile_language(&value).ok_or_else(invalid)?)
+                    }
+                    "-g" | "--glyphs" => {
+                        options.glyphs = Some(GlyphSet::from_id(&value).ok_or_else(invalid)?)
+                    }
+                    "--record" => options.record = Some(value),
+                    "--replay" => options.replay = Some(value),
+                    "--boss-title" => options.boss_title = Some(value),
+                    "--speed" => {
+                        let speed = value.parse().ok().filter(|&speed: &f64| speed > 0.0);
+                        options.speed = Some(speed.ok_or_else(invalid)?)
+                    }
+                    _ => options.seed = Some(value.parse().map_err(|_| invalid())?),
+                }
+            }
+            _ if name.starts_with('-') && name.len() > 1 => {
+                return Err(CliError::UnknownOption(name))
+            }
+            _ if options.game.is_none() => options.game = Some(arg),
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+
+    // 回放时种子和游戏都由录像决定
+    if options.replay.is_some() {
+        if options.record.is_some() {
+            return Err(CliError::Conflict("--replay", "--record"));
+        }
+        if options.seed.is_some() {
+            return Err(CliError::Conflict("--replay", "--seed"));
+        }
+        if options.game.is_some() {
+            return Err(CliError::Conflict("--replay", "[GAME]"));
+        }
+    }
+    Ok(options)
+}
+
+pub(crate) fn parse_language(value: &str) -> Option<Language> {
+    match value.to_lowercase().as_str() {
+        "en" | "english" => Some(Language::English),
+        "zh" | "cn" | "chinese" => Some(Language::Chinese),
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_compile_language(value: &str) -> Option<CompileLanguage> {
+    match value.to_lowercase().as_str() {
+        "rust" => Some(CompileLanguage::Rust),
+        "go" => Some(CompileLanguage::Go),
+        "cmake" => Some(CompileLanguage::CMake),
+        _ => None,
+    }
+}
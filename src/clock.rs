@@ -0,0 +1,46 @@
+use std::time::{Duration, Instant};
+
+/// 固定步长的模拟时钟
+///
+/// 主循环每次只询问“到现在为止应该执行几次 update”，每次 update 都推进同样的
+/// `step`，所以按键多少、终端快慢都不会影响游戏速度。
+pub struct FixedClock {
+    step: Duration,
+    last_tick: Instant,
+}
+
+impl FixedClock {
+    /// 落后太多时最多补几步，避免卡顿后一次性快进
+    const MAX_CATCH_UP: u32 = 10;
+
+    pub fn new(step: Duration) -> Self {
+        Self {
+            step,
+            last_tick: Instant::now(),
+        }
+    }
+
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// 距离下一次 update 还有多久，用作 `event::poll` 的超时
+    pub fn time_until_tick(&self) -> Duration {
+        self.step.saturating_sub(self.last_tick.elapsed())
+    }
+
+    /// 返回应该执行的每次 update 对应的时刻，并把时钟推进相应的步数；
+    /// 主循环据此把这段时间里到达的输入排在正确的 update 之前
+    pub fn tick_times(&mut self) -> Vec<Instant> {
+        let elapsed = self.last_tick.elapsed();
+        let due = (elapsed.as_nanos() / self.step.as_nanos()) as u32;
+        if due > Self::MAX_CATCH_UP {
+            // 跳过的时间里到达的输入都排在第一次 update 之前
+            self.last_tick = Instant::now();
+            return vec![self.last_tick; Self::MAX_CATCH_UP as usize];
+        }
+        let start = self.last_tick;
+        self.last_tick += self.step * due;
+        (1..=due).map(|n| start + self.step * n).collect()
+    }
+}
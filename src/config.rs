@@ -0,0 +1,412 @@
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crossterm::event::KeyCode;
+
+use crate::{
+    cli,
+    game_manager::CompileLanguage,
+    glyphs::GlyphSet,
+    keymap::{self, Action, Keymap},
+    theme::ThemeName,
+    translation::Language,
+    Difficulty,
+};
+
+const FILE_HEADER: &str = "# terminal-games config";
+
+// 可以出现的段落，空字符串表示文件开头的顶层设置
+const SECTIONS: [&str; 3] = ["", "difficulty", "keys"];
+
+/// 设置文件中的错误，行号从 1 开始
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    /// 文件存在但是无法读取
+    Io(String),
+    /// 既不是 `key = "value"` 也不是 `[section]`
+    Syntax {
+        line: usize,
+    },
+    UnknownSection {
+        line: usize,
+        name: String,
+    },
+    UnknownKey {
+        line: usize,
+        key: String,
+    },
+    InvalidValue {
+        line: usize,
+        key: String,
+        value: String,
+        expected: &'static str,
+    },
+    /// `[keys]` 中无法识别的按键名字
+    InvalidKey {
+        line: usize,
+        action: String,
+        key: String,
+    },
+    /// `q` 和 `b` 由主菜单和老板键使用
+    ReservedKey {
+        line: usize,
+        key: String,
+    },
+    /// `[difficulty]` 中的游戏没有注册
+    UnknownGame {
+        name: String,
+        available: Vec<&'static str>,
+    },
+    /// 同一个游戏中的两个操作绑定了同一个键
+    KeyConflict {
+        key: String,
+        first: &'static str,
+        second: &'static str,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "cannot read the file: {}", err),
+            ConfigError::Syntax { line } => {
+                write!(
+                    f,
+                    "line {}: expected `key = \"value\"` or `[section]`",
+                    line
+                )
+            }
+            ConfigError::UnknownSection { line, name } => {
+                write!(f, "line {}: unknown section [{}]", line, name)
+            }
+            ConfigError::UnknownKey { line, key } => {
+                write!(f, "line {}: unknown setting '{}'", line, key)
+            }
+            ConfigError::InvalidValue {
+                line,
+                key,
+                value,
+                expected,
+            } => write!(
+                f,
+                "line {}: invalid value '{}' for '{}' (expected one of: {})",
+                line, value, key, expected
+            ),
+            ConfigError::InvalidKey { line, action, key } => write!(
+                f,
+                "line {}: unknown key '{}' for '{}' (use names like left, a, space, enter, esc or f1)",
+                line, key, action
+            ),
+            ConfigError::ReservedKey { line, key } => write!(
+                f,
+                "line {}: '{}' cannot be bound, q, b and ? are reserved for the menu, the boss key and help",
+                line, key
+            ),
+            ConfigError::KeyConflict { key, first, second } => write!(
+                f,
+                "key '{}' is bound to both '{}' and '{}' in [keys]",
+                key, first, second
+            ),
+            ConfigError::UnknownGame { name, available } => write!(
+                f,
+                "unknown game '{}' in [difficulty] (available games: {})",
+                name,
+                available.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// 设置界面中修改的用户设置，保存在 `<config_dir>/config.toml`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    /// 界面语言，没有设置时按系统语言选择
+    pub language: Option<Language>,
+    pub compile_language: Option<CompileLanguage>,
+    /// 配色，没有设置时按 `NO_COLOR` 选择
+    pub theme: Option<ThemeName>,
+    /// 棋盘上使用的字符，没有设置时按终端猜测
+    pub glyphs: Option<GlyphSet>,
+    // 按游戏 id 保存的难度，没有列出的游戏使用默认难度
+    difficulties: BTreeMap<String, Difficulty>,
+    // 修改过的按键，没有列出的操作使用默认按键
+    keys: BTreeMap<Action, Vec<KeyCode>>,
+    path: Option<PathBuf>,
+}
+
+impl Config {
+    /// 从默认位置读取设置，文件不存在时使用默认设置
+    pub fn load() -> Result<Self, Vec<ConfigError>> {
+        match config_path() {
+            Some(path) => Self::load_from(path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// 不关联文件的副本，`save` 时什么也不做
+    pub fn without_file(&self) -> Self {
+        Self {
+            path: None,
+            ..self.clone()
+        }
+    }
+
+    /// 读取指定的文件，文件中的所有错误一起返回
+    pub fn load_from(path: impl Into<PathBuf>) -> Result<Self, Vec<ConfigError>> {
+        let path = path.into();
+        let mut config = match fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(vec![ConfigError::Io(err.to_string())]),
+        };
+        config.path = Some(path);
+        Ok(config)
+    }
+
+    pub fn difficulty(&self, game_id: &str) -> Difficulty {
+        self.difficulties.get(game_id).copied().unwrap_or_default()
+    }
+
+    pub fn set_difficulty(&mut self, game_id: &str, difficulty: Difficulty) {
+        self.difficulties.insert(game_id.to_string(), difficulty);
+    }
+
+    /// 默认按键加上设置文件中修改过的按键
+    pub fn keymap(&self) -> Keymap {
+        let mut keymap = Keymap::new();
+        for (&action, keys) in &self.keys {
+            keymap.bind(action, keys.clone());
+        }
+        keymap
+    }
+
+    pub fn set_keys(&mut self, action: Action, keys: Vec<KeyCode>) {
+        self.keys.insert(action, keys);
+    }
+
+    /// 设置文件中修改过至少一个操作的按键
+    pub fn has_custom_keys(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// 恢复操作的默认按键
+    pub fn reset_keys(&mut self, action: Action) {
+        self.keys.remove(&action);
+    }
+
+    /// 设置了难度的游戏 id，GameManager 用它检查是否有拼错的游戏
+    pub fn difficulty_games(&self) -> impl Iterator<Item = &str> {
+        self.difficulties.keys().map(String::as_str)
+    }
+
+    /// 解析 TOML 格式的设置：顶层的语言、配色和字符集设置、`[difficulty]` 表和 `[keys]` 表
+    ///
+    /// 只支持 `key = "value"` 和 `[section]` 两种行，以及 `#` 开头的注释。
+    pub fn parse(text: &str) -> Result<Self, Vec<ConfigError>> {
+        let mut config = Self::default();
+        let mut errors = Vec::new();
+        let mut section = String::new();
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = name.trim().to_string();
+                if !SECTIONS.contains(&section.as_str()) {
+                    errors.push(ConfigError::UnknownSection {
+                        line: line_number,
+                        name: section.clone(),
+                    });
+                }
+                continue;
+            }
+            let (key, value) = match parse_entry(line) {
+                Some(entry) => entry,
+                None => {
+                    errors.push(ConfigError::Syntax { line: line_number });
+                    continue;
+                }
+            };
+            if let Err(err) = config.set(&section, key, value, line_number) {
+                errors.push(err);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(errors)
+        }
+    }
+
+    // 应用一行设置，未知段落中的行已经报告过，这里直接跳过
+    fn set(
+        &mut self,
+        section: &str,
+        key: &str,
+        value: &str,
+        line: usize,
+    ) -> Result<(), ConfigError> {
+        let invalid = |expected| ConfigError::InvalidValue {
+            line,
+            key: key.to_string(),
+            value: value.to_string(),
+            expected,
+        };
+        match (section, key) {
+            ("", "language") => {
+                let language = cli::parse_language(value).ok_or_else(|| invalid("en, zh"))?;
+                self.language = Some(language);
+            }
+            ("", "compile_language") => {
+                let lang =
+                    cli::parse_compile_language(value).ok_or_else(|| invalid("rust, go, cmake"))?;
+                self.compile_language = Some(lang);
+            }
+            ("", "theme") => {
+                let name = ThemeName::from_id(value)
+                    .ok_or_else(|| invalid("default, high-contrast, monochrome, solarized"))?;
+                self.theme = Some(name);
+            }
+            ("", "glyphs") => {
+                let set =
+                    GlyphSet::from_id(value).ok_or_else(|| invalid("emoji, unicode, ascii"))?;
+                self.glyphs = Some(set);
+            }
+            ("difficulty", id) => {
+                let difficulty =
+                    Difficulty::from_id(value).ok_or_else(|| invalid("easy, normal, hard"))?;
+                self.set_difficulty(id, difficulty);
+            }
+            ("keys", id) => {
+                let action = Action::from_id(id).ok_or_else(|| ConfigError::UnknownKey {
+                    line,
+                    key: key.to_string(),
+                })?;
+                let keys = parse_keys(value, line, id)?;
+                self.set_keys(action, keys);
+            }
+            (section, _) if !SECTIONS.contains(&section) => {}
+            _ => {
+                return Err(ConfigError::UnknownKey {
+                    line,
+                    key: key.to_string(),
+                })
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", FILE_HEADER);
+        if let Some(language) = self.language {
+            let id = match language {
+                Language::English => "en",
+                Language::Chinese => "zh",
+            };
+            text.push_str(&format!("language = \"{}\"\n", id));
+        }
+        if let Some(lang) = self.compile_language {
+            let id = match lang {
+                CompileLanguage::Rust => "rust",
+                CompileLanguage::Go => "go",
+                CompileLanguage::CMake => "cmake",
+            };
+            text.push_str(&format!("compile_language = \"{}\"\n", id));
+        }
+        if let Some(name) = self.theme {
+            text.push_str(&format!("theme = \"{}\"\n", name.id()));
+        }
+        if let Some(set) = self.glyphs {
+            text.push_str(&format!("glyphs = \"{}\"\n", set.id()));
+        }
+        if !self.difficulties.is_empty() {
+            text.push_str("\n[difficulty]\n");
+            for (id, difficulty) in &self.difficulties {
+                text.push_str(&format!("{} = \"{}\"\n", id, difficulty.id()));
+            }
+        }
+        if !self.keys.is_empty() {
+            text.push_str("\n[keys]\n");
+            for (action, keys) in &self.keys {
+                let names: Vec<_> = keys
+                    .iter()
+                    .map(|&code| keymap::format_key_code(code))
+                    .collect();
+                text.push_str(&format!("{} = \"{}\"\n", action.id(), names.join(", ")));
+            }
+        }
+        text
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // 与排行榜一样先写临时文件再改名
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, self.to_text())?;
+        fs::rename(tmp, path)
+    }
+}
+
+/// 保存设置的目录，通常是 `~/.config/terminal-games`
+pub fn config_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+    Some(base.join("terminal-games"))
+}
+
+/// 默认的设置文件 `<config_dir>/config.toml`
+pub fn config_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("config.toml"))
+}
+
+// 按键列表用逗号分隔，例如 `"left, a"`；空字符串表示不绑定按键
+fn parse_keys(value: &str, line: usize, action: &str) -> Result<Vec<KeyCode>, ConfigError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| match keymap::parse_key_code(name) {
+            Some(code) if keymap::is_reserved(code) => Err(ConfigError::ReservedKey {
+                line,
+                key: name.to_string(),
+            }),
+            Some(code) => Ok(code),
+            None => Err(ConfigError::InvalidKey {
+                line,
+                action: action.to_string(),
+                key: name.to_string(),
+            }),
+        })
+        .collect()
+}
+
+// 拆开 `key = "value"`，值必须带引号，后面可以跟 `#` 注释
+fn parse_entry(line: &str) -> Option<(&str, &str)> {
+    let (key, rest) = line.split_once('=')?;
+    let key = key.trim();
+    let rest = rest.trim().strip_prefix('"')?;
+    let (value, tail) = rest.split_once('"')?;
+    let tail = tail.trim();
+    let valid_key = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    (valid_key && (tail.is_empty() || tail.starts_with('#'))).then_some((key, value))
+}
@@ -0,0 +1,179 @@
+use lazy_static::lazy_static;
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    collections::VecDeque,
+    env, fs, io,
+    panic::{self, PanicHookInfo},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::game_manager::GameManager;
+use crate::replay::{format_event, InputEvent};
+use crate::scores;
+
+/// 崩溃报告中保留的最近输入条数
+pub const RECENT_INPUTS: usize = 32;
+
+const FILE_HEADER: &str = "# terminal-games crash report";
+
+/// 崩溃时需要知道的运行状态：当前的游戏、随机数种子和最近的输入
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CrashContext {
+    /// 正在玩的游戏的 id，在主菜单或设置界面时为 None
+    pub game: Option<&'static str>,
+    pub seed: Option<u64>,
+    inputs: VecDeque<InputEvent>,
+}
+
+impl CrashContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记下一次输入，只保留最近的 `RECENT_INPUTS` 条
+    pub fn record_input(&mut self, event: InputEvent) {
+        if self.inputs.len() == RECENT_INPUTS {
+            self.inputs.pop_front();
+        }
+        self.inputs.push_back(event);
+    }
+
+    /// 从管理器读取当前的游戏和种子
+    pub fn track(&mut self, manager: &GameManager) {
+        self.game = manager.current_game();
+        self.seed = manager.seed();
+    }
+
+    /// 最近的输入，按时间先后排列
+    pub fn inputs(&self) -> impl Iterator<Item = &InputEvent> {
+        self.inputs.iter()
+    }
+}
+
+lazy_static! {
+    // panic hook 中只能通过全局状态取得主循环的信息
+    static ref CONTEXT: Mutex<CrashContext> = Mutex::new(CrashContext::new());
+}
+
+/// 主循环每收到一个输入调用一次
+pub fn record_input(event: InputEvent) {
+    if let Ok(mut context) = CONTEXT.lock() {
+        context.record_input(event);
+    }
+}
+
+/// 主循环处理完输入后调用，更新崩溃报告中的游戏和种子
+pub fn track(manager: &GameManager) {
+    if let Ok(mut context) = CONTEXT.lock() {
+        context.track(manager);
+    }
+}
+
+/// 一次 panic 的报告，写成文本文件以便附在问题报告中
+#[derive(Clone, Debug)]
+pub struct CrashReport {
+    pub message: String,
+    /// panic 发生的源代码位置 `文件:行:列`
+    pub location: Option<String>,
+    pub backtrace: String,
+    pub context: CrashContext,
+    pub timestamp: u64,
+}
+
+impl CrashReport {
+    pub fn new(message: String, location: Option<String>, context: CrashContext) -> Self {
+        Self {
+            message,
+            location,
+            backtrace: Backtrace::force_capture().to_string(),
+            context,
+            timestamp: scores::now(),
+        }
+    }
+
+    fn from_panic(info: &PanicHookInfo) -> Self {
+        // 崩溃可能发生在持有锁的时候，这时放弃上下文而不是再次 panic
+        let context = match CONTEXT.try_lock() {
+            Ok(context) => context.clone(),
+            Err(_) => CrashContext::new(),
+        };
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+        Self::new(panic_message(info.payload()), location, context)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", FILE_HEADER);
+        text.push_str(&format!("version: {}\n", env!("CARGO_PKG_VERSION")));
+        text.push_str(&format!(
+            "time: {} ({})\n",
+            scores::format_date(self.timestamp),
+            self.timestamp
+        ));
+        text.push_str(&format!("message: {}\n", self.message));
+        if let Some(location) = &self.location {
+            text.push_str(&format!("location: {}\n", location));
+        }
+        text.push_str(&format!(
+            "game: {}\n",
+            self.context.game.unwrap_or("(menu)")
+        ));
+        match self.context.seed {
+            Some(seed) => text.push_str(&format!("seed: {}\n", seed)),
+            None => text.push_str("seed: (random)\n"),
+        }
+        // 与录像文件中的格式相同，可以直接拿来重现
+        text.push_str("\nrecent inputs (oldest first):\n");
+        for event in self.context.inputs() {
+            text.push_str(&format_event(event));
+            text.push('\n');
+        }
+        text.push_str("\nbacktrace:\n");
+        text.push_str(&self.backtrace);
+        text
+    }
+
+    /// 写入 `dir` 下以时间命名的文件，返回文件路径
+    pub fn save(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("crash-{}.txt", self.timestamp));
+        fs::write(&path, self.to_text())?;
+        Ok(path)
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "(no message)".to_string()
+    }
+}
+
+/// 崩溃报告所在的目录：数据目录下的 `crashes`，没有数据目录时使用临时目录
+pub fn crash_dir() -> PathBuf {
+    scores::data_dir()
+        .map(|dir| dir.join("crashes"))
+        .unwrap_or_else(env::temp_dir)
+}
+
+/// 安装 panic hook：先调用 `restore` 恢复终端，再写崩溃报告，
+/// 最后交给原来的 hook 打印 panic 信息
+pub fn install_hook(restore: fn()) {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore();
+        let report = CrashReport::from_panic(info);
+        match report.save(crash_dir()) {
+            Ok(path) => eprintln!("crash report written to '{}'", path.display()),
+            Err(err) => eprintln!("failed to write crash report: {}", err),
+        }
+        default_hook(info);
+    }));
+}
@@ -1,193 +1,1207 @@
-use crate::games::{
-    goldminer::GoldMiner, minesweeper::MineSweeper, snake::Snake, tetris::Tetris,
-    twenty_forty_eight::TwentyFortyEight,
-};
+use crate::achievements::{Achievement, Achievements, ACHIEVEMENTS};
+use crate::config::{self, Config, ConfigError};
+use crate::games::compiling::Compiling;
+use crate::games::registry::{GameDescriptor, GameRegistry};
+use crate::glyphs::{GlyphSet, Glyphs};
+use crate::keymap::{self, Action, Keymap};
+use crate::saves::SaveStore;
+use crate::scores::{self, HighScores, ScoreEntry};
+use crate::stats::{self, Stats};
+use crate::theme::{Theme, ThemeName};
 use crate::translation::{Language, Translations};
-use crate::Game;
-use crossterm::event::KeyCode;
-use std::io::Stdout;
-use tui::backend::CrosstermBackend;
+use crate::{Difficulty, Game, GameRequest};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use std::{cell::OnceCell, collections::VecDeque, io, time::Duration};
 use tui::{
+    backend::Backend,
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
     Frame,
 };
 
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum CompileLanguage {
     Rust,
     Go,
     CMake,
 }
 
-// 定义所有游戏的枚举
+// 当前显示的界面：主菜单、设置或者第几个已注册的游戏
 #[derive(PartialEq, Clone, Copy)]
-pub enum GameType {
+pub enum Screen {
     MainMenu,
-    GoldMiner,
-    Tetris,
-    Snake,
-    TwentyFortyEight,
-    MineSweeper,
+    Settings,
+    Game(usize),
+}
+
+// 设置界面中的一行
+#[derive(PartialEq, Clone, Copy)]
+enum Setting {
+    Language,
+    CompileLanguage,
+    // 第几个游戏的难度
+    Difficulty(usize),
+    Glyphs,
+    Theme,
+    // 打开按键设置页面
+    Keys,
 }
 
-// 移除 GameEnum，直接使用 Box<dyn Game>
+// 成就解锁提示显示的时间
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+// 右上角的成就解锁提示，多个提示依次显示
+struct Toast {
+    achievement: &'static Achievement,
+    remaining: Duration,
+}
+
+const LANGUAGES: [Language; 2] = [Language::English, Language::Chinese];
+
+const COMPILE_LANGUAGES: [CompileLanguage; 3] = [
+    CompileLanguage::Rust,
+    CompileLanguage::Go,
+    CompileLanguage::CMake,
+];
+
 pub struct GameInfo {
-    game_type: GameType,
-    title_key: &'static str,
-    game: Box<dyn Game>,
+    descriptor: GameDescriptor,
+    // 第一次进入游戏（或者需要它的信息）时才按当前的设置创建
+    game: OnceCell<Box<dyn Game>>,
 }
 
 pub struct GameManager {
-    pub state: GameType,
-    games: Vec<GameInfo>, // 改为 Vec 而不是固定大小数组
+    pub state: Screen,
+    games: Vec<GameInfo>,
     selected_game: usize,
     translations: Translations,
-    selecting_language: bool,
     compile_language: CompileLanguage,
-    selecting_compile_language: bool,
+    config: Config,
+    config_errors: Vec<ConfigError>,
+    keymap: Keymap,
+    theme: Theme,
+    glyphs: Glyphs,
+    selected_setting: usize,
+    // 设置界面中打开的按键设置页面
+    showing_keys: bool,
+    selected_action: usize,
+    // 正在等待玩家按下新的按键
+    capturing_key: bool,
+    // 关闭设置界面后回到的界面
+    settings_return: Screen,
+    // 上一次渲染的区域，用于换算鼠标坐标
+    last_area: Rect,
+    // 最近一次收到的终端大小，还没有收到时不检查游戏的最小尺寸
+    terminal_size: Option<(u16, u16)>,
+    should_quit: bool,
+    high_scores: HighScores,
+    showing_high_scores: bool,
+    stats: Stats,
+    achievements: Achievements,
+    toasts: VecDeque<Toast>,
+    // 主菜单中打开的成就和统计页面
+    showing_achievements: bool,
+    saves: SaveStore,
+    seed: Option<u64>,
+    compiling: Compiling,
+    // 老板键打开的假装编译界面，盖住当前的菜单或者游戏
+    hiding: bool,
+    // `?` 打开的帮助，盖在当前的游戏或者主菜单上
+    showing_help: bool,
+    // 启动时盖在最上面的设置文件错误，按任意键关闭
+    showing_config_errors: bool,
 }
 
 impl GameManager {
+    /// 读取默认位置的设置、排行榜和存档
+    ///
+    /// 设置文件无法解析时整个文件都不生效，使用默认设置并且不会覆盖这个文件；
+    /// 按键冲突和 `[difficulty]` 中未知的游戏不影响其它设置。
+    /// 两种错误都可以从 `config_errors` 取得，并在启动后显示在界面上，按任意键关闭。
     pub fn new() -> Self {
-        // 定义所有游戏
-        let games = vec![
-            GameInfo {
-                game_type: GameType::GoldMiner,
-                title_key: "goldminer.goldminer_title",
-                game: Box::new(GoldMiner::new()),
-            },
-            GameInfo {
-                game_type: GameType::Tetris,
-                title_key: "tetris.tetris_title",
-                game: Box::new(Tetris::new()),
-            },
-            GameInfo {
-                game_type: GameType::Snake,
-                title_key: "snake.snake_title",
-                game: Box::new(Snake::new()),
-            },
-            GameInfo {
-                game_type: GameType::TwentyFortyEight,
-                title_key: "2048.twenty_forty_eight_title",
-                game: Box::new(TwentyFortyEight::new()),
-            },
-            GameInfo {
-                game_type: GameType::MineSweeper,
-                title_key: "minesweeper.minesweeper_title",
-                game: Box::new(MineSweeper::new()),
-            },
-        ];
+        let (config, errors) = match Config::load() {
+            Ok(config) => (config, Vec::new()),
+            Err(errors) => (Config::default(), errors),
+        };
+        let detect_glyphs = config.glyphs.is_none();
+        let mut manager = Self::with_registry(GameRegistry::builtin())
+            .with_config(config)
+            .with_high_scores(HighScores::load())
+            .with_stats(Stats::load())
+            .with_save_store(SaveStore::open());
+        manager.config_errors.extend(errors);
+        manager.showing_config_errors = !manager.config_errors.is_empty();
+        if detect_glyphs {
+            manager.set_glyphs(GlyphSet::detect());
+        }
+        manager
+    }
+
+    /// 使用自定义的游戏注册表创建管理器，菜单按注册顺序列出游戏
+    ///
+    /// 排行榜、统计和存档只保存在内存中，需要时用 `with_high_scores`、`with_stats` 和
+    /// `with_save_store` 指定文件
+    pub fn with_registry(registry: GameRegistry) -> Self {
+        let mut translations = Translations::new();
+        let games = registry
+            .into_iter()
+            .map(|descriptor| {
+                translations.add_table(descriptor.translations);
+                GameInfo {
+                    game: OnceCell::new(),
+                    descriptor,
+                }
+            })
+            .collect();
 
-        Self {
-            state: GameType::MainMenu,
+        let mut manager = Self {
+            state: Screen::MainMenu,
             games,
             selected_game: 0,
-            translations: Translations::new(),
-            selecting_language: false,
+            translations,
             compile_language: CompileLanguage::Rust,
-            selecting_compile_language: false,
+            config: Config::default(),
+            config_errors: Vec::new(),
+            keymap: Keymap::new(),
+            theme: Theme::default(),
+            glyphs: Glyphs::default(),
+            selected_setting: 0,
+            showing_keys: false,
+            selected_action: 0,
+            capturing_key: false,
+            settings_return: Screen::MainMenu,
+            last_area: Rect::default(),
+            terminal_size: None,
+            should_quit: false,
+            high_scores: HighScores::default(),
+            showing_high_scores: false,
+            stats: Stats::default(),
+            achievements: Achievements::default(),
+            toasts: VecDeque::new(),
+            showing_achievements: false,
+            saves: SaveStore::default(),
+            seed: None,
+            compiling: Compiling::new(),
+            showing_help: false,
+            showing_config_errors: false,
+            hiding: false,
+        };
+        manager.achievements = Achievements::new(&manager.stats, manager.game_ids());
+        manager.set_theme(ThemeName::detect());
+        manager
+    }
+
+    /// 应用用户设置，设置界面中的修改会写回同一个文件
+    pub fn with_config(mut self, config: Config) -> Self {
+        if let Some(language) = config.language {
+            self.set_language(language);
         }
+        if let Some(lang) = config.compile_language {
+            self.set_compile_language(lang);
+        }
+        if let Some(name) = config.theme {
+            self.set_theme(name);
+        }
+        if let Some(set) = config.glyphs {
+            self.set_glyphs(set);
+        }
+        for game_info in &mut self.games {
+            let has_difficulty = game_info.descriptor.has_difficulty;
+            if let (true, Some(game)) = (has_difficulty, game_info.game.get_mut()) {
+                game.set_difficulty(config.difficulty(game_info.descriptor.id));
+            }
+        }
+        let available = self.game_ids();
+        self.config_errors = config
+            .difficulty_games()
+            .filter(|id| !available.contains(id))
+            .map(|id| ConfigError::UnknownGame {
+                name: id.to_string(),
+                available: available.clone(),
+            })
+            .collect();
+        self.config = config;
+        self.apply_keymap();
+        // 默认按键没有冲突，只在修改过按键时才检查
+        if self.config.has_custom_keys() {
+            let conflicts = self.key_conflicts();
+            self.config_errors.extend(conflicts);
+        }
+        self.showing_config_errors = !self.config_errors.is_empty();
+        self
     }
 
-    pub fn handle_input(&mut self, key: KeyCode) {
-        match self.state {
-            GameType::MainMenu => {
-                if self.selecting_language {
-                    match key {
-                        KeyCode::Char('e') => {
-                            self.translations.set_language(Language::English);
-                            self.set_language(Language::English);
-                            self.selecting_language = false;
-                        }
-                        KeyCode::Char('c') => {
-                            self.translations.set_language(Language::Chinese);
-                            self.set_language(Language::Chinese);
-                            self.selecting_language = false;
+    // 把设置中的按键交给所有游戏和菜单的翻译
+    fn apply_keymap(&mut self) {
+        self.keymap = self.config.keymap();
+        self.translations.set_keymap(&self.keymap);
+        for game in self.games.iter_mut().filter_map(|g| g.game.get_mut()) {
+            game.set_keymap(&self.keymap);
+        }
+    }
+
+    // 同一个游戏中绑定了同一个键的操作，每对只报告一次
+    fn key_conflicts(&self) -> Vec<ConfigError> {
+        let mut conflicts = Vec::new();
+        for index in 0..self.games.len() {
+            let mut actions = self.games[index].descriptor.actions.clone();
+            actions.sort();
+            for (index, &first) in actions.iter().enumerate() {
+                for &second in &actions[index + 1..] {
+                    let shared = self
+                        .keymap
+                        .keys(first)
+                        .iter()
+                        .filter(|code| self.keymap.keys(second).contains(code));
+                    for &code in shared {
+                        let conflict = ConfigError::KeyConflict {
+                            key: keymap::format_key_code(code),
+                            first: first.id(),
+                            second: second.id(),
+                        };
+                        if !conflicts.contains(&conflict) {
+                            conflicts.push(conflict);
                         }
-                        KeyCode::Esc => self.selecting_language = false,
-                        _ => {}
                     }
-                } else if self.selecting_compile_language {
-                    match key {
-                        KeyCode::Char('r') => {
-                            self.compile_language = CompileLanguage::Rust;
-                            self.set_compile_language(CompileLanguage::Rust);
-                            self.selecting_compile_language = false;
-                        }
-                        KeyCode::Char('g') => {
-                            self.compile_language = CompileLanguage::Go;
-                            self.set_compile_language(CompileLanguage::Go);
-                            self.selecting_compile_language = false;
-                        }
-                        KeyCode::Char('m') => {
-                            self.compile_language = CompileLanguage::CMake;
-                            self.set_compile_language(CompileLanguage::CMake);
-                            self.selecting_compile_language = false;
-                        }
-                        KeyCode::Esc => self.selecting_compile_language = false,
-                        _ => {}
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// 读取设置时发现的错误，为空表示设置文件没有问题
+    pub fn config_errors(&self) -> &[ConfigError] {
+        &self.config_errors
+    }
+
+    /// 启动时发现的设置文件错误还显示在界面上
+    pub fn is_showing_config_errors(&self) -> bool {
+        self.showing_config_errors
+    }
+
+    /// 使用指定的排行榜，例如 `HighScores::load()` 读取的默认文件
+    pub fn with_high_scores(mut self, high_scores: HighScores) -> Self {
+        self.high_scores = high_scores;
+        for game_info in &mut self.games {
+            if let Some(game) = game_info.game.get_mut() {
+                game.set_high_scores(self.high_scores.top(game_info.descriptor.id), None);
+            }
+        }
+        self
+    }
+
+    /// 使用指定的统计，例如 `Stats::load()` 读取的默认文件
+    ///
+    /// 统计中已经满足条件的成就直接算作解锁，不会再弹出提示
+    pub fn with_stats(mut self, stats: Stats) -> Self {
+        self.achievements = Achievements::new(&stats, self.game_ids());
+        self.stats = stats;
+        self
+    }
+
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    /// 保存统计，游戏时间只在这里写入文件
+    pub fn save_stats(&self) -> io::Result<()> {
+        self.stats.save()
+    }
+
+    /// 使用指定的存档目录，每个游戏在创建时载入自己的存档
+    pub fn with_save_store(mut self, saves: SaveStore) -> Self {
+        self.saves = saves;
+        self
+    }
+
+    /// 保存所有还没结束的游戏，已经结束的游戏删除旧存档；没有创建过的游戏不动它的存档
+    pub fn save_games(&self) -> io::Result<()> {
+        for index in 0..self.games.len() {
+            if self.games[index].game.get().is_some() {
+                self.save_game(index)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn save_game(&self, index: usize) -> io::Result<()> {
+        let id = self.games[index].descriptor.id;
+        match self.game(index).save_state() {
+            Some(data) => self.saves.save(id, &data),
+            None => self.saves.remove(id),
+        }
+    }
+
+    // 第 index 个游戏，第一次使用时创建
+    fn game(&self, index: usize) -> &dyn Game {
+        self.games[index]
+            .game
+            .get_or_init(|| self.create_game(index))
+            .as_ref()
+    }
+
+    fn game_mut(&mut self, index: usize) -> &mut Box<dyn Game> {
+        if self.games[index].game.get().is_none() {
+            let game = self.create_game(index);
+            let _ = self.games[index].game.set(game);
+        }
+        self.games[index]
+            .game
+            .get_mut()
+            .expect("game was just created")
+    }
+
+    // 按当前的设置创建游戏，并载入它的排行榜和存档
+    fn create_game(&self, index: usize) -> Box<dyn Game> {
+        let descriptor = &self.games[index].descriptor;
+        let id = descriptor.id;
+        let seed = match self.seed {
+            Some(seed) => Self::game_seed(seed, id),
+            None => rand::random(),
+        };
+        let mut game = descriptor.create(seed);
+        game.set_language(self.translations.current_language());
+        game.set_keymap(&self.keymap);
+        game.set_theme(&self.theme);
+        game.set_glyphs(&self.glyphs);
+        if descriptor.has_difficulty {
+            game.set_difficulty(self.config.difficulty(id));
+        }
+        game.set_high_scores(self.high_scores.top(id), None);
+        if let Some(data) = self.saves.load(id) {
+            if !game.load_state(&data) {
+                self.saves.discard(id);
+            }
+        }
+        if let Some((width, height)) = self.terminal_size {
+            game.resize(width, height);
+        }
+        game
+    }
+
+    // 进入第 index 个游戏，第一次进入时先创建游戏并载入存档
+    fn enter_game(&mut self, index: usize) {
+        self.state = Screen::Game(index);
+        self.game_mut(index).on_enter();
+    }
+
+    // 离开当前的游戏回到主菜单，游戏保留在离开时的状态
+    fn exit_to_menu(&mut self) {
+        if let Screen::Game(index) = self.state {
+            self.game_mut(index).on_exit();
+        }
+        self.state = Screen::MainMenu;
+    }
+
+    // 已经进入、但是被设置界面、帮助或者老板键盖住的游戏
+    fn covered_game(&self) -> Option<usize> {
+        match (self.state, self.settings_return) {
+            (Screen::Game(index), _) if self.hiding || self.showing_help => Some(index),
+            (Screen::Settings, Screen::Game(index)) => Some(index),
+            _ => None,
+        }
+    }
+
+    // 打开或关闭设置界面、帮助和老板键之后调用，`covered` 是之前被盖住的游戏：
+    // 刚被盖住的游戏收到 on_suspend，重新露出来的游戏收到 on_resume，
+    // 直接回到主菜单的游戏已经收到了 on_exit
+    fn notify_covered(&mut self, covered: Option<usize>) {
+        let now = self.covered_game();
+        if now == covered {
+            return;
+        }
+        if let Some(index) = covered {
+            if self.state == Screen::Game(index) {
+                self.game_mut(index).on_resume();
+            }
+        }
+        if let Some(index) = now {
+            self.game_mut(index).on_suspend();
+        }
+    }
+
+    /// 所有已注册游戏的 id，按菜单顺序排列
+    pub fn game_ids(&self) -> Vec<&'static str> {
+        self.games.iter().map(|g| g.descriptor.id).collect()
+    }
+
+    /// 所有已注册游戏的 id 和当前语言下的名字
+    pub fn game_titles(&self) -> Vec<(&'static str, String)> {
+        self.games
+            .iter()
+            .map(|g| {
+                let title = self.translations.get_text(g.descriptor.title_key);
+                (g.descriptor.id, title)
+            })
+            .collect()
+    }
+
+    /// 跳过主菜单直接进入指定的游戏，没有这个 id 时返回 false
+    pub fn start_game(&mut self, id: &str) -> bool {
+        match self.games.iter().position(|g| g.descriptor.id == id) {
+            Some(index) => {
+                self.selected_game = index;
+                self.enter_game(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 用同一个种子重新设置所有游戏的随机数，还没开始的游戏会重新生成开局
+    ///
+    /// 每个游戏的种子由这个种子和游戏 id 得出，与注册顺序无关。
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+        for game_info in &mut self.games {
+            if let Some(game) = game_info.game.get_mut() {
+                game.set_seed(Self::game_seed(seed, game_info.descriptor.id));
+            }
+        }
+    }
+
+    // FNV-1a 散列游戏 id 后与种子混合
+    fn game_seed(seed: u64, id: &str) -> u64 {
+        id.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        }) ^ seed
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// 正在玩的游戏的 id，不在游戏中时为 None
+    pub fn current_game(&self) -> Option<&'static str> {
+        match self.state {
+            Screen::Game(index) => Some(self.games[index].descriptor.id),
+            _ => None,
+        }
+    }
+
+    /// 主菜单中按 `q` 退出程序
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    /// 正在显示老板键打开的假装编译界面，游戏在此期间不会推进
+    pub fn is_hiding(&self) -> bool {
+        self.hiding
+    }
+
+    // Ctrl+B 在任何界面都可以切换；没有在接收文字输入时 `b` 也可以
+    fn is_boss_key(&self, key: KeyEvent) -> bool {
+        let captures_text_input = match self.state {
+            Screen::MainMenu => false,
+            Screen::Settings => self.capturing_key,
+            Screen::Game(index) => self.game(index).captures_text_input(),
+        };
+        match key.code {
+            KeyCode::Char('b') if key.modifiers == KeyModifiers::CONTROL => true,
+            KeyCode::Char('b') => key.modifiers.is_empty() && !captures_text_input,
+            _ => false,
+        }
+    }
+
+    // 主菜单和游戏中按 `?` 打开帮助，游戏在接收文字输入时除外；有的终端会给 `?` 带上 Shift
+    fn is_help_key(&self, key: KeyEvent) -> bool {
+        let available = match self.state {
+            Screen::MainMenu => {
+                !self.showing_high_scores && !self.showing_achievements && !self.games.is_empty()
+            }
+            Screen::Settings => false,
+            Screen::Game(index) => !self.game(index).captures_text_input(),
+        };
+        available
+            && key.code == KeyCode::Char('?')
+            && !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+
+    /// 正在显示 `?` 打开的帮助，游戏在此期间不会推进
+    pub fn is_showing_help(&self) -> bool {
+        self.showing_help
+    }
+
+    // 帮助打开时只能关闭帮助，或者在游戏中直接返回主菜单
+    fn handle_help_input(&mut self, key: KeyEvent) {
+        let covered = self.covered_game();
+        if key.code == KeyCode::Esc || self.is_help_key(key) {
+            self.showing_help = false;
+        } else if let Screen::Game(index) = self.state {
+            if Self::is_back_to_menu(key, self.game(index).captures_text_input()) {
+                self.showing_help = false;
+                self.exit_to_menu();
+            }
+        }
+        self.notify_covered(covered);
+    }
+
+    fn is_save(key: KeyEvent) -> bool {
+        key.code == KeyCode::Char('s') && key.modifiers == KeyModifiers::CONTROL
+    }
+
+    // Ctrl+Q 总是返回主菜单；没有在接收文字输入的游戏里 `q` 也可以
+    fn is_back_to_menu(key: KeyEvent, captures_text_input: bool) -> bool {
+        match key.code {
+            KeyCode::Char('q') if key.modifiers == KeyModifiers::CONTROL => true,
+            KeyCode::Char('q') => key.modifiers.is_empty() && !captures_text_input,
+            _ => false,
+        }
+    }
+
+    pub fn handle_input(&mut self, key: KeyEvent) {
+        // 假装编译时只有再按一次老板键才回到原来的界面
+        if self.is_boss_key(key) {
+            let covered = self.covered_game();
+            self.hiding = !self.hiding;
+            self.notify_covered(covered);
+            return;
+        }
+        if self.hiding {
+            return;
+        }
+        if self.showing_config_errors {
+            self.showing_config_errors = false;
+            return;
+        }
+        if self.showing_help {
+            self.handle_help_input(key);
+            return;
+        }
+        if self.is_help_key(key) {
+            let covered = self.covered_game();
+            self.showing_help = true;
+            self.notify_covered(covered);
+            return;
+        }
+        match self.state {
+            Screen::MainMenu => {
+                if key.code == KeyCode::Char('q') {
+                    self.should_quit = true;
+                } else if self.showing_high_scores {
+                    if matches!(key.code, KeyCode::Esc | KeyCode::Char('h')) {
+                        self.showing_high_scores = false;
+                    }
+                } else if self.showing_achievements {
+                    if matches!(key.code, KeyCode::Esc | KeyCode::Char('a')) {
+                        self.showing_achievements = false;
                     }
                 } else {
-                    match key {
+                    match key.code {
+                        KeyCode::Char('h') => self.showing_high_scores = true,
+                        KeyCode::Char('a') => self.showing_achievements = true,
+                        KeyCode::Char('s') => self.open_settings(),
                         KeyCode::Char(c) => {
                             // 通过数字选择游戏
-                            if let Some(index) = c.to_digit(10) {
-                                let index = index as usize - 1;
+                            // '0' 不对应任何游戏
+                            let index = c.to_digit(10).and_then(|n| (n as usize).checked_sub(1));
+                            if let Some(index) = index {
                                 if index < self.games.len() {
-                                    self.state = self.games[index].game_type;
+                                    self.enter_game(index);
                                 }
                             }
                         }
-                        KeyCode::Up => {
-                            if self.selected_game > 0 {
-                                self.selected_game -= 1;
-                            }
+                        KeyCode::Up if self.selected_game > 0 => {
+                            self.selected_game -= 1;
                         }
-                        KeyCode::Down => {
-                            if self.selected_game < self.games.len() - 1 {
-                                self.selected_game += 1;
-                            }
+                        KeyCode::Down if self.selected_game + 1 < self.games.len() => {
+                            self.selected_game += 1;
                         }
-                        KeyCode::Enter => {
-                            self.state = self.games[self.selected_game].game_type;
+                        KeyCode::Enter if !self.games.is_empty() => {
+                            self.enter_game(self.selected_game)
                         }
                         _ => {}
                     }
                 }
             }
-            _ => {
-                if let Some(game_info) = self.games.iter_mut().find(|g| g.game_type == self.state) {
-                    game_info.game.handle_input(key);
+            Screen::Settings => self.handle_settings_input(key),
+            Screen::Game(index) => {
+                let too_small = self.too_small(index);
+                let game = self.game_mut(index);
+                if Self::is_back_to_menu(key, game.captures_text_input()) {
+                    self.exit_to_menu();
+                } else if Self::is_save(key) {
+                    // 界面中没有地方显示写入错误，退出时还会再保存一次
+                    let _ = self.save_game(index);
+                } else if too_small {
+                    // 窗口太小时看不到游戏画面，不把按键交给游戏
+                } else {
+                    game.handle_input(key);
+                    self.record_result(index);
+                    self.record_events(index);
+                    self.handle_request(index);
                 }
             }
         }
     }
 
-    pub fn update(&mut self) {
-        if let Some(game_info) = self.games.iter_mut().find(|g| g.game_type == self.state) {
-            game_info.game.update();
+    // 把游戏刚结束的一局记入排行榜，并把新的排行榜交还给游戏显示
+    fn record_result(&mut self, index: usize) {
+        let result = match self.game_mut(index).take_result() {
+            Some(result) => result,
+            None => return,
+        };
+
+        let id = self.games[index].descriptor.id;
+        self.stats.record_result(id, &result);
+        let _ = self.stats.save();
+        // 游戏时间的成就也在这里检查，不需要每一帧都检查
+        self.check_achievements();
+        let rank = self.high_scores.record(
+            id,
+            ScoreEntry {
+                name: scores::player_name(),
+                score: result.score,
+                timestamp: result.started_at,
+                detail: result.detail,
+            },
+        );
+        // 界面中没有地方显示写入错误，保存失败时只保留内存中的记录
+        let _ = self.high_scores.save();
+        let top = self.high_scores.top(id).to_vec();
+        self.game_mut(index).set_high_scores(&top, rank);
+    }
+
+    // 把游戏中发生的事情记入统计，并检查有没有新解锁的成就
+    fn record_events(&mut self, index: usize) {
+        let events = self.game_mut(index).take_events();
+        if events.is_empty() {
+            return;
         }
+        let id = self.games[index].descriptor.id;
+        for event in events {
+            self.stats.record_event(id, event);
+        }
+        self.check_achievements();
     }
 
-    pub fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
+    fn check_achievements(&mut self) {
+        let unlocked = self.achievements.update(&self.stats);
+        if unlocked.is_empty() {
+            return;
+        }
+        self.toasts
+            .extend(unlocked.into_iter().map(|achievement| Toast {
+                achievement,
+                remaining: TOAST_DURATION,
+            }));
+        // 解锁成就时马上保存，程序意外退出也不会丢掉
+        let _ = self.stats.save();
+    }
+
+    /// 已经解锁的成就
+    pub fn achievements(&self) -> &Achievements {
+        &self.achievements
+    }
+
+    /// 正在右上角提示的成就，没有提示时返回 None
+    pub fn current_toast(&self) -> Option<&'static Achievement> {
+        self.toasts.front().map(|toast| toast.achievement)
+    }
+
+    // 执行游戏在暂停菜单中选择的操作
+    fn handle_request(&mut self, index: usize) {
+        match self.game_mut(index).take_request() {
+            Some(GameRequest::BackToMenu) => self.exit_to_menu(),
+            Some(GameRequest::OpenSettings) => self.open_settings(),
+            None => {}
+        }
+    }
+
+    fn open_settings(&mut self) {
+        let covered = self.covered_game();
+        self.settings_return = self.state;
+        self.selected_setting = 0;
+        self.showing_keys = false;
+        self.capturing_key = false;
+        self.state = Screen::Settings;
+        self.notify_covered(covered);
+    }
+
+    // 设置界面的所有行：语言、假装编译的语言、每个支持难度的游戏、字符集、配色和按键设置
+    fn settings(&self) -> Vec<Setting> {
+        let mut settings = vec![Setting::Language, Setting::CompileLanguage];
+        settings.extend(
+            self.games
+                .iter()
+                .enumerate()
+                .filter(|(_, game_info)| game_info.descriptor.has_difficulty)
+                .map(|(index, _)| Setting::Difficulty(index)),
+        );
+        settings.push(Setting::Glyphs);
+        settings.push(Setting::Theme);
+        settings.push(Setting::Keys);
+        settings
+    }
+
+    // 至少有一个已注册的游戏用到的操作，按 Action::ALL 的顺序
+    fn bindable_actions(&self) -> Vec<Action> {
+        let used: Vec<Action> = self
+            .games
+            .iter()
+            .flat_map(|game_info| game_info.descriptor.actions.iter().copied())
+            .collect();
+        Action::ALL
+            .into_iter()
+            .filter(|action| used.contains(action))
+            .collect()
+    }
+
+    fn handle_settings_input(&mut self, key: KeyEvent) {
+        if self.showing_keys {
+            self.handle_keys_input(key);
+            return;
+        }
+        let count = self.settings().len();
+        match key.code {
+            KeyCode::Esc => {
+                let covered = self.covered_game();
+                self.state = self.settings_return;
+                self.notify_covered(covered);
+            }
+            KeyCode::Up => self.selected_setting = self.selected_setting.saturating_sub(1),
+            KeyCode::Down => self.selected_setting = (self.selected_setting + 1).min(count - 1),
+            KeyCode::Left => self.change_setting(false),
+            KeyCode::Right | KeyCode::Enter => self.change_setting(true),
+            _ => {}
+        }
+    }
+
+    fn handle_keys_input(&mut self, key: KeyEvent) {
+        let actions = self.bindable_actions();
+        // 没有注册任何用到操作的游戏时页面是空的，只能返回
+        let action = match actions.get(self.selected_action) {
+            Some(&action) => action,
+            None => {
+                if key.code == KeyCode::Esc {
+                    self.showing_keys = false;
+                }
+                return;
+            }
+        };
+        if self.capturing_key {
+            // 组合键和保留的键不能绑定，继续等待
+            let plain = !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+            match key.code {
+                KeyCode::Esc => self.capturing_key = false,
+                code if plain && !keymap::is_reserved(code) => {
+                    self.capturing_key = false;
+                    self.rebind(action, Some(vec![code]));
+                }
+                _ => {}
+            }
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.showing_keys = false,
+            KeyCode::Up => self.selected_action = self.selected_action.saturating_sub(1),
+            KeyCode::Down if self.selected_action + 1 < actions.len() => self.selected_action += 1,
+            KeyCode::Enter => self.capturing_key = true,
+            KeyCode::Backspace | KeyCode::Delete => self.rebind(action, None),
+            _ => {}
+        }
+    }
+
+    // 把按键绑定给操作，None 表示恢复默认按键
+    //
+    // 同一个游戏里其他操作上的这些键会被移除，一个键在每个游戏里只对应一个操作。
+    fn rebind(&mut self, action: Action, keys: Option<Vec<KeyCode>>) {
+        let new_keys = keys
+            .clone()
+            .unwrap_or_else(|| action.default_keys().to_vec());
+        for other in self.actions_sharing_game(action) {
+            let current = self.keymap.keys(other);
+            if current.iter().any(|code| new_keys.contains(code)) {
+                let remaining = current
+                    .iter()
+                    .copied()
+                    .filter(|code| !new_keys.contains(code))
+                    .collect();
+                self.config.set_keys(other, remaining);
+            }
+        }
+        match keys {
+            Some(keys) => self.config.set_keys(action, keys),
+            None => self.config.reset_keys(action),
+        }
+        self.apply_keymap();
+        // 界面中没有地方显示写入错误，保存失败时设置只在这次运行中有效
+        let _ = self.config.save();
+    }
+
+    // 与 action 出现在同一个游戏中的其他操作
+    fn actions_sharing_game(&self, action: Action) -> Vec<Action> {
+        let mut actions: Vec<Action> = self
+            .games
+            .iter()
+            .map(|game_info| &game_info.descriptor.actions)
+            .filter(|actions| actions.contains(&action))
+            .flatten()
+            .copied()
+            .filter(|&other| other != action)
+            .collect();
+        actions.sort();
+        actions.dedup();
+        actions
+    }
+
+    /// 当前使用的按键，包括在设置界面中做的修改
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    // 把选中的设置换成上一个或下一个值，并立即写入设置文件
+    fn change_setting(&mut self, forward: bool) {
+        match self.settings()[self.selected_setting] {
+            Setting::Language => {
+                let language = cycle(&LANGUAGES, self.translations.current_language(), forward);
+                self.set_language(language);
+                self.config.language = Some(language);
+            }
+            Setting::CompileLanguage => {
+                let lang = cycle(&COMPILE_LANGUAGES, self.compile_language, forward);
+                self.set_compile_language(lang);
+                self.config.compile_language = Some(lang);
+            }
+            Setting::Difficulty(index) => {
+                let game_info = &mut self.games[index];
+                let id = game_info.descriptor.id;
+                let difficulty = cycle(&Difficulty::ALL, self.config.difficulty(id), forward);
+                if let Some(game) = game_info.game.get_mut() {
+                    game.set_difficulty(difficulty);
+                }
+                self.config.set_difficulty(id, difficulty);
+            }
+            Setting::Glyphs => {
+                let set = cycle(&GlyphSet::ALL, self.glyphs.set, forward);
+                self.set_glyphs(set);
+                self.config.glyphs = Some(set);
+            }
+            Setting::Theme => {
+                let name = cycle(&ThemeName::ALL, self.theme.name, forward);
+                self.set_theme(name);
+                self.config.theme = Some(name);
+            }
+            Setting::Keys => {
+                // 按键设置是单独的页面，只能向前打开
+                if forward {
+                    self.showing_keys = true;
+                    self.selected_action = 0;
+                }
+                return;
+            }
+        }
+        // 界面中没有地方显示写入错误，保存失败时设置只在这次运行中有效
+        let _ = self.config.save();
+    }
+
+    /// 当前设置，包括在设置界面中做的修改
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn handle_mouse(&mut self, event: MouseEvent) {
+        if self.hiding || self.showing_help || self.showing_config_errors {
+            return;
+        }
+        match self.state {
+            Screen::MainMenu => {
+                if self.showing_high_scores || self.showing_achievements {
+                    return;
+                }
+                match event.kind {
+                    MouseEventKind::Down(MouseButton::Left) => {
+                        if let Some(index) = self.menu_item_at(event.row) {
+                            self.selected_game = index;
+                            self.enter_game(index);
+                        }
+                    }
+                    MouseEventKind::ScrollUp if self.selected_game > 0 => {
+                        self.selected_game -= 1;
+                    }
+                    MouseEventKind::ScrollDown if self.selected_game + 1 < self.games.len() => {
+                        self.selected_game += 1;
+                    }
+                    _ => {}
+                }
+            }
+            Screen::Settings => {}
+            Screen::Game(index) if self.too_small(index) => {}
+            Screen::Game(index) => {
+                let area = self.last_area;
+                self.game_mut(index).handle_mouse(event, area);
+                self.record_result(index);
+                self.record_events(index);
+            }
+        }
+    }
+
+    // 菜单中游戏列表从边框内第 5 行开始，与 render_main_menu 的布局一致
+    fn menu_item_at(&self, row: u16) -> Option<usize> {
+        let first_item_row = self.last_area.y + 1 + 4;
+        let index = row.checked_sub(first_item_row)? as usize;
+        (index < self.games.len()).then_some(index)
+    }
+
+    pub fn update(&mut self, dt: Duration) {
+        if self.hiding {
+            self.compiling.update(dt);
+            return;
+        }
+        if let Some(toast) = self.toasts.front_mut() {
+            toast.remaining = toast.remaining.saturating_sub(dt);
+            if toast.remaining.is_zero() {
+                self.toasts.pop_front();
+            }
+        }
+        if let Screen::Game(index) = self.state {
+            // 在 update 而不是渲染时暂停，回放时才能得到同样的结果
+            if self.too_small(index) {
+                self.game_mut(index).pause();
+                return;
+            }
+            if self.showing_help || self.showing_config_errors {
+                return;
+            }
+            let game = self.game_mut(index);
+            // 欢迎界面、暂停菜单和结束画面停留的时间不算游戏时间
+            let playing = game.is_playing();
+            game.update(dt);
+            if playing {
+                self.stats.add_time(self.games[index].descriptor.id, dt);
+            }
+            self.record_result(index);
+            self.record_events(index);
+        }
+    }
+
+    /// 终端大小改变时调用，所有游戏都会收到新的大小以便重新布局，之后创建的游戏在创建时收到
+    pub fn handle_resize(&mut self, width: u16, height: u16) {
+        self.terminal_size = Some((width, height));
+        for game in self.games.iter_mut().filter_map(|g| g.game.get_mut()) {
+            game.resize(width, height);
+        }
+    }
+
+    // 终端放不下游戏声明的最小尺寸
+    fn too_small(&self, index: usize) -> bool {
+        let (min_width, min_height) = self.game(index).min_size();
+        self.terminal_size
+            .is_some_and(|(width, height)| width < min_width || height < min_height)
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        f.render_widget(ManagerView(self), area);
+    }
+
+    /// 直接绘制到缓冲区，不依赖具体的终端后端
+    pub fn draw(&mut self, buf: &mut Buffer, area: Rect) {
+        self.last_area = area;
+        if self.hiding {
+            self.compiling.render(buf, area);
+            return;
+        }
         match self.state {
-            GameType::MainMenu => self.render_main_menu(f, area),
-            _ => {
-                if let Some(game_info) = self.games.iter_mut().find(|g| g.game_type == self.state) {
-                    game_info.game.render(f, area);
+            Screen::MainMenu if self.showing_high_scores => self.render_high_scores(buf, area),
+            Screen::MainMenu if self.showing_achievements => self.render_achievements(buf, area),
+            Screen::MainMenu => self.render_main_menu(buf, area),
+            Screen::Settings if self.showing_keys => self.render_keys(buf, area),
+            Screen::Settings => self.render_settings(buf, area),
+            Screen::Game(index) => {
+                let (min_width, min_height) = self.game(index).min_size();
+                if area.width < min_width || area.height < min_height {
+                    self.render_resize(buf, area, (min_width, min_height));
+                } else {
+                    self.game_mut(index).render(buf, area);
                 }
             }
         }
+        if self.showing_help {
+            self.render_help(buf, area);
+        }
+        if self.showing_config_errors {
+            self.render_config_errors(buf, area);
+        }
+        if let Some(achievement) = self.current_toast() {
+            self.render_toast(buf, area, achievement);
+        }
     }
 
-    fn render_main_menu(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
+    // 右上角的成就解锁提示，盖在任何界面上；窗口放不下时不显示
+    fn render_toast(&self, buf: &mut Buffer, area: Rect, achievement: &Achievement) {
+        let text = format!(
+            "{}: {}",
+            self.translations.get_text("achievement_unlocked"),
+            self.translations.get_text(&achievement.title_key())
+        );
+        let line = Spans::from(Span::styled(text, self.theme.heading));
+        let width = line.width() as u16 + 4;
+        if width > area.width || area.height < 3 {
+            return;
+        }
+        let toast = Rect::new(area.right() - width, area.y, width, 3);
+
+        Clear.render(toast, buf);
+        Paragraph::new(line)
+            .block(Block::default().borders(Borders::ALL))
+            .alignment(tui::layout::Alignment::Center)
+            .render(toast, buf);
+    }
+
+    // `?` 打开的帮助：游戏中是当前的游戏，主菜单中是选中的游戏，全部由游戏的 metadata 生成
+    fn render_help(&self, buf: &mut Buffer, area: Rect) {
+        let index = match self.state {
+            Screen::Game(index) => index,
+            _ => self.selected_game,
+        };
+        // 主菜单上从描述读取帮助，不为此创建游戏
+        let metadata = match self.state {
+            Screen::Game(_) => self.game(index).metadata(),
+            _ => self.games[index].descriptor.help(&self.translations),
+        };
+        let name = if metadata.name.is_empty() {
+            self.translations
+                .get_text(self.games[index].descriptor.title_key)
+        } else {
+            metadata.name.clone()
+        };
+
+        let mut text = vec![Spans::from("")];
+        if !metadata.description.is_empty() {
+            text.push(Spans::from(metadata.description.clone()));
+            text.push(Spans::from(""));
+        }
+        if !metadata.controls.is_empty() {
+            text.push(Spans::from(Span::styled(
+                self.translations.get_text("help_controls"),
+                self.theme.heading,
+            )));
+            let controls = metadata.control_lines(&self.translations);
+            text.extend(controls.into_iter().map(Spans::from));
+            text.push(Spans::from(""));
+        }
+        text.push(Spans::from(Span::styled(
+            self.translations.get_text("help_global"),
+            self.theme.heading,
+        )));
+        for line in self.translations.get_text("help_global_keys").split('\n') {
+            text.push(Spans::from(line.to_string()));
+        }
+        text.push(Spans::from(""));
+        text.push(Spans::from(Span::styled(
+            self.translations.get_text("help_close"),
+            self.theme.muted,
+        )));
+
+        let title = Span::styled(
+            format!("{}: {}", self.translations.get_text("help_title"), name),
+            self.theme.title,
+        );
+        let width = text
+            .iter()
+            .map(|line| line.width() as u16 + 4)
+            .max()
+            .unwrap_or(0)
+            .max(title.width() as u16 + 4);
+        let overlay = centered(area, width, text.len() as u16 + 3);
+
+        Clear.render(overlay, buf);
+        Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(tui::layout::Alignment::Center)
+            .render(overlay, buf);
+    }
+
+    // 启动时设置文件中的错误，错误信息本身只有英文，与命令行参数的错误一致
+    fn render_config_errors(&self, buf: &mut Buffer, area: Rect) {
+        let mut text = vec![Spans::from("")];
+        if let Some(path) = config::config_path() {
+            text.push(Spans::from(Span::styled(
+                path.display().to_string(),
+                self.theme.heading,
+            )));
+        }
+        text.extend(
+            self.config_errors
+                .iter()
+                .map(|err| Spans::from(err.to_string())),
+        );
+        text.push(Spans::from(""));
+        text.push(Spans::from(Span::styled(
+            self.translations.get_text("config_errors_hint"),
+            self.theme.muted,
+        )));
+
+        let title = Span::styled(
+            self.translations.get_text("config_errors_title"),
+            self.theme.danger,
+        );
+        let width = text
+            .iter()
+            .map(|line| line.width() as u16 + 4)
+            .max()
+            .unwrap_or(0)
+            .max(title.width() as u16 + 4);
+        // 较长的路径和错误信息在窄的窗口中折成多行
+        let inner = width.min(area.width).saturating_sub(2).max(1);
+        let height: u16 = text
+            .iter()
+            .map(|line| (line.width() as u16).max(1).div_ceil(inner))
+            .sum();
+        let overlay = centered(area, width, height + 3);
+
+        Clear.render(overlay, buf);
+        Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(tui::layout::Alignment::Center)
+            .wrap(tui::widgets::Wrap { trim: true })
+            .render(overlay, buf);
+    }
+
+    // 所有游戏共用的“请放大窗口”界面
+    fn render_resize(&self, buf: &mut Buffer, area: Rect, min_size: (u16, u16)) {
+        let text = vec![
+            Spans::from(Span::styled(
+                self.translations.get_text("resize_title"),
+                self.theme.danger,
+            )),
+            Spans::from(""),
+            Spans::from(format!(
+                "{}: {}x{}",
+                self.translations.get_text("resize_needed"),
+                min_size.0,
+                min_size.1
+            )),
+            Spans::from(format!(
+                "{}: {}x{}",
+                self.translations.get_text("resize_current"),
+                area.width,
+                area.height
+            )),
+            Spans::from(""),
+            Spans::from(Span::styled(
+                self.translations.get_text("resize_hint"),
+                self.theme.muted,
+            )),
+        ];
+        let paragraph = Paragraph::new(text)
+            .alignment(tui::layout::Alignment::Center)
+            .wrap(tui::widgets::Wrap { trim: true });
+        paragraph.render(area, buf);
+    }
+
+    fn render_main_menu(&self, buf: &mut Buffer, area: Rect) {
         let mut menu_text = vec![
             Spans::from(vec![Span::styled(
                 self.translations.get_text("menu_title"),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )]),
             Spans::from(""),
             Spans::from(self.translations.get_text("available_games")),
@@ -200,73 +1214,319 @@ impl GameManager {
                 format!(
                     " {}. {}",
                     index + 1,
-                    self.translations.get_text(game_info.title_key)
+                    self.translations.get_text(game_info.descriptor.title_key)
                 ),
-                Style::default().fg(if self.selected_game == index {
-                    Color::Green
+                if self.selected_game == index {
+                    self.theme.selected
                 } else {
-                    Color::White
-                }),
+                    Style::default()
+                },
             )]));
         }
 
-        if self.selecting_compile_language {
-            menu_text.push(Spans::from(""));
-            menu_text.push(Spans::from(vec![Span::styled(
-                "Select Compile Language / 选择编译语言:",
-                Style::default().fg(Color::Yellow),
-            )]));
-            menu_text.push(Spans::from("R: Rust"));
-            menu_text.push(Spans::from("G: Go"));
-            menu_text.push(Spans::from("M: CMake"));
-            menu_text.push(Spans::from("ESC: Cancel / 取消"));
-        } else if self.selecting_language {
-            menu_text.push(Spans::from(""));
-            menu_text.push(Spans::from(vec![Span::styled(
-                "Select Language / 选择语言:",
-                Style::default().fg(Color::Yellow),
-            )]));
-            menu_text.push(Spans::from("E: English"));
-            menu_text.push(Spans::from("C: 中文"));
-            menu_text.push(Spans::from("ESC: Cancel / 取消"));
-        } else {
-            for line in self.translations.get_text("controls").split('\n') {
-                menu_text.push(Spans::from(line.to_string()));
-            }
-            menu_text.push(Spans::from(format!(
-                "{} ({})",
-                self.translations.get_text("compiling"),
-                match self.compile_language {
-                    CompileLanguage::Rust => "Rust",
-                    CompileLanguage::Go => "Go",
-                    CompileLanguage::CMake => "CMake",
-                }
-            )));
-            menu_text.push(Spans::from(
-                self.translations.get_text("compiling_language"),
-            ));
+        for line in self.translations.get_text("controls").split('\n') {
+            menu_text.push(Spans::from(line.to_string()));
         }
+        let compile_language = match self.compile_language {
+            CompileLanguage::Rust => "Rust",
+            CompileLanguage::Go => "Go",
+            CompileLanguage::CMake => "CMake",
+        };
+        menu_text.push(Spans::from(
+            self.translations
+                .get_text("compiling")
+                .replace("{language}", compile_language),
+        ));
+        menu_text.push(Spans::from(self.translations.get_text("settings_control")));
+        menu_text.push(Spans::from(
+            self.translations.get_text("high_scores_control"),
+        ));
+        menu_text.push(Spans::from(
+            self.translations.get_text("achievements_control"),
+        ));
+        menu_text.push(Spans::from(self.translations.get_text("help_control")));
 
         let paragraph = Paragraph::new(menu_text)
             .block(Block::default().borders(Borders::ALL).title(Span::styled(
                 self.translations.get_text("menu_title"),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )))
             .alignment(tui::layout::Alignment::Center);
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
+    }
+
+    fn render_settings(&self, buf: &mut Buffer, area: Rect) {
+        let mut text = vec![Spans::from("")];
+        for (index, setting) in self.settings().into_iter().enumerate() {
+            let (name, value) = match setting {
+                Setting::Language => (
+                    self.translations.get_text("settings_language"),
+                    match self.translations.current_language() {
+                        Language::English => "English".to_string(),
+                        Language::Chinese => "中文".to_string(),
+                    },
+                ),
+                Setting::CompileLanguage => (
+                    self.translations.get_text("settings_compile_language"),
+                    match self.compile_language {
+                        CompileLanguage::Rust => "Rust",
+                        CompileLanguage::Go => "Go",
+                        CompileLanguage::CMake => "CMake",
+                    }
+                    .to_string(),
+                ),
+                Setting::Difficulty(game) => {
+                    let descriptor = &self.games[game].descriptor;
+                    let difficulty = self.config.difficulty(descriptor.id);
+                    (
+                        format!(
+                            "{} {}",
+                            self.translations.get_text(descriptor.title_key),
+                            self.translations.get_text("settings_difficulty")
+                        ),
+                        self.translations.get_text(difficulty.title_key()),
+                    )
+                }
+                Setting::Glyphs => (
+                    self.translations.get_text("settings_glyphs"),
+                    self.translations.get_text(self.glyphs.set.title_key()),
+                ),
+                Setting::Theme => (
+                    self.translations.get_text("settings_theme"),
+                    self.translations.get_text(self.theme.name.title_key()),
+                ),
+                Setting::Keys => (self.translations.get_text("settings_keys"), String::new()),
+            };
+            let style = if index == self.selected_setting {
+                self.theme.highlight
+            } else {
+                Style::default()
+            };
+            let line = match setting {
+                Setting::Keys => format!(" {} ▶ ", name),
+                _ => format!(" {}: ◀ {} ▶ ", name, value),
+            };
+            text.push(Spans::from(Span::styled(line, style)));
+        }
+        text.push(Spans::from(""));
+        text.push(Spans::from(self.translations.get_text("settings_hint")));
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(Span::styled(
+                self.translations.get_text("settings_title"),
+                self.theme.title,
+            )))
+            .alignment(tui::layout::Alignment::Center);
+        paragraph.render(area, buf);
+    }
+
+    fn render_keys(&self, buf: &mut Buffer, area: Rect) {
+        let mut text = vec![Spans::from("")];
+        for (index, action) in self.bindable_actions().into_iter().enumerate() {
+            let selected = index == self.selected_action;
+            let keys = if selected && self.capturing_key {
+                self.translations.get_text("keys_waiting")
+            } else {
+                self.keymap.describe(action)
+            };
+            let style = if selected {
+                self.theme.highlight
+            } else {
+                Style::default()
+            };
+            text.push(Spans::from(Span::styled(
+                format!(
+                    " {}: {} ",
+                    self.translations.get_text(&action.title_key()),
+                    keys
+                ),
+                style,
+            )));
+        }
+        text.push(Spans::from(""));
+        text.push(Spans::from(self.translations.get_text(
+            if self.capturing_key {
+                "keys_capture_hint"
+            } else {
+                "keys_hint"
+            },
+        )));
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(Span::styled(
+                self.translations.get_text("keys_title"),
+                self.theme.title,
+            )))
+            .alignment(tui::layout::Alignment::Center);
+        paragraph.render(area, buf);
+    }
+
+    fn render_high_scores(&self, buf: &mut Buffer, area: Rect) {
+        let mut text = vec![Spans::from("")];
+        for game_info in &self.games {
+            text.push(Spans::from(Span::styled(
+                self.translations.get_text(game_info.descriptor.title_key),
+                self.theme.heading,
+            )));
+            // 跳过每个表格自带的标题，整个页面只显示一次
+            let entries = self.high_scores.top(game_info.descriptor.id);
+            let table = scores::table_lines(entries, &self.translations, &self.theme);
+            text.extend(table.into_iter().skip(1));
+            text.push(Spans::from(""));
+        }
+        text.push(Spans::from(self.translations.get_text("back_to_menu")));
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(Span::styled(
+                self.translations.get_text("high_scores"),
+                self.theme.title,
+            )))
+            .alignment(tui::layout::Alignment::Center);
+        paragraph.render(area, buf);
+    }
+
+    // 成就列表和每个游戏的统计
+    fn render_achievements(&self, buf: &mut Buffer, area: Rect) {
+        let t = &self.translations;
+        let mut text = vec![
+            Spans::from(""),
+            Spans::from(format!(
+                "{}: {}/{}",
+                t.get_text("achievements_unlocked"),
+                self.achievements.unlocked_count(),
+                ACHIEVEMENTS.len()
+            )),
+        ];
+        for achievement in &ACHIEVEMENTS {
+            let unlocked = self.achievements.is_unlocked(achievement.id);
+            text.push(Spans::from(Span::styled(
+                format!(
+                    "[{}] {} - {}",
+                    if unlocked { "x" } else { " " },
+                    t.get_text(&achievement.title_key()),
+                    t.get_text(&achievement.description_key())
+                ),
+                if unlocked {
+                    self.theme.heading
+                } else {
+                    self.theme.muted
+                },
+            )));
+        }
+
+        text.push(Spans::from(""));
+        text.push(Spans::from(Span::styled(
+            t.get_text("stats_title"),
+            self.theme.heading,
+        )));
+        text.push(Spans::from(format!(
+            "{}: {}  {}: {}",
+            t.get_text("stats_played"),
+            self.stats.total_played(),
+            t.get_text("stats_time"),
+            stats::format_duration(self.stats.total_time())
+        )));
+        for game_info in &self.games {
+            let game = self.stats.game(game_info.descriptor.id);
+            let mut line = format!(
+                "{}: {} {}",
+                t.get_text(game_info.descriptor.title_key),
+                t.get_text("stats_played"),
+                game.played
+            );
+            if let Some(score) = game.best_score {
+                line.push_str(&format!(", {} {}", t.get_text("stats_best"), score));
+            }
+            for (counter, value) in game.counters() {
+                line.push_str(&format!(", {} {}", t.get_text(&counter.title_key()), value));
+            }
+            text.push(Spans::from(line));
+        }
+        text.push(Spans::from(""));
+        text.push(Spans::from(t.get_text("back_to_menu")));
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(Span::styled(
+                t.get_text("achievements_title"),
+                self.theme.title,
+            )))
+            .alignment(tui::layout::Alignment::Center);
+        paragraph.render(area, buf);
     }
 
     pub fn set_language(&mut self, language: Language) {
         self.translations.set_language(language);
-        for game_info in &mut self.games {
-            game_info.game.set_language(language);
+        for game in self.games.iter_mut().filter_map(|g| g.game.get_mut()) {
+            game.set_language(language);
+        }
+    }
+
+    /// 切换配色，菜单、所有游戏和老板键界面立即使用新的样式
+    pub fn set_theme(&mut self, name: ThemeName) {
+        self.theme = Theme::new(name);
+        self.compiling.set_theme(&self.theme);
+        for game in self.games.iter_mut().filter_map(|g| g.game.get_mut()) {
+            game.set_theme(&self.theme);
+        }
+    }
+
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// 切换棋盘上使用的字符集
+    pub fn set_glyphs(&mut self, set: GlyphSet) {
+        self.glyphs = Glyphs::new(set);
+        self.translations.set_glyphs(&self.glyphs);
+        for game in self.games.iter_mut().filter_map(|g| g.game.get_mut()) {
+            game.set_glyphs(&self.glyphs);
         }
     }
 
+    pub fn glyphs(&self) -> &Glyphs {
+        &self.glyphs
+    }
+
     pub fn set_compile_language(&mut self, lang: CompileLanguage) {
         self.compile_language = lang;
-        for game_info in &mut self.games {
-            game_info.game.set_compile_language(lang);
-        }
+        self.compiling.set_language(lang);
+    }
+}
+
+// 在 area 中央取出不超过 area 的矩形
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+fn cycle<T: Copy + PartialEq>(values: &[T], current: T, forward: bool) -> T {
+    let index = values.iter().position(|&v| v == current).unwrap_or(0);
+    let next = if forward {
+        (index + 1) % values.len()
+    } else {
+        (index + values.len() - 1) % values.len()
+    };
+    values[next]
+}
+
+// Frame 不暴露缓冲区，通过 Widget 把绘制转交给 GameManager::draw
+struct ManagerView<'a>(&'a mut GameManager);
+
+impl Widget for ManagerView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.0.draw(buf, area);
+    }
+}
+
+// 只包含自带的游戏，不读写任何文件；程序启动时用 `GameManager::new` 读取用户的设置和记录
+impl Default for GameManager {
+    fn default() -> Self {
+        Self::with_registry(GameRegistry::builtin())
     }
 }
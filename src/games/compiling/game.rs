@@ -1,37 +1,51 @@
 use std::collections::VecDeque;
-use std::io::Stdout;
+use std::time::Duration;
 use tui::{
-    backend::CrosstermBackend,
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph},
-    Frame,
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 
-use crate::game_manager::CompileLanguage;
+use crate::{game_manager::CompileLanguage, theme::Theme};
+
+// 每隔多久滚动一行编译信息
+const SCROLL_INTERVAL: Duration = Duration::from_millis(80);
 
 pub struct Compiling {
     messages: VecDeque<String>,
-    tick_count: u32,
+    scroll_timer: Duration,
     display_messages: Vec<String>,
     current_language: CompileLanguage,
+    theme: Theme,
+}
+
+impl Default for Compiling {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Compiling {
     pub fn new() -> Self {
         let mut compiling = Self {
             messages: VecDeque::new(),
-            tick_count: 0,
+            scroll_timer: Duration::ZERO,
             display_messages: Vec::new(),
             current_language: CompileLanguage::Rust,
+            theme: Theme::default(),
         };
         compiling.set_language(CompileLanguage::Rust);
         compiling
     }
 
+    /// 单色配色下编译输出也不带颜色
+    pub fn set_theme(&mut self, theme: &Theme) {
+        self.theme = *theme;
+    }
+
     pub fn set_language(&mut self, lang: CompileLanguage) {
-        self.current_language = lang.clone();
+        self.current_language = lang;
         self.messages.clear();
 
         let compile_messages = match lang {
@@ -98,17 +112,18 @@ impl Compiling {
             .extend(compile_messages.iter().map(|&s| s.to_string()));
     }
 
-    pub fn update(&mut self) {
-        self.tick_count += 1;
+    pub fn update(&mut self, dt: Duration) {
+        self.scroll_timer += dt;
 
-        // 每50ms (假设update每10ms调用一次) 更新一次消息
-        if self.tick_count % 5 == 0 && !self.messages.is_empty() {
-            let first_msg = self.messages.pop_front().unwrap();
-            self.messages.push_back(first_msg);
+        while self.scroll_timer >= SCROLL_INTERVAL {
+            self.scroll_timer -= SCROLL_INTERVAL;
+            if let Some(first_msg) = self.messages.pop_front() {
+                self.messages.push_back(first_msg);
+            }
         }
     }
 
-    pub fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
+    pub fn render(&mut self, buf: &mut Buffer, area: Rect) {
         // 根据区域高度确定显示的消息数量
         let visible_lines = (area.height as usize).saturating_sub(2);
         self.display_messages = self
@@ -127,8 +142,7 @@ impl Compiling {
                 match self.current_language {
                     CompileLanguage::Rust => {
                         if msg.starts_with("Compiling") {
-                            spans
-                                .push(Span::styled("Compiling", Style::default().fg(Color::Green)));
+                            spans.push(Span::styled("Compiling", self.theme.heading));
                             spans.push(Span::raw(" "));
                             spans.push(Span::raw(msg.strip_prefix("Compiling ").unwrap_or(msg)));
                         } else {
@@ -137,7 +151,7 @@ impl Compiling {
                     }
                     CompileLanguage::Go => {
                         if msg.starts_with("go:") {
-                            spans.push(Span::styled("go:", Style::default().fg(Color::Cyan)));
+                            spans.push(Span::styled("go:", self.theme.accent));
                             spans.push(Span::raw(" "));
                             spans.push(Span::raw(msg.strip_prefix("go: ").unwrap_or(msg)));
                         } else {
@@ -146,7 +160,7 @@ impl Compiling {
                     }
                     CompileLanguage::CMake => {
                         if msg.starts_with("--") {
-                            spans.push(Span::styled("--", Style::default().fg(Color::Yellow)));
+                            spans.push(Span::styled("--", self.theme.title));
                             spans.push(Span::raw(" "));
                             spans.push(Span::raw(msg.strip_prefix("-- ").unwrap_or(msg)));
                         } else {
@@ -168,6 +182,6 @@ impl Compiling {
         let paragraph =
             Paragraph::new(spans).block(Block::default().borders(Borders::ALL).title(title));
 
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
     }
 }
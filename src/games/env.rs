@@ -0,0 +1,28 @@
+use std::fmt::Debug;
+
+/// 不需要终端的游戏环境，用来编写和训练自动玩游戏的程序
+///
+/// 环境直接驱动游戏逻辑，跳过欢迎界面、暂停和渲染；实时游戏（俄罗斯方块、贪吃蛇）
+/// 每一步执行一个动作之后再前进一格。
+pub trait GameEnv {
+    /// 每一步可以执行的动作
+    type Action: Copy + PartialEq + Debug;
+    /// 每一步之后可以看到的游戏状态
+    type Observation: Clone + PartialEq + Debug;
+
+    /// 用给定的种子开始新的一局，返回开局时的状态
+    fn reset(&mut self, seed: u64) -> Self::Observation;
+
+    /// 执行一个动作，返回新的状态、这一步的奖励和这一局是否已经结束
+    ///
+    /// 不合法的动作和结束之后的动作都不会改变游戏，奖励为 0。
+    fn step(&mut self, action: Self::Action) -> (Self::Observation, f64, bool);
+
+    /// 当前可以执行的动作，一局结束之后为空
+    fn legal_actions(&self) -> Vec<Self::Action>;
+
+    /// 当前的游戏状态
+    fn observe(&self) -> Self::Observation;
+
+    fn is_done(&self) -> bool;
+}
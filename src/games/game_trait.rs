@@ -1,16 +1,130 @@
-use crate::game_manager::CompileLanguage;
+use crate::games::metadata::GameMetadata;
+use crate::glyphs::Glyphs;
+use crate::keymap::Keymap;
+use crate::saves::SaveData;
+use crate::scores::{GameResult, ScoreEntry};
+use crate::stats::GameEvent;
+use crate::theme::Theme;
 use crate::translation::Language;
-use crossterm::event::KeyCode;
-use std::io::Stdout;
-use tui::{backend::CrosstermBackend, layout::Rect, Frame};
+use crossterm::event::{KeyEvent, MouseEvent};
+use std::time::Duration;
+use tui::{buffer::Buffer, layout::Rect};
+
+/// 游戏难度，在设置界面中按游戏分别选择
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// 配置文件中使用的名字
+    pub fn id(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.id() == id)
+    }
+
+    /// 界面中显示的名字的翻译键
+    pub fn title_key(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "difficulty_easy",
+            Difficulty::Normal => "difficulty_normal",
+            Difficulty::Hard => "difficulty_hard",
+        }
+    }
+}
+
+/// 游戏请求 GameManager 执行的操作，通过 `Game::take_request` 取出
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameRequest {
+    /// 返回主菜单
+    BackToMenu,
+    /// 打开设置界面，关闭后回到这个游戏
+    OpenSettings,
+}
 
 pub trait Game {
     fn new() -> Self
     where
         Self: Sized;
-    fn handle_input(&mut self, key: KeyCode);
-    fn update(&mut self);
+    fn handle_input(&mut self, key: KeyEvent);
+    /// 游戏正在接收文字输入（例如输入名字）时返回 true，
+    /// 此时 `q` 会交给游戏处理，只能用 Ctrl+Q 返回主菜单
+    fn captures_text_input(&self) -> bool {
+        false
+    }
+    /// 推进一个固定的时间步长 `dt`
+    fn update(&mut self, dt: Duration);
+    /// 正在进行一局，不在欢迎界面、暂停菜单或者结束画面；只有这时才计入游戏时间。
+    /// 没有这些画面的游戏不需要实现
+    fn is_playing(&self) -> bool {
+        true
+    }
+    /// 完整显示游戏需要的终端大小（宽, 高），终端更小时 GameManager 显示提示并暂停游戏
+    fn min_size(&self) -> (u16, u16) {
+        (0, 0)
+    }
+    /// 终端大小改变时调用，启动时也会调用一次，游戏可以按新的大小重新布局
+    fn resize(&mut self, _width: u16, _height: u16) {}
+    /// 暂停进行中的一局，不在进行中或者不支持暂停时什么也不做
+    fn pause(&mut self) {}
+    /// 从菜单或命令行进入游戏时调用，第一次进入时游戏在此之前才被创建
+    fn on_enter(&mut self) {}
+    /// 返回主菜单时调用，游戏保留当前的状态，之后可能再次进入
+    fn on_exit(&mut self) {}
+    /// 设置界面、帮助或老板键盖住游戏时调用，在 `on_resume` 之前不会收到 update
+    fn on_suspend(&mut self) {}
+    /// 盖住游戏的界面关闭、回到游戏时调用
+    fn on_resume(&mut self) {}
+    /// 处理鼠标事件，`area` 是游戏上一次渲染时的区域，用于把终端坐标换算成游戏坐标
+    fn handle_mouse(&mut self, _event: MouseEvent, _area: Rect) {}
     fn set_language(&mut self, language: Language);
-    fn set_compile_language(&mut self, lang: CompileLanguage);
-    fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect);
+    /// 只有注册时用 `GameDescriptor::with_difficulty` 声明了难度的游戏才会收到
+    fn set_difficulty(&mut self, _difficulty: Difficulty) {}
+    /// 当前语言下的名字、介绍和操作说明，`?` 打开的帮助由它生成
+    fn metadata(&self) -> GameMetadata {
+        GameMetadata::default()
+    }
+    /// 按键设置修改后调用，之后的输入和帮助文本都应使用新的按键
+    fn set_keymap(&mut self, _keymap: &Keymap) {}
+    /// 切换配色后调用，之后的绘制都应使用新的样式
+    fn set_theme(&mut self, _theme: &Theme) {}
+    /// 切换字符集后调用，棋盘上的方块、食物和地雷等应使用新的字符
+    fn set_glyphs(&mut self, _glyphs: &Glyphs) {}
+    /// 重新设置随机数种子，还没开始的一局会按新的种子重新生成
+    fn set_seed(&mut self, _seed: u64) {}
+    fn render(&mut self, buf: &mut Buffer, area: Rect);
+    /// 一局结束时返回成绩（每次结果只返回一次），由 GameManager 记入排行榜
+    fn take_result(&mut self) -> Option<GameResult> {
+        None
+    }
+    /// 上次调用之后发生的事件，按发生的顺序排列，由 GameManager 记入统计和成就
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        Vec::new()
+    }
+    /// 处理完输入后由 GameManager 调用，每个请求只返回一次
+    fn take_request(&mut self) -> Option<GameRequest> {
+        None
+    }
+    /// 排行榜更新后调用，`rank` 是刚上报的成绩的名次
+    fn set_high_scores(&mut self, _scores: &[ScoreEntry], _rank: Option<usize>) {}
+    /// 返回还没结束的一局的存档，没有可以保存的局时返回 None
+    fn save_state(&self) -> Option<SaveData> {
+        None
+    }
+    /// 载入存档，欢迎界面随后提供继续游戏；存档无法使用时返回 false
+    fn load_state(&mut self, _data: &SaveData) -> bool {
+        false
+    }
 }
@@ -1,46 +1,54 @@
-use crossterm::event::KeyCode;
-use std::{cell::RefCell, io::Stdout, time::Instant};
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::time::Duration;
 use tui::{
-    backend::CrosstermBackend,
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph},
-    Frame,
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 
 use crate::games::goldminer::item::{Item, ItemType};
-use crate::translation::{Language, Translations};
+use crate::translation::{Language, Translations, GOLDMINER_TRANSLATIONS};
 use crate::{
-    game_manager::CompileLanguage,
-    games::game_trait::Game,
-    games::{compiling::Compiling, goldminer::hook::HookState},
+    games::goldminer::hook::HookState,
+    games::metadata::{Control, GameMetadata},
+    games::registry::GameDescriptor,
+    games::shell::ShellGame,
+    glyphs::Glyphs,
+    keymap::{Action, Keymap},
+    saves::SaveData,
+    scores::{self, GameResult, ScoreDetail, ScoreEntry},
+    stats::GameEvent,
+    theme::Theme,
 };
 
-// 添加游戏状态枚举
-#[derive(PartialEq)]
-pub enum GameState {
-    Welcome,
-    Playing,
-    Paused,
-}
-
 pub struct GoldMiner {
     pub hook_x: f32,
     pub hook_y: f32,
     pub hook_angle: f32,
     pub hook_state: HookState,
     pub score: i32,
-    pub last_update: Instant,
     pub items: Vec<Item>,
     pub caught_item: Option<Item>,
     pub window_width: f32,
     pub window_height: f32,
     pub level: i32,
     pub items_collected: i32,
-    pub game_state: GameState,  // 添加游戏状态字段
     translations: Translations, // 添加translations字段
-    compiling: RefCell<Compiling>,
+    theme: Theme,
+    glyphs: Glyphs,
+    started_at: u64,
+    result: Option<GameResult>,
+    // 还没有被 GameManager 取走的事件
+    events: Vec<GameEvent>,
+    high_scores: Vec<ScoreEntry>,
+    high_score_rank: Option<usize>,
+    rng: StdRng,
+    // 生成当前关卡物品之前的随机数状态；还没有抓到任何物品时，终端大小改变后用它
+    // 按新的大小重新生成同样的关卡，载入存档或者抓到物品之后为 None
+    layout_rng: Option<StdRng>,
 }
 
 impl GoldMiner {
@@ -50,26 +58,45 @@ impl GoldMiner {
     ///
     /// 返回一个初始化好的 Game 结构体
     pub fn new() -> GoldMiner {
-        let terminal = crossterm::terminal::size().unwrap();
-        let width = terminal.0 as f32;
-        let height = terminal.1 as f32;
+        Self::with_seed(rand::random())
+    }
+
+    /// 使用给定的随机数种子创建游戏实例
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - 随机数种子，相同的种子（和相同的终端大小）得到相同的物品布局
+    pub fn with_seed(seed: u64) -> GoldMiner {
+        // 不读取实际的终端大小，布局只由种子和 resize 收到的大小决定，回放时才能得到同样的关卡
+        Self::with_size(seed, 80.0, 24.0)
+    }
 
+    // 按给定的场地大小开始新的一局
+    fn with_size(seed: u64, width: f32, height: f32) -> GoldMiner {
         let mut game = GoldMiner {
             hook_x: width / 2.0,
             hook_y: 2.0,
             hook_angle: 0.0,
             hook_state: HookState::Idle,
             score: 0,
-            last_update: Instant::now(),
             items: Vec::new(),
             caught_item: None,
             window_width: width,
             window_height: height,
             level: 1,
             items_collected: 0,
-            game_state: GameState::Welcome, // 初始状态为欢迎界面
-            translations: Translations::new().with_namespace("goldminer"),
-            compiling: RefCell::new(Compiling::new()),
+            translations: Translations::new()
+                .with_table(&GOLDMINER_TRANSLATIONS)
+                .with_namespace("goldminer"),
+            theme: Theme::default(),
+            glyphs: Glyphs::default(),
+            started_at: scores::now(),
+            result: None,
+            events: Vec::new(),
+            high_scores: Vec::new(),
+            high_score_rank: None,
+            rng: StdRng::seed_from_u64(seed),
+            layout_rng: None,
         };
         game.generate_items();
         game
@@ -79,8 +106,8 @@ impl GoldMiner {
     ///
     /// 随机生成金子和石头，并将它们放置在游戏区域内
     pub fn generate_items(&mut self) {
-        use rand::{thread_rng, Rng};
-        let mut rng = thread_rng();
+        self.layout_rng = Some(self.rng.clone());
+        let rng = &mut self.rng;
 
         self.items.clear();
         self.items_collected = 0;
@@ -152,14 +179,8 @@ impl GoldMiner {
     /// 更新游戏状态
     ///
     /// 处理钩子的移动、物品的捕获以及分数的计算
-    pub fn update(&mut self) {
-        if self.game_state == GameState::Paused {
-            self.compiling.borrow_mut().update();
-            return;
-        }
-        let now = Instant::now();
-        let delta = now.duration_since(self.last_update).as_secs_f32();
-        self.last_update = now;
+    pub fn update(&mut self, dt: Duration) {
+        let delta = dt.as_secs_f32();
 
         if self.hook_state == HookState::Idle {
             // 修改：扩展到10个关卡的速度变化
@@ -196,6 +217,7 @@ impl GoldMiner {
                         && (self.hook_y - item.y).abs() < item.size + 1.0
                     {
                         self.caught_item = Some((*item).clone());
+                        self.layout_rng = None;
                         item_to_remove = Some((item.x, item.y));
                         self.hook_state = HookState::Retracting;
                         break;
@@ -226,6 +248,9 @@ impl GoldMiner {
                 if let Some(item) = self.caught_item.take() {
                     self.score += item.value;
                     self.items_collected += 1;
+                    if matches!(item.item_type, ItemType::Gold) {
+                        self.events.push(GameEvent::GoldCollected);
+                    }
 
                     let gold_remaining = self
                         .items
@@ -233,6 +258,13 @@ impl GoldMiner {
                         .any(|i| matches!(i.item_type, ItemType::Gold));
 
                     if !gold_remaining {
+                        // 淘金者没有结束画面，每过一关上报一次，同一局只保留最高的一次
+                        self.result = Some(GameResult {
+                            score: self.score as i64,
+                            detail: ScoreDetail::Level(self.level as u64),
+                            started_at: self.started_at,
+                        });
+                        self.events.push(GameEvent::LevelCompleted);
                         self.level += 1;
                         self.generate_items();
                     }
@@ -246,57 +278,108 @@ impl GoldMiner {
     ///
     /// # Arguments
     ///
-    /// * `key` - 用户按下的键
-    pub fn handle_input(&mut self, key: KeyCode) {
-        match self.game_state {
-            GameState::Welcome => {
-                if key == KeyCode::Enter {
-                    self.game_state = GameState::Playing;
-                }
-            }
-            GameState::Playing => match key {
-                KeyCode::Char(' ') => {
-                    if self.hook_state == HookState::Idle {
-                        self.hook_state = HookState::Extending;
-                    }
-                }
-                KeyCode::Char('p') | KeyCode::Esc => {
-                    self.game_state = GameState::Paused;
-                }
-                _ => {}
-            },
-            GameState::Paused => {
-                if key == KeyCode::Char('p') || key == KeyCode::Esc {
-                    self.game_state = GameState::Playing;
-                }
-            }
+    /// * `action` - 按键对应的操作
+    pub fn handle_action(&mut self, action: Action) {
+        if action == Action::ReleaseHook && self.hook_state == HookState::Idle {
+            self.hook_state = HookState::Extending;
         }
     }
 
-    /// 渲染游戏界面
-    ///
-    /// # Arguments
-    ///
-    /// * `f` - 帧缓冲区
-    /// * `area` - 渲染区域
-    pub fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        match self.game_state {
-            GameState::Welcome => self.render_welcome(f, area),
-            GameState::Playing => self.render_game(f, area),
-            GameState::Paused => self.render_pause(f, area),
+    /// 开始新的一局，保留语言、配色、字符集和排行榜
+    fn reset(&mut self, seed: u64) {
+        // 沿用当前的场地大小，而不是重新读取终端大小
+        let mut game = Self::with_size(seed, self.window_width, self.window_height);
+        std::mem::swap(&mut game.translations, &mut self.translations);
+        game.theme = self.theme;
+        game.glyphs = self.glyphs;
+        game.high_scores = std::mem::take(&mut self.high_scores);
+        *self = game;
+    }
+
+    /// 按新的终端大小重新布局：钩子回到中间；还没有抓到物品的关卡按新的大小重新生成，
+    /// 否则把跑到场地外的物品移回场地边上
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.window_width = width as f32;
+        self.window_height = height as f32;
+        self.hook_x = self.window_width / 2.0;
+        // 开始时窗口太小没有生成物品的关卡也在这里补上
+        if let Some(rng) = self.layout_rng.take() {
+            self.rng = rng;
+            self.generate_items();
+            return;
+        }
+        for item in &mut self.items {
+            item.x = item.x.min(self.window_width - item.size - 1.0).max(1.0);
+            item.y = item.y.min(self.window_height - item.size - 1.0).max(1.0);
+        }
+    }
+
+    /// 保存当前的关卡、分数和剩余物品
+    pub fn save_state(&self) -> Option<SaveData> {
+        // 正在拉回的物品还没有计分，放回场地里保存
+        let items = self.items.iter().chain(self.caught_item.as_ref());
+        let mut data = SaveData::new();
+        data.set("level", self.level);
+        data.set("score", self.score);
+        data.set("items_collected", self.items_collected);
+        data.set_list("items", items);
+        data.set("started_at", self.started_at);
+        Some(data)
+    }
+
+    /// 载入存档，存档不完整时返回 false 并保持原样
+    pub fn load_state(&mut self, data: &SaveData) -> bool {
+        let items = data.get_list::<String>("items").and_then(|items| {
+            items
+                .iter()
+                .map(|item| Item::parse(item))
+                .collect::<Option<Vec<_>>>()
+        });
+        let (level, score, items_collected, items, started_at) = match (
+            data.get("level"),
+            data.get("score"),
+            data.get("items_collected"),
+            items,
+            data.get("started_at"),
+        ) {
+            (Some(level), Some(score), Some(collected), Some(items), Some(started_at)) => {
+                (level, score, collected, items, started_at)
+            }
+            _ => return false,
+        };
+
+        self.level = level;
+        self.score = score;
+        self.items_collected = items_collected;
+        self.items = items;
+        self.caught_item = None;
+        self.layout_rng = None;
+        self.started_at = started_at;
+        self.hook_angle = 0.0;
+        self.hook_y = 2.0;
+        self.hook_state = HookState::Idle;
+        true
+    }
+
+    /// 处理鼠标事件，游戏中点击左键释放钩子
+    pub fn handle_mouse(&mut self, event: MouseEvent) {
+        if event.kind == MouseEventKind::Down(MouseButton::Left)
+            && self.hook_state == HookState::Idle
+        {
+            self.hook_state = HookState::Extending;
         }
     }
 
     // 添加处理欢迎界面的渲染函数
-    fn render_welcome(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        let welcome_text = vec![
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        let mut welcome_text = vec![
             Spans::from(vec![Span::styled(
                 format!(
                     "{} {}!",
                     self.translations.get_text("welcome_to"),
                     self.translations.get_text("goldminer_title")
                 ),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )]),
             Spans::from(""),
             Spans::from(self.translations.get_text("how_to_play")),
@@ -313,72 +396,78 @@ impl GoldMiner {
             Spans::from(self.translations.get_text("heavier_items")),
             Spans::from(self.translations.get_text("more_obstacles")),
             Spans::from(""),
-            Spans::from(self.translations.get_text("controls_title")),
-            Spans::from(self.translations.get_text("space_control")),
+            Spans::from(self.translations.get_global_text("help_controls")),
+        ];
+        let controls = self.metadata().control_lines(&self.translations);
+        welcome_text.extend(controls.into_iter().map(Spans::from));
+        welcome_text.extend([
             Spans::from(self.translations.get_text("quit_control")),
             Spans::from(""),
             Spans::from(vec![Span::styled(
                 self.translations.get_text("press_enter"),
-                Style::default().fg(Color::Green),
+                self.theme.heading,
             )]),
-        ];
+        ]);
+        if resumable {
+            welcome_text.push(Spans::from(Span::styled(
+                self.translations.get_global_text("resume_saved"),
+                self.theme.heading,
+            )));
+        }
+        welcome_text.push(Spans::from(""));
+        welcome_text.extend(scores::table_lines(
+            &self.high_scores,
+            &self.translations,
+            &self.theme,
+        ));
 
         let paragraph = Paragraph::new(welcome_text)
             .block(Block::default().borders(Borders::ALL).title(Span::styled(
                 self.translations.get_text("goldminer_title"),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )))
             .alignment(tui::layout::Alignment::Center);
-        f.render_widget(paragraph, area);
-    }
-
-    // 将原来的 render 函数改名为 render_game
-    fn render_game(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        // 添加最小窗口大小检查
-        if area.width < 20 || area.height < 10 {
-            let warning = vec![
-                Spans::from(vec![Span::styled(
-                    "Window too small!",
-                    Style::default().fg(Color::Red),
-                )]),
-                Spans::from(vec![Span::styled(
-                    "Please resize",
-                    Style::default().fg(Color::Yellow),
-                )]),
-            ];
-            let paragraph = Paragraph::new(warning).block(Block::default().borders(Borders::ALL));
-            f.render_widget(paragraph, area);
-            return;
-        }
-
-        self.window_width = area.width as f32;
-        self.window_height = area.height as f32;
-        self.hook_x = self.window_width / 2.0;
-
-        // let hook_chars = vec!["▼", "▽"];
-        // let rope_chars = vec!["║", "│"];
-        // let frame_index = (self.last_update.elapsed().as_millis() / 200) as usize % 2;
-        // let hook_char = hook_chars[frame_index];
-        // let rope_char = rope_chars[frame_index];
+        paragraph.render(area, buf);
+    }
 
+    /// 渲染游戏界面
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - 帧缓冲区
+    /// * `area` - 渲染区域
+    fn render_game(&self, buf: &mut Buffer, area: Rect) {
         let swing_range = (self.window_width / 2.0) - 10.0;
         let hook_screen_x = self.hook_x + (self.hook_angle.sin() * swing_range);
 
         let mut content = vec![];
 
-        content.push(Spans::from(vec![
+        let mut status = vec![
             Span::styled(
                 format!("{} ", self.translations.get_text("level")),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             ),
-            Span::styled(self.level.to_string(), Style::default().fg(Color::Green)),
+            Span::styled(self.level.to_string(), self.theme.heading),
             Span::raw("  "),
             Span::styled(
                 format!("{} ", self.translations.get_text("score")),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             ),
-            Span::styled(self.score.to_string(), Style::default().fg(Color::Green)),
-        ]));
+            Span::styled(self.score.to_string(), self.theme.heading),
+        ];
+        if self.high_score_rank.is_some() {
+            status.push(Span::raw("  "));
+            status.extend(
+                scores::game_over_line(
+                    &self.high_scores,
+                    self.high_score_rank,
+                    &self.translations,
+                    &self.theme,
+                )
+                .0,
+            );
+        }
+        content.push(Spans::from(status));
 
         for y in 0..area.height {
             let mut line_spans = vec![];
@@ -400,12 +489,20 @@ impl GoldMiner {
                     {
                         match item.item_type {
                             ItemType::Gold => {
-                                char_to_draw = if item.size > 1.5 { '◆' } else { '♦' };
-                                style = Style::default().fg(Color::Yellow);
+                                char_to_draw = if item.size > 1.5 {
+                                    self.glyphs.gold_large
+                                } else {
+                                    self.glyphs.gold_small
+                                };
+                                style = self.theme.treasure;
                             }
                             ItemType::Stone => {
-                                char_to_draw = if item.size > 1.5 { '■' } else { '□' };
-                                style = Style::default().fg(Color::Gray);
+                                char_to_draw = if item.size > 1.5 {
+                                    self.glyphs.stone_large
+                                } else {
+                                    self.glyphs.stone_small
+                                };
+                                style = self.theme.muted;
                             }
                             ItemType::Nothing => {}
                         }
@@ -425,12 +522,20 @@ impl GoldMiner {
                     {
                         match caught.item_type {
                             ItemType::Gold => {
-                                char_to_draw = if caught.size > 1.5 { '◆' } else { '♦' };
-                                style = Style::default().fg(Color::Yellow);
+                                char_to_draw = if caught.size > 1.5 {
+                                    self.glyphs.gold_large
+                                } else {
+                                    self.glyphs.gold_small
+                                };
+                                style = self.theme.treasure;
                             }
                             ItemType::Stone => {
-                                char_to_draw = if caught.size > 1.5 { '■' } else { '□' };
-                                style = Style::default().fg(Color::Gray);
+                                char_to_draw = if caught.size > 1.5 {
+                                    self.glyphs.stone_large
+                                } else {
+                                    self.glyphs.stone_small
+                                };
+                                style = self.theme.muted;
                             }
                             ItemType::Nothing => {}
                         }
@@ -442,9 +547,15 @@ impl GoldMiner {
                 let hook_y = self.hook_y.round() as u16;
 
                 if y == hook_y && x == hook_x {
-                    line_spans.push(Span::styled("▼", Style::default().fg(Color::Red)));
+                    line_spans.push(Span::styled(
+                        self.glyphs.hook.to_string(),
+                        self.theme.danger,
+                    ));
                 } else if x == hook_x && y < hook_y {
-                    line_spans.push(Span::styled("│", Style::default().fg(Color::Red)));
+                    line_spans.push(Span::styled(
+                        self.glyphs.rope.to_string(),
+                        self.theme.danger,
+                    ));
                 } else {
                     line_spans.push(Span::styled(char_to_draw.to_string(), style));
                 }
@@ -455,49 +566,126 @@ impl GoldMiner {
         let paragraph = Paragraph::new(content).block(
             Block::default().borders(Borders::ALL).title(Span::styled(
                 self.translations.get_text("goldminer_title"),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )),
         );
-        f.render_widget(paragraph, area);
-    }
-
-    fn render_pause(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        if self.game_state == GameState::Paused {
-            self.compiling.borrow_mut().render(f, area);
-        }
+        paragraph.render(area, buf);
     }
 
     pub fn set_language(&mut self, language: Language) {
         self.translations.set_language(language);
     }
+}
 
-    pub fn set_compile_language(&mut self, lang: CompileLanguage) {
-        self.compiling.borrow_mut().set_language(lang);
+impl Default for GoldMiner {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl Game for GoldMiner {
-    fn new() -> Self {
-        Self::new()
+impl ShellGame for GoldMiner {
+    fn with_seed(seed: u64) -> Self {
+        GoldMiner::with_seed(seed)
+    }
+
+    fn restart(&mut self, seed: u64) {
+        self.reset(seed)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    const ACTIONS: &'static [Action] = &[Action::ReleaseHook];
+
+    fn handle_action(&mut self, action: Action) {
+        self.handle_action(action)
+    }
+
+    fn update(&mut self, dt: Duration) {
+        self.update(dt)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent, _area: Rect) {
+        self.handle_mouse(event)
     }
 
-    fn handle_input(&mut self, key: KeyCode) {
-        self.handle_input(key)
+    // 再小就放不下状态栏和钩子的摆动范围
+    fn min_size(&self) -> (u16, u16) {
+        (20, 10)
     }
 
-    fn update(&mut self) {
-        self.update()
+    fn resize(&mut self, width: u16, height: u16) {
+        GoldMiner::resize(self, width, height)
     }
 
-    fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        self.render(f, area)
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        GoldMiner::render_welcome(self, buf, area, resumable)
+    }
+
+    fn render(&mut self, buf: &mut Buffer, area: Rect) {
+        self.render_game(buf, area)
     }
 
     fn set_language(&mut self, language: Language) {
         self.set_language(language)
     }
 
-    fn set_compile_language(&mut self, lang: CompileLanguage) {
-        self.set_compile_language(lang)
+    fn set_keymap(&mut self, keymap: &Keymap) {
+        self.translations.set_keymap(keymap)
+    }
+
+    fn set_theme(&mut self, theme: &Theme) {
+        self.theme = *theme;
+    }
+
+    fn set_glyphs(&mut self, glyphs: &Glyphs) {
+        self.glyphs = *glyphs;
+        self.translations.set_glyphs(glyphs);
     }
+
+    fn describe(translations: &Translations) -> GameMetadata {
+        let release = translations.get_text("control_release");
+        GameMetadata {
+            name: translations.get_text("goldminer_title"),
+            description: translations.get_text("description"),
+            controls: vec![
+                Control::keys(&[Action::ReleaseHook], release.clone()),
+                Control::other(translations.get_global_text("input_left_click"), release),
+            ],
+        }
+    }
+
+    fn metadata(&self) -> GameMetadata {
+        Self::describe(&self.translations)
+    }
+
+    fn take_result(&mut self) -> Option<GameResult> {
+        self.result.take()
+    }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn set_high_scores(&mut self, scores: &[ScoreEntry], rank: Option<usize>) {
+        self.high_scores = scores.to_vec();
+        self.high_score_rank = rank;
+    }
+
+    fn save_state(&self) -> Option<SaveData> {
+        GoldMiner::save_state(self)
+    }
+
+    fn load_state(&mut self, data: &SaveData) -> bool {
+        GoldMiner::load_state(self, data)
+    }
+}
+
+pub fn descriptor() -> GameDescriptor {
+    GameDescriptor::shell::<GoldMiner>(
+        "goldminer",
+        "goldminer.goldminer_title",
+        &GOLDMINER_TRANSLATIONS,
+    )
 }
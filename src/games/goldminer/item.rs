@@ -14,3 +14,42 @@ pub struct Item {
     pub size: f32,
     pub weight: f32,
 }
+
+impl Item {
+    /// 从存档格式 `类型:x:y:价值:大小:重量` 解析物品
+    pub fn parse(text: &str) -> Option<Item> {
+        let fields: Vec<&str> = text.split(':').collect();
+        if fields.len() != 6 {
+            return None;
+        }
+        let item_type = match fields[0] {
+            "gold" => ItemType::Gold,
+            "stone" => ItemType::Stone,
+            "nothing" => ItemType::Nothing,
+            _ => return None,
+        };
+        Some(Item {
+            x: fields[1].parse().ok()?,
+            y: fields[2].parse().ok()?,
+            item_type,
+            value: fields[3].parse().ok()?,
+            size: fields[4].parse().ok()?,
+            weight: fields[5].parse().ok()?,
+        })
+    }
+}
+
+impl std::fmt::Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let item_type = match self.item_type {
+            ItemType::Gold => "gold",
+            ItemType::Stone => "stone",
+            ItemType::Nothing => "nothing",
+        };
+        write!(
+            f,
+            "{}:{}:{}:{}:{}:{}",
+            item_type, self.x, self.y, self.value, self.size, self.weight
+        )
+    }
+}
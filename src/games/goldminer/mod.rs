@@ -2,4 +2,4 @@ mod game;
 mod hook;
 mod item;
 
-pub use game::GoldMiner;
+pub use game::{descriptor, GoldMiner};
@@ -0,0 +1,72 @@
+use crate::keymap::Action;
+use crate::translation::Translations;
+
+/// 一条操作说明的输入部分
+#[derive(Clone, Debug, PartialEq)]
+pub enum Input {
+    /// 可以重新绑定的操作，显示时换成当前绑定的按键
+    Actions(Vec<Action>),
+    /// 鼠标等不能重新绑定的输入，已经翻译成当前语言
+    Other(String),
+}
+
+/// 帮助中的一条操作说明：按什么，做什么
+#[derive(Clone, Debug, PartialEq)]
+pub struct Control {
+    pub input: Input,
+    /// 已经翻译成当前语言的说明
+    pub description: String,
+}
+
+impl Control {
+    pub fn keys(actions: &[Action], description: String) -> Self {
+        Self {
+            input: Input::Actions(actions.to_vec()),
+            description,
+        }
+    }
+
+    pub fn other(input: String, description: String) -> Self {
+        Self {
+            input: Input::Other(input),
+            description,
+        }
+    }
+
+    /// 按 `translations` 中的按键设置显示的输入，例如 `←/A →/D`
+    pub fn input_text(&self, translations: &Translations) -> String {
+        match &self.input {
+            Input::Actions(actions) => actions
+                .iter()
+                .map(|&action| translations.key_text(action))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Input::Other(text) => text.clone(),
+        }
+    }
+}
+
+/// 游戏对自己的描述，`?` 打开的帮助和暂停菜单中的操作说明都由它生成
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GameMetadata {
+    /// 当前语言的游戏名
+    pub name: String,
+    /// 一两句话的玩法介绍
+    pub description: String,
+    pub controls: Vec<Control>,
+}
+
+impl GameMetadata {
+    /// 每条操作说明一行，例如 `←/A →/D: Move the block`
+    pub fn control_lines(&self, translations: &Translations) -> Vec<String> {
+        self.controls
+            .iter()
+            .map(|control| {
+                translations
+                    .get_global_text("control_line")
+                    .replace("{input}", &control.input_text(translations))
+                    .replace("{description}", &control.description)
+            })
+            .collect()
+    }
+}
@@ -1,25 +1,57 @@
-use crossterm::event::KeyCode;
-use std::{cell::RefCell, io::Stdout};
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::time::Duration;
 use tui::{
-    backend::CrosstermBackend,
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph},
-    Frame,
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 
-use crate::translation::{Language, Translations};
-use crate::{game_manager::CompileLanguage, games::compiling::Compiling, games::game_trait::Game};
+use crate::translation::{Language, Translations, MINESWEEPER_TRANSLATIONS};
+use crate::{
+    games::env::GameEnv,
+    games::metadata::{Control, GameMetadata},
+    games::registry::GameDescriptor,
+    games::shell::ShellGame,
+    glyphs::Glyphs,
+    keymap::{self, Keymap},
+    saves::SaveData,
+    scores::{self, GameResult, ScoreDetail, ScoreEntry},
+    stats::GameEvent,
+    theme::Theme,
+    Difficulty,
+};
 
 const BOARD_SIZE: usize = 10;
 const MINE_COUNT: usize = 15;
 
-#[derive(PartialEq)]
-pub enum GameState {
-    Welcome,
-    Playing,
-    Paused,
+/// [`GameEnv`] 中的动作，坐标为 `(x, y)`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Reveal(usize, usize),
+    /// 插上或者拔掉旗子
+    Flag(usize, usize),
+}
+
+/// [`GameEnv`] 中看到的一个格子
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cell {
+    Hidden,
+    Flagged,
+    /// 已经揭开，数字是周围地雷的数量
+    Open(u8),
+    /// 一局结束后显示出来的地雷
+    Mine,
+}
+
+/// [`GameEnv`] 中看到的状态
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observation {
+    /// 10 行 10 列，按 `cells[y][x]` 访问
+    pub cells: Vec<Vec<Cell>>,
+    pub is_win: bool,
 }
 
 pub struct MineSweeper {
@@ -31,111 +63,232 @@ pub struct MineSweeper {
     is_win: bool,
     cursor_x: usize,
     cursor_y: usize,
-    language: Language,
-    compile_language: CompileLanguage,
-    game_state: GameState,
     translations: Translations,
-    compiling: RefCell<Compiling>,
+    theme: Theme,
+    glyphs: Glyphs,
     last_click_x: Option<usize>,
     last_click_y: Option<usize>,
+    // 本局已经用掉的时间，只在游戏进行中累计
+    elapsed: Duration,
+    started_at: u64,
+    result: Option<GameResult>,
+    // 还没有被 GameManager 取走的事件
+    events: Vec<GameEvent>,
+    high_scores: Vec<ScoreEntry>,
+    high_score_rank: Option<usize>,
+    rng: StdRng,
+    // 设置中选择的难度，布雷时才用到
+    difficulty: Difficulty,
+    // 当前这一局布雷时使用的难度，中途修改设置不会改变它
+    board_difficulty: Difficulty,
 }
 
-impl Game for MineSweeper {
-    fn new() -> Self {
-        Self::new()
+impl ShellGame for MineSweeper {
+    fn with_seed(seed: u64) -> Self {
+        MineSweeper::with_seed(seed)
     }
 
-    fn handle_input(&mut self, key: KeyCode) {
-        match self.game_state {
-            GameState::Welcome => {
-                if key == KeyCode::Enter {
-                    self.game_state = GameState::Playing;
-                }
+    fn restart(&mut self, seed: u64) {
+        self.reset(seed);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    const ACTIONS: &'static [keymap::Action] = &[
+        keymap::Action::MoveUp,
+        keymap::Action::MoveDown,
+        keymap::Action::MoveLeft,
+        keymap::Action::MoveRight,
+        keymap::Action::Reveal,
+        keymap::Action::Flag,
+    ];
+
+    fn handle_action(&mut self, action: keymap::Action) {
+        match action {
+            keymap::Action::MoveUp if self.cursor_y > 0 => {
+                self.cursor_y -= 1;
             }
-            GameState::Playing => {
-                if self.game_over {
-                    if key == KeyCode::Char('r') {
-                        *self = Self::new();
-                        self.game_state = GameState::Playing;
-                    }
-                    return;
-                }
+            keymap::Action::MoveDown if self.cursor_y < BOARD_SIZE - 1 => {
+                self.cursor_y += 1;
+            }
+            keymap::Action::MoveLeft if self.cursor_x > 0 => {
+                self.cursor_x -= 1;
+            }
+            keymap::Action::MoveRight if self.cursor_x < BOARD_SIZE - 1 => {
+                self.cursor_x += 1;
+            }
+            keymap::Action::Reveal if !self.flagged[self.cursor_y][self.cursor_x] => {
+                self.last_click_x = Some(self.cursor_x);
+                self.last_click_y = Some(self.cursor_y);
+                self.reveal(self.cursor_x, self.cursor_y);
+            }
+            keymap::Action::Flag if !self.revealed[self.cursor_y][self.cursor_x] => {
+                self.toggle_flag(self.cursor_x, self.cursor_y);
+            }
+            _ => {}
+        }
+    }
 
-                match key {
-                    KeyCode::Up | KeyCode::Char('w') => {
-                        if self.cursor_y > 0 {
-                            self.cursor_y -= 1;
-                        }
-                    }
-                    KeyCode::Down | KeyCode::Char('s') => {
-                        if self.cursor_y < BOARD_SIZE - 1 {
-                            self.cursor_y += 1;
-                        }
-                    }
-                    KeyCode::Left | KeyCode::Char('a') => {
-                        if self.cursor_x > 0 {
-                            self.cursor_x -= 1;
-                        }
-                    }
-                    KeyCode::Right | KeyCode::Char('d') => {
-                        if self.cursor_x < BOARD_SIZE - 1 {
-                            self.cursor_x += 1;
-                        }
-                    }
-                    KeyCode::Char(' ') => {
-                        if !self.flagged[self.cursor_y][self.cursor_x] {
-                            self.last_click_x = Some(self.cursor_x);
-                            self.last_click_y = Some(self.cursor_y);
-                            self.reveal(self.cursor_x, self.cursor_y);
-                        }
-                    }
-                    KeyCode::Char('f') => {
-                        if !self.revealed[self.cursor_y][self.cursor_x] {
-                            self.flagged[self.cursor_y][self.cursor_x] = !self.flagged[self.cursor_y][self.cursor_x];
-                            self.check_win();
-                        }
-                    }
-                    KeyCode::Char('p') | KeyCode::Esc => {
-                        self.game_state = GameState::Paused;
-                    }
-                    _ => {}
+    fn handle_mouse(&mut self, event: MouseEvent, area: Rect) {
+        let (x, y) = match Self::cell_at(area, event.column, event.row) {
+            Some(cell) => cell,
+            None => return,
+        };
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.cursor_x = x;
+                self.cursor_y = y;
+                if !self.flagged[y][x] {
+                    self.last_click_x = Some(x);
+                    self.last_click_y = Some(y);
+                    self.reveal(x, y);
                 }
             }
-            GameState::Paused => {
-                if key == KeyCode::Char('p') || key == KeyCode::Esc {
-                    self.game_state = GameState::Playing;
+            MouseEventKind::Down(MouseButton::Right) => {
+                self.cursor_x = x;
+                self.cursor_y = y;
+                if !self.revealed[y][x] {
+                    self.toggle_flag(x, y);
                 }
             }
+            _ => {}
         }
     }
 
-    fn update(&mut self) {
-        if self.game_state == GameState::Paused {
-            self.compiling.borrow_mut().update();
-        }
+    fn update(&mut self, dt: Duration) {
+        self.elapsed += dt;
     }
 
-    fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        match self.game_state {
-            GameState::Welcome => self.render_welcome(f, area),
-            GameState::Playing => self.render_game(f, area),
-            GameState::Paused => self.render_pause(f, area),
-        }
+    fn is_game_over(&self) -> bool {
+        self.game_over
+    }
+
+    // 10×10 的棋盘，每格四列加分隔线
+    fn min_size(&self) -> (u16, u16) {
+        (53, 23)
+    }
+
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        MineSweeper::render_welcome(self, buf, area, resumable);
+    }
+
+    fn render(&mut self, buf: &mut Buffer, area: Rect) {
+        self.render_game(buf, area);
     }
 
     fn set_language(&mut self, language: Language) {
-        self.language = language;
         self.translations.set_language(language);
     }
 
-    fn set_compile_language(&mut self, lang: CompileLanguage) {
-        self.compile_language = lang;
-        self.compiling.borrow_mut().set_language(lang);
+    fn set_keymap(&mut self, keymap: &Keymap) {
+        self.translations.set_keymap(keymap);
+    }
+
+    fn set_theme(&mut self, theme: &Theme) {
+        self.theme = *theme;
+    }
+
+    fn set_glyphs(&mut self, glyphs: &Glyphs) {
+        self.glyphs = *glyphs;
+        self.translations.set_glyphs(glyphs);
+    }
+
+    // 还没有翻开或标记任何格子时按新的难度重新布雷，否则从下一局开始生效
+    fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        let touched = self
+            .revealed
+            .iter()
+            .chain(&self.flagged)
+            .flatten()
+            .any(|&cell| cell);
+        if !touched && !self.game_over {
+            self.mines = vec![vec![false; BOARD_SIZE]; BOARD_SIZE];
+            self.board = vec![vec![0; BOARD_SIZE]; BOARD_SIZE];
+            self.place_mines();
+            self.calculate_numbers();
+        }
+    }
+
+    fn describe(translations: &Translations) -> GameMetadata {
+        let text = |key| translations.get_text(key);
+        let click = |key| translations.get_global_text(key);
+        GameMetadata {
+            name: text("minesweeper_title"),
+            description: text("description"),
+            controls: vec![
+                Control::keys(
+                    &[
+                        keymap::Action::MoveUp,
+                        keymap::Action::MoveDown,
+                        keymap::Action::MoveLeft,
+                        keymap::Action::MoveRight,
+                    ],
+                    text("control_move"),
+                ),
+                Control::keys(&[keymap::Action::Reveal], text("control_reveal")),
+                Control::keys(&[keymap::Action::Flag], text("control_flag")),
+                Control::other(click("input_left_click"), text("control_reveal")),
+                Control::other(click("input_right_click"), text("control_flag")),
+            ],
+        }
+    }
+
+    fn metadata(&self) -> GameMetadata {
+        Self::describe(&self.translations)
+    }
+
+    fn take_result(&mut self) -> Option<GameResult> {
+        self.result.take()
+    }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn set_high_scores(&mut self, scores: &[ScoreEntry], rank: Option<usize>) {
+        self.high_scores = scores.to_vec();
+        self.high_score_rank = rank;
+    }
+
+    fn save_state(&self) -> Option<SaveData> {
+        let mut data = SaveData::new();
+        data.set_grid("mines", &self.mines);
+        data.set_grid("revealed", &self.revealed);
+        data.set_grid("flagged", &self.flagged);
+        data.set("cursor_x", self.cursor_x);
+        data.set("cursor_y", self.cursor_y);
+        data.set("elapsed_ms", self.elapsed.as_millis());
+        data.set("started_at", self.started_at);
+        data.set("difficulty", self.board_difficulty.id());
+        Some(data)
+    }
+
+    fn load_state(&mut self, data: &SaveData) -> bool {
+        self.restore(data).is_some()
+    }
+}
+
+impl Default for MineSweeper {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl MineSweeper {
     pub fn new() -> MineSweeper {
+        Self::with_seed(rand::random())
+    }
+
+    /// 使用给定的随机数种子创建游戏，相同的种子得到相同的地雷布局
+    pub fn with_seed(seed: u64) -> MineSweeper {
+        Self::with_difficulty(seed, Difficulty::Normal)
+    }
+
+    fn with_difficulty(seed: u64, difficulty: Difficulty) -> MineSweeper {
         let mut game = MineSweeper {
             board: vec![vec![0; BOARD_SIZE]; BOARD_SIZE],
             mines: vec![vec![false; BOARD_SIZE]; BOARD_SIZE],
@@ -145,50 +298,146 @@ impl MineSweeper {
             is_win: false,
             cursor_x: 0,
             cursor_y: 0,
-            language: Language::English,
-            compile_language: CompileLanguage::Rust,
-            game_state: GameState::Welcome,
-            translations: Translations::new().with_namespace("minesweeper"),
-            compiling: RefCell::new(Compiling::new()),
+            translations: Translations::new()
+                .with_table(&MINESWEEPER_TRANSLATIONS)
+                .with_namespace("minesweeper"),
+            theme: Theme::default(),
+            glyphs: Glyphs::default(),
             last_click_x: None,
             last_click_y: None,
+            elapsed: Duration::ZERO,
+            started_at: scores::now(),
+            result: None,
+            events: Vec::new(),
+            high_scores: Vec::new(),
+            high_score_rank: None,
+            rng: StdRng::seed_from_u64(seed),
+            difficulty,
+            board_difficulty: difficulty,
         };
         game.place_mines();
         game.calculate_numbers();
         game
     }
 
-    fn render_welcome(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        let welcome_text = vec![
+    // 开始新的一局，保留语言、配色、字符集和排行榜
+    fn reset(&mut self, seed: u64) {
+        let mut game = Self::with_difficulty(seed, self.difficulty);
+        std::mem::swap(&mut game.translations, &mut self.translations);
+        game.theme = self.theme;
+        game.glyphs = self.glyphs;
+        game.high_scores = std::mem::take(&mut self.high_scores);
+        *self = game;
+    }
+
+    // 先解析全部字段再赋值，存档不完整时游戏保持原样
+    fn restore(&mut self, data: &SaveData) -> Option<()> {
+        let mines = data.get_grid("mines", BOARD_SIZE, BOARD_SIZE)?;
+        let revealed = data.get_grid("revealed", BOARD_SIZE, BOARD_SIZE)?;
+        let flagged = data.get_grid("flagged", BOARD_SIZE, BOARD_SIZE)?;
+        let cursor_x = data.get("cursor_x").filter(|&x| x < BOARD_SIZE)?;
+        let cursor_y = data.get("cursor_y").filter(|&y| y < BOARD_SIZE)?;
+        let elapsed_ms = data.get("elapsed_ms")?;
+        let started_at = data.get("started_at")?;
+        // v3 之前的存档没有记录难度，按地雷数推断
+        let board_difficulty = if data.version() < 3 {
+            let count = mines.iter().flatten().filter(|&&mine| mine).count();
+            Difficulty::ALL
+                .into_iter()
+                .find(|&difficulty| Self::mines_for(difficulty) == count)
+                .unwrap_or_default()
+        } else {
+            Difficulty::from_id(&data.get::<String>("difficulty")?)?
+        };
+
+        self.mines = mines;
+        self.revealed = revealed;
+        self.flagged = flagged;
+        self.board = vec![vec![0; BOARD_SIZE]; BOARD_SIZE];
+        self.calculate_numbers();
+        self.cursor_x = cursor_x;
+        self.cursor_y = cursor_y;
+        self.elapsed = Duration::from_millis(elapsed_ms);
+        self.started_at = started_at;
+        self.board_difficulty = board_difficulty;
+        self.last_click_x = None;
+        self.last_click_y = None;
+        self.game_over = false;
+        self.is_win = false;
+        Some(())
+    }
+
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        let mut welcome_text = vec![
             Spans::from(vec![Span::styled(
                 self.translations.get_text("minesweeper_title"),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )]),
             Spans::from(""),
             Spans::from(vec![Span::styled(
                 self.translations.get_text("how_to_play"),
-                Style::default().fg(Color::Green),
+                self.theme.heading,
             )]),
             Spans::from(""),
-            Spans::from(self.translations.get_text("controls")),
-            Spans::from(""),
-            Spans::from(self.translations.get_text("goal")),
+            Spans::from(self.translations.get_text("description")),
             Spans::from(""),
+        ];
+        let goal = self.translations.get_text("goal");
+        welcome_text.extend(goal.lines().map(|line| Spans::from(line.to_string())));
+        welcome_text.push(Spans::from(""));
+        welcome_text.push(Spans::from(
+            self.translations.get_global_text("help_controls"),
+        ));
+        let controls = self.metadata().control_lines(&self.translations);
+        welcome_text.extend(controls.into_iter().map(Spans::from));
+        welcome_text.extend([
+            Spans::from(self.translations.get_text("cursor_tip")),
             Spans::from(""),
             Spans::from(self.translations.get_text("press_enter")),
-        ];
-    
+        ]);
+        if resumable {
+            welcome_text.push(Spans::from(Span::styled(
+                self.translations.get_global_text("resume_saved"),
+                self.theme.heading,
+            )));
+        }
+        welcome_text.push(Spans::from(""));
+        welcome_text.extend(scores::table_lines(
+            &self.high_scores,
+            &self.translations,
+            &self.theme,
+        ));
+
         let paragraph = Paragraph::new(welcome_text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(tui::layout::Alignment::Center);
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
+    }
+
+    /// 把终端坐标换算成棋盘格子，布局与 `render_game` 保持一致：
+    /// 棋盘在边框内居中，每个格子占 4 列，格子之间和每行之间各有一条分隔线
+    fn cell_at(area: Rect, column: u16, row: u16) -> Option<(usize, usize)> {
+        let inner = Block::default().borders(Borders::ALL).inner(area);
+        let board_width = (BOARD_SIZE * 5 + 1) as u16;
+        let left = inner.x + (inner.width / 2).saturating_sub(board_width / 2);
+
+        let dx = column.checked_sub(left + 1)? as usize;
+        let dy = row.checked_sub(inner.y + 1)? as usize;
+        if dx % 5 == 4 || dy % 2 == 1 {
+            return None;
+        }
+
+        let (x, y) = (dx / 5, dy / 2);
+        (x < BOARD_SIZE && y < BOARD_SIZE).then_some((x, y))
     }
 
-    fn render_game(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
+    fn render_game(&self, buf: &mut Buffer, area: Rect) {
         let mut text = vec![];
 
         // 添加顶部边框
-        text.push(Spans::from("┌────┬────┬────┬────┬────┬────┬────┬────┬────┬────┐"));
+        text.push(Spans::from(
+            "┌────┬────┬────┬────┬────┬────┬────┬────┬────┬────┐",
+        ));
 
         for y in 0..BOARD_SIZE {
             let mut line = Vec::new();
@@ -196,7 +445,7 @@ impl MineSweeper {
                 let mut style = Style::default();
                 let cell_str = if self.revealed[y][x] {
                     if self.mines[y][x] {
-                        " 💣 ".to_string()
+                        self.glyphs.mine.to_string()
                     } else {
                         let count = self.board[y][x];
                         if count > 0 {
@@ -206,14 +455,18 @@ impl MineSweeper {
                         }
                     }
                 } else if self.flagged[y][x] {
-                    " 🚩 ".to_string()
+                    self.glyphs.flag.to_string()
                 } else {
-                    "  ■ ".to_string()
+                    self.glyphs.hidden.to_string()
                 };
 
-                // 设置当前光标位置的背景色为黄色
+                // 高亮光标所在的格子，踩中的地雷反色显示
                 if x == self.cursor_x && y == self.cursor_y {
-                    style = style.bg(Color::Yellow);
+                    style = self.theme.highlight;
+                }
+                if self.mines[y][x] && self.last_click_x == Some(x) && self.last_click_y == Some(y)
+                {
+                    style = self.theme.danger.add_modifier(Modifier::REVERSED);
                 }
                 line.push(Span::styled(cell_str, style));
                 line.push(Span::raw("│"));
@@ -222,12 +475,16 @@ impl MineSweeper {
             text.push(Spans::from(line));
 
             if y < BOARD_SIZE - 1 {
-                text.push(Spans::from("├────┼────┼────┼────┼────┼────┼────┼────┼────┼────┤"));
+                text.push(Spans::from(
+                    "├────┼────┼────┼────┼────┼────┼────┼────┼────┼────┤",
+                ));
             }
         }
 
         // 添加底部边框
-        text.push(Spans::from("└────┴────┴────┴────┴────┴────┴────┴────┴────┴────┘"));
+        text.push(Spans::from(
+            "└────┴────┴────┴────┴────┴────┴────┴────┴────┴────┘",
+        ));
 
         // 添加游戏结束提示
         if self.game_over {
@@ -235,35 +492,49 @@ impl MineSweeper {
             if self.is_win {
                 text.push(Spans::from(vec![Span::styled(
                     self.translations.get_text("game_win"),
-                    Style::default().fg(Color::Green),
+                    self.theme.heading,
                 )]));
             } else {
                 text.push(Spans::from(vec![Span::styled(
                     self.translations.get_text("game_over"),
-                    Style::default().fg(Color::Red),
+                    self.theme.danger,
                 )]));
             }
             text.push(Spans::from(self.translations.get_text("press_r_restart")));
+            text.push(scores::game_over_line(
+                &self.high_scores,
+                self.high_score_rank,
+                &self.translations,
+                &self.theme,
+            ));
         }
 
         let paragraph = Paragraph::new(text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(tui::layout::Alignment::Center);
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
+    }
+
+    // 难度越高地雷越多
+    fn mine_count(&self) -> usize {
+        Self::mines_for(self.difficulty)
     }
 
-    fn render_pause(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        self.compiling.borrow_mut().render(f, area);
+    fn mines_for(difficulty: Difficulty) -> usize {
+        match difficulty {
+            Difficulty::Easy => 10,
+            Difficulty::Normal => MINE_COUNT,
+            Difficulty::Hard => 22,
+        }
     }
 
     fn place_mines(&mut self) {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+        self.board_difficulty = self.difficulty;
         let mut mines_placed = 0;
 
-        while mines_placed < MINE_COUNT {
-            let x = rng.gen_range(0..BOARD_SIZE);
-            let y = rng.gen_range(0..BOARD_SIZE);
+        while mines_placed < self.mine_count() {
+            let x = self.rng.gen_range(0..BOARD_SIZE);
+            let y = self.rng.gen_range(0..BOARD_SIZE);
             if !self.mines[y][x] {
                 self.mines[y][x] = true;
                 mines_placed += 1;
@@ -283,10 +554,13 @@ impl MineSweeper {
                             }
                             let nx = x as i32 + dx;
                             let ny = y as i32 + dy;
-                            if nx >= 0 && nx < BOARD_SIZE as i32 && ny >= 0 && ny < BOARD_SIZE as i32 {
-                                if self.mines[ny as usize][nx as usize] {
-                                    count += 1;
-                                }
+                            if nx >= 0
+                                && nx < BOARD_SIZE as i32
+                                && ny >= 0
+                                && ny < BOARD_SIZE as i32
+                                && self.mines[ny as usize][nx as usize]
+                            {
+                                count += 1;
                             }
                         }
                     }
@@ -334,6 +608,15 @@ impl MineSweeper {
         self.check_win();
     }
 
+    // 插上或者拔掉旗子，插旗也可能正好标出了所有地雷
+    fn toggle_flag(&mut self, x: usize, y: usize) {
+        self.flagged[y][x] = !self.flagged[y][x];
+        if self.flagged[y][x] {
+            self.events.push(GameEvent::MineFlagged);
+        }
+        self.check_win();
+    }
+
     fn check_win(&mut self) {
         // 检查是否所有非地雷格子都已揭开
         let mut all_safe_revealed = true;
@@ -365,6 +648,16 @@ impl MineSweeper {
 
         // 任一条件满足即胜利
         if all_safe_revealed || all_mines_flagged {
+            // 只记录胜利的局，用时越短分数越高
+            if !self.is_win {
+                let seconds = self.elapsed.as_secs();
+                self.result = Some(GameResult {
+                    score: 1000_i64.saturating_sub(seconds as i64).max(1),
+                    detail: ScoreDetail::Seconds(seconds),
+                    started_at: self.started_at,
+                });
+                self.events.push(GameEvent::Won(self.board_difficulty));
+            }
             self.is_win = true;
             self.game_over = true;
             // 胜利时显示所有地雷位置
@@ -378,3 +671,97 @@ impl MineSweeper {
         }
     }
 }
+
+/// 奖励是这一步揭开的安全格子数，踩到地雷时为 -1
+impl GameEnv for MineSweeper {
+    type Action = Action;
+    type Observation = Observation;
+
+    fn reset(&mut self, seed: u64) -> Observation {
+        self.reset(seed);
+        self.observe()
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f64, bool) {
+        let safe_revealed = |game: &Self| {
+            (0..BOARD_SIZE)
+                .flat_map(|y| (0..BOARD_SIZE).map(move |x| (x, y)))
+                .filter(|&(x, y)| game.revealed[y][x] && !game.mines[y][x])
+                .count()
+        };
+        let before = safe_revealed(self);
+        let mut reward = 0.0;
+        if self.legal_actions().contains(&action) {
+            match action {
+                Action::Reveal(x, y) => {
+                    self.last_click_x = Some(x);
+                    self.last_click_y = Some(y);
+                    self.reveal(x, y);
+                    reward = if self.game_over && !self.is_win {
+                        -1.0
+                    } else {
+                        (safe_revealed(self) - before) as f64
+                    };
+                }
+                Action::Flag(x, y) => self.toggle_flag(x, y),
+            }
+        }
+        (self.observe(), reward, self.game_over)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        if self.game_over {
+            return Vec::new();
+        }
+        let mut actions = Vec::new();
+        for y in 0..BOARD_SIZE {
+            for x in 0..BOARD_SIZE {
+                if self.revealed[y][x] {
+                    continue;
+                }
+                if !self.flagged[y][x] {
+                    actions.push(Action::Reveal(x, y));
+                }
+                actions.push(Action::Flag(x, y));
+            }
+        }
+        actions
+    }
+
+    fn observe(&self) -> Observation {
+        let cells = (0..BOARD_SIZE)
+            .map(|y| {
+                (0..BOARD_SIZE)
+                    .map(|x| {
+                        if self.revealed[y][x] && self.mines[y][x] {
+                            Cell::Mine
+                        } else if self.revealed[y][x] {
+                            Cell::Open(self.board[y][x])
+                        } else if self.flagged[y][x] {
+                            Cell::Flagged
+                        } else {
+                            Cell::Hidden
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Observation {
+            cells,
+            is_win: self.is_win,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.game_over
+    }
+}
+
+pub fn descriptor() -> GameDescriptor {
+    GameDescriptor::shell::<MineSweeper>(
+        "minesweeper",
+        "minesweeper.minesweeper_title",
+        &MINESWEEPER_TRANSLATIONS,
+    )
+    .with_difficulty()
+}
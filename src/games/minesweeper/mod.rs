@@ -1,3 +1,3 @@
 mod game;
 
-pub use game::MineSweeper;
+pub use game::{descriptor, Action, Cell, MineSweeper, Observation};
@@ -1,4 +1,9 @@
+pub mod env;
 pub mod game_trait;
+pub mod metadata;
+pub mod pause;
+pub mod registry;
+pub mod shell;
 
 pub mod compiling;
 pub mod goldminer;
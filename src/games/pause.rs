@@ -0,0 +1,211 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use tui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::{
+    games::metadata::{Control, GameMetadata},
+    keymap::{Action, Keymap},
+    theme::Theme,
+    translation::{Language, Translations},
+};
+
+/// 暂停菜单中选择的操作，由 [`GameShell`](super::shell::GameShell) 执行
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PauseAction {
+    Resume,
+    Restart,
+    Settings,
+    MainMenu,
+}
+
+/// 暂停菜单当前显示的页面
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PauseView {
+    Menu,
+    Controls,
+}
+
+// 菜单项的翻译键，顺序与 handle_input 中的下标一致
+const ITEMS: [&str; 5] = [
+    "pause_resume",
+    "pause_restart",
+    "pause_controls",
+    "pause_settings",
+    "pause_main_menu",
+];
+
+/// 盖在暂停的游戏画面上的菜单：继续、重新开始、查看操作说明、设置和返回主菜单
+pub struct PauseMenu {
+    view: PauseView,
+    selected: usize,
+    // 暂停键同时用来关闭菜单
+    keymap: Keymap,
+    theme: Theme,
+    translations: Translations,
+}
+
+impl Default for PauseMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PauseMenu {
+    pub fn new() -> Self {
+        Self {
+            view: PauseView::Menu,
+            selected: 0,
+            keymap: Keymap::new(),
+            theme: Theme::default(),
+            translations: Translations::new(),
+        }
+    }
+
+    /// 每次暂停都从菜单第一项开始
+    pub fn open(&mut self) {
+        self.view = PauseView::Menu;
+        self.selected = 0;
+    }
+
+    pub fn view(&self) -> PauseView {
+        self.view
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.translations.set_language(language);
+    }
+
+    pub fn set_keymap(&mut self, keymap: &Keymap) {
+        self.keymap = keymap.clone();
+        self.translations.set_keymap(keymap);
+    }
+
+    pub fn set_theme(&mut self, theme: &Theme) {
+        self.theme = *theme;
+    }
+
+    /// 由外壳处理的暂停和重新开始的操作说明
+    pub fn shell_controls(&self) -> Vec<Control> {
+        Self::controls(&self.translations)
+    }
+
+    /// 同 `shell_controls`，`translations` 可以使用任意命名空间
+    pub fn controls(translations: &Translations) -> Vec<Control> {
+        vec![
+            Control::keys(
+                &[Action::Pause],
+                translations.get_global_text("control_pause"),
+            ),
+            Control::keys(
+                &[Action::Restart],
+                translations.get_global_text("control_restart"),
+            ),
+        ]
+    }
+
+    fn is_pause_key(&self, key: KeyEvent) -> bool {
+        self.keymap.action(key, &[Action::Pause]).is_some()
+    }
+
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<PauseAction> {
+        match self.view {
+            PauseView::Menu => match key.code {
+                _ if self.is_pause_key(key) => return Some(PauseAction::Resume),
+                KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down => self.selected = (self.selected + 1).min(ITEMS.len() - 1),
+                KeyCode::Enter => match self.selected {
+                    0 => return Some(PauseAction::Resume),
+                    1 => return Some(PauseAction::Restart),
+                    2 => self.view = PauseView::Controls,
+                    3 => return Some(PauseAction::Settings),
+                    _ => return Some(PauseAction::MainMenu),
+                },
+                _ => {}
+            },
+            PauseView::Controls => match key.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Backspace => self.view = PauseView::Menu,
+                _ if self.is_pause_key(key) => return Some(PauseAction::Resume),
+                _ => {}
+            },
+        }
+        None
+    }
+
+    /// 在 `area` 中央绘制菜单，操作说明页面显示 `metadata` 中游戏自己的操作
+    pub fn render(&self, buf: &mut Buffer, area: Rect, metadata: &GameMetadata) {
+        let mut text = vec![Spans::from("")];
+        match self.view {
+            PauseView::Menu => {
+                for (index, key) in ITEMS.iter().enumerate() {
+                    let style = if index == self.selected {
+                        self.theme.highlight
+                    } else {
+                        Style::default()
+                    };
+                    text.push(Spans::from(Span::styled(
+                        format!(" {} ", self.translations.get_text(key)),
+                        style,
+                    )));
+                }
+                text.push(Spans::from(""));
+                text.push(Spans::from(self.translations.get_text("pause_hint")));
+            }
+            PauseView::Controls => {
+                let controls = metadata.control_lines(&self.translations);
+                text.extend(controls.into_iter().map(Spans::from));
+                text.push(Spans::from(""));
+                for line in self
+                    .translations
+                    .get_text("pause_shell_controls")
+                    .split('\n')
+                {
+                    text.push(Spans::from(line.to_string()));
+                }
+                text.push(Spans::from(""));
+                text.push(Spans::from(self.translations.get_text("back_to_menu")));
+            }
+        }
+
+        let title = match self.view {
+            PauseView::Menu => "pause_title",
+            PauseView::Controls => "pause_controls",
+        };
+        let width = text
+            .iter()
+            .map(|line| line.width() as u16 + 4)
+            .max()
+            .unwrap_or(0)
+            .max(24);
+        let overlay = centered(area, width, text.len() as u16 + 3);
+
+        Clear.render(overlay, buf);
+        Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(Span::styled(
+                self.translations.get_text(title),
+                self.theme.title,
+            )))
+            .alignment(Alignment::Center)
+            .render(overlay, buf);
+    }
+}
+
+// 在 area 中央取出不超过 area 的矩形
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
@@ -0,0 +1,154 @@
+use crate::games::metadata::GameMetadata;
+use crate::games::shell::{GameShell, ShellGame};
+use crate::games::{goldminer, minesweeper, snake, tetris, twenty_forty_eight};
+use crate::keymap::Action;
+use crate::translation::{TranslationTable, Translations};
+use crate::Game;
+
+/// 描述一个可注册的游戏
+///
+/// 每个游戏模块通过 `descriptor()` 提供自己的描述，`GameManager` 只遍历描述，
+/// 不再需要为每个游戏修改枚举或翻译加载代码。
+pub struct GameDescriptor {
+    /// 唯一标识，例如 `"tetris"`
+    pub id: &'static str,
+    /// 菜单中显示的标题翻译键，例如 `"tetris.tetris_title"`
+    pub title_key: &'static str,
+    /// 游戏自带的翻译表，会被合并进主菜单的翻译
+    pub translations: &'static TranslationTable,
+    /// 游戏支持选择难度，设置界面会为它显示一行
+    pub has_difficulty: bool,
+    /// 游戏用到的可以重新绑定的操作，设置界面按它列出按键并检查同一个游戏中的冲突，
+    /// 这样打开设置时不需要创建游戏
+    pub actions: Vec<Action>,
+    // 主菜单上的帮助，不需要创建游戏
+    help: fn(&Translations) -> GameMetadata,
+    factory: Box<dyn Fn(u64) -> Box<dyn Game>>,
+}
+
+impl GameDescriptor {
+    pub fn new<F>(
+        id: &'static str,
+        title_key: &'static str,
+        translations: &'static TranslationTable,
+        factory: F,
+    ) -> Self
+    where
+        F: Fn(u64) -> Box<dyn Game> + 'static,
+    {
+        Self {
+            id,
+            title_key,
+            translations,
+            has_difficulty: false,
+            actions: Vec::new(),
+            help: |_| GameMetadata::default(),
+            factory: Box::new(factory),
+        }
+    }
+
+    /// 描述一个由 [`GameShell`] 包装的游戏，操作列表直接取自 `G::ACTIONS` 和外壳自己的操作，
+    /// 帮助取自 `G::describe`
+    pub fn shell<G: ShellGame + 'static>(
+        id: &'static str,
+        title_key: &'static str,
+        translations: &'static TranslationTable,
+    ) -> Self {
+        Self::new(id, title_key, translations, |seed| {
+            Box::new(GameShell::<G>::with_seed(seed))
+        })
+        .with_actions(GameShell::<G>::all_actions())
+        .with_help(GameShell::<G>::describe)
+    }
+
+    /// 声明游戏支持 `Game::set_difficulty`
+    pub fn with_difficulty(mut self) -> Self {
+        self.has_difficulty = true;
+        self
+    }
+
+    /// 声明直接实现 `Game` 的游戏用到的操作，`GameDescriptor::shell` 会自动填好
+    pub fn with_actions(mut self, actions: Vec<Action>) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    /// 声明直接实现 `Game` 的游戏在主菜单上的帮助，`GameDescriptor::shell` 会自动填好；
+    /// 传入的翻译以游戏的 id 为命名空间
+    pub fn with_help(mut self, help: fn(&Translations) -> GameMetadata) -> Self {
+        self.help = help;
+        self
+    }
+
+    /// 不创建游戏，按 `translations` 的语言和按键取得帮助
+    pub fn help(&self, translations: &Translations) -> GameMetadata {
+        let translations = translations
+            .clone()
+            .with_table(self.translations)
+            .with_namespace(self.id);
+        (self.help)(&translations)
+    }
+
+    /// 用给定的随机数种子创建一个新的游戏实例，相同的种子得到相同的开局
+    pub fn create(&self, seed: u64) -> Box<dyn Game> {
+        (self.factory)(seed)
+    }
+}
+
+/// 按注册顺序保存所有游戏描述
+#[derive(Default)]
+pub struct GameRegistry {
+    descriptors: Vec<GameDescriptor>,
+}
+
+impl GameRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 包含本仓库自带的所有游戏
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register(goldminer::descriptor())
+            .register(tetris::descriptor())
+            .register(snake::descriptor())
+            .register(twenty_forty_eight::descriptor())
+            .register(minesweeper::descriptor());
+        registry
+    }
+
+    /// 注册一个游戏；如果 id 已存在，则在原位置替换
+    pub fn register(&mut self, descriptor: GameDescriptor) -> &mut Self {
+        match self.descriptors.iter().position(|d| d.id == descriptor.id) {
+            Some(index) => self.descriptors[index] = descriptor,
+            None => self.descriptors.push(descriptor),
+        }
+        self
+    }
+
+    pub fn find(&self, id: &str) -> Option<&GameDescriptor> {
+        self.descriptors.iter().find(|d| d.id == id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &GameDescriptor> {
+        self.descriptors.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.descriptors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.descriptors.is_empty()
+    }
+}
+
+impl IntoIterator for GameRegistry {
+    type Item = GameDescriptor;
+    type IntoIter = std::vec::IntoIter<GameDescriptor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.descriptors.into_iter()
+    }
+}
@@ -0,0 +1,422 @@
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::time::Duration;
+use tui::{buffer::Buffer, layout::Rect};
+
+use crate::{
+    games::metadata::GameMetadata,
+    games::pause::{PauseAction, PauseMenu},
+    glyphs::Glyphs,
+    keymap::{Action, Keymap},
+    saves::SaveData,
+    scores::{GameResult, ScoreEntry},
+    stats::GameEvent,
+    theme::Theme,
+    translation::{Language, Translations},
+    Difficulty, Game, GameRequest,
+};
+
+/// 游戏外壳所处的阶段
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameState {
+    Welcome,
+    Playing,
+    /// 游戏画面上显示暂停菜单
+    Paused,
+}
+
+/// 由 [`GameShell`] 包装的游戏，只需要实现一局进行中的逻辑
+///
+/// 欢迎界面、暂停菜单以及结束后重新开始都由外壳处理，所有游戏的这些行为因此保持一致。
+/// 外壳按照按键设置把按键换成 [`Action`]，游戏只需要处理自己声明的操作。
+pub trait ShellGame {
+    fn with_seed(seed: u64) -> Self
+    where
+        Self: Sized;
+    /// 用给定的种子开始新的一局，保留语言和排行榜
+    fn restart(&mut self, seed: u64);
+    /// 只更换随机数种子，不影响正在进行的一局
+    fn reseed(&mut self, seed: u64);
+    /// 进行中用到的操作，暂停和重新开始由外壳处理，不需要列出
+    const ACTIONS: &'static [Action];
+    /// 处理进行中的操作，只会收到 `actions` 中列出的操作
+    fn handle_action(&mut self, action: Action);
+    fn handle_mouse(&mut self, _event: MouseEvent, _area: Rect) {}
+    /// 推进进行中的一局，暂停和结束后不会调用
+    fn update(&mut self, _dt: Duration) {}
+    /// 完整显示欢迎界面和棋盘需要的终端大小（宽, 高）
+    fn min_size(&self) -> (u16, u16);
+    /// 终端大小改变时调用，按终端大小布局的游戏需要实现
+    fn resize(&mut self, _width: u16, _height: u16) {}
+    /// 这一局已经结束，等待重新开始
+    fn is_game_over(&self) -> bool {
+        false
+    }
+    /// 绘制欢迎界面，`resumable` 为 true 时应提示可以按 C 继续存档
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool);
+    /// 绘制进行中（包括已经结束）的一局
+    fn render(&mut self, buf: &mut Buffer, area: Rect);
+    fn set_language(&mut self, language: Language);
+    /// 按键设置修改后调用，游戏的翻译需要它来显示实际绑定的按键
+    fn set_keymap(&mut self, keymap: &Keymap);
+    /// 切换配色时调用，重新开始的一局也要保留配色
+    fn set_theme(&mut self, theme: &Theme);
+    /// 切换字符集时调用，只用文字绘制的游戏不需要实现
+    fn set_glyphs(&mut self, _glyphs: &Glyphs) {}
+    /// 不需要游戏实例的名字、介绍和操作说明，`translations` 使用游戏自己的命名空间；
+    /// 主菜单上的帮助通过 `GameDescriptor` 读取它，不用为此创建游戏
+    fn describe(translations: &Translations) -> GameMetadata
+    where
+        Self: Sized;
+    /// 当前语言下的名字、介绍和操作说明，欢迎界面、暂停菜单和帮助都用它显示操作
+    fn metadata(&self) -> GameMetadata;
+    /// 设置界面中修改难度时调用，没有声明难度的游戏不会收到
+    fn set_difficulty(&mut self, _difficulty: Difficulty) {}
+    fn take_result(&mut self) -> Option<GameResult> {
+        None
+    }
+    /// 进行中发生的事件，新的一局开始由外壳上报，不需要游戏自己记录
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        Vec::new()
+    }
+    fn set_high_scores(&mut self, _scores: &[ScoreEntry], _rank: Option<usize>) {}
+    /// 保存进行中的一局，外壳只在有东西值得保存时调用
+    fn save_state(&self) -> Option<SaveData> {
+        None
+    }
+    fn load_state(&mut self, _data: &SaveData) -> bool {
+        false
+    }
+}
+
+/// 为 [`ShellGame`] 处理欢迎、暂停和结束等阶段，并实现 [`Game`]
+pub struct GameShell<G> {
+    game: G,
+    state: GameState,
+    pause: PauseMenu,
+    keymap: Keymap,
+    // 暂停菜单中选择的、需要 GameManager 执行的操作
+    request: Option<GameRequest>,
+    // 已经载入了存档，欢迎界面可以继续上次的游戏
+    resumable: bool,
+    // 生成重新开始时使用的种子
+    rng: StdRng,
+    // 外壳自己上报的事件（开始新的一局），在游戏的事件之前取走
+    events: Vec<GameEvent>,
+}
+
+impl<G: ShellGame> GameShell<G> {
+    pub fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    /// 使用给定的随机数种子创建游戏，相同的种子得到相同的开局
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            game: G::with_seed(seed),
+            state: GameState::Welcome,
+            pause: PauseMenu::new(),
+            keymap: Keymap::new(),
+            request: None,
+            resumable: false,
+            rng: StdRng::seed_from_u64(seed),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    pub fn game_mut(&mut self) -> &mut G {
+        &mut self.game
+    }
+
+    pub fn state(&self) -> GameState {
+        self.state
+    }
+
+    fn restart(&mut self) {
+        let seed = self.rng.gen();
+        self.game.restart(seed);
+        self.resumable = false;
+        self.start();
+    }
+
+    fn start(&mut self) {
+        self.state = GameState::Playing;
+        self.events.push(GameEvent::Started);
+    }
+
+    pub fn pause_menu(&self) -> &PauseMenu {
+        &self.pause
+    }
+
+    fn pause(&mut self) {
+        self.pause.open();
+        self.state = GameState::Paused;
+    }
+
+    /// 正在进行、还没有结束的一局
+    pub fn is_playing(&self) -> bool {
+        self.state == GameState::Playing && !self.game.is_game_over()
+    }
+
+    /// 只暂停进行中、还没有结束的一局
+    pub fn auto_pause(&mut self) {
+        if self.is_playing() {
+            self.pause();
+        }
+    }
+
+    fn apply(&mut self, action: PauseAction) {
+        match action {
+            PauseAction::Resume => self.state = GameState::Playing,
+            PauseAction::Restart => self.restart(),
+            PauseAction::Settings => self.request = Some(GameRequest::OpenSettings),
+            PauseAction::MainMenu => self.request = Some(GameRequest::BackToMenu),
+        }
+    }
+
+    pub fn handle_input(&mut self, key: KeyEvent) {
+        match self.state {
+            GameState::Welcome => match key.code {
+                KeyCode::Enter if self.resumable => self.restart(),
+                KeyCode::Enter => self.start(),
+                KeyCode::Char('c') if self.resumable => {
+                    self.resumable = false;
+                    self.state = GameState::Playing;
+                }
+                _ => {}
+            },
+            GameState::Playing if self.game.is_game_over() => {
+                if self.keymap.action(key, &[Action::Restart]).is_some() {
+                    self.restart();
+                }
+            }
+            GameState::Playing => {
+                if self.keymap.action(key, &[Action::Pause]).is_some() {
+                    self.pause();
+                } else if let Some(action) = self.keymap.action(key, G::ACTIONS) {
+                    self.game.handle_action(action);
+                }
+            }
+            GameState::Paused => {
+                if let Some(action) = self.pause.handle_input(key) {
+                    self.apply(action);
+                }
+            }
+        }
+    }
+
+    pub fn handle_mouse(&mut self, event: MouseEvent, area: Rect) {
+        if self.is_playing() {
+            self.game.handle_mouse(event, area);
+        }
+    }
+
+    pub fn update(&mut self, dt: Duration) {
+        if self.is_playing() {
+            self.game.update(dt);
+        }
+    }
+
+    pub fn render(&mut self, buf: &mut Buffer, area: Rect) {
+        match self.state {
+            GameState::Welcome => self.game.render_welcome(buf, area, self.resumable),
+            GameState::Playing => self.game.render(buf, area),
+            GameState::Paused => {
+                self.game.render(buf, area);
+                self.pause.render(buf, area, &self.game.metadata());
+            }
+        }
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.game.set_language(language);
+        self.pause.set_language(language);
+    }
+
+    pub fn set_keymap(&mut self, keymap: &Keymap) {
+        self.keymap = keymap.clone();
+        self.game.set_keymap(keymap);
+        self.pause.set_keymap(keymap);
+    }
+
+    pub fn set_theme(&mut self, theme: &Theme) {
+        self.game.set_theme(theme);
+        self.pause.set_theme(theme);
+    }
+
+    pub fn set_glyphs(&mut self, glyphs: &Glyphs) {
+        self.game.set_glyphs(glyphs);
+    }
+
+    /// 外壳处理的暂停和重新开始，加上游戏自己的操作
+    pub fn actions(&self) -> Vec<Action> {
+        Self::all_actions()
+    }
+
+    /// 与 `actions` 相同，但不需要创建游戏，`GameDescriptor::shell` 用它填写操作列表
+    pub fn all_actions() -> Vec<Action> {
+        let mut actions = vec![Action::Pause, Action::Restart];
+        actions.extend_from_slice(G::ACTIONS);
+        actions
+    }
+
+    /// 外壳和游戏上次调用之后的事件
+    pub fn take_events(&mut self) -> Vec<GameEvent> {
+        let mut events = std::mem::take(&mut self.events);
+        events.extend(self.game.take_events());
+        events
+    }
+
+    /// 同 `metadata`，但是不需要创建游戏，`GameDescriptor::shell` 用它提供帮助
+    pub fn describe(translations: &Translations) -> GameMetadata {
+        let mut metadata = G::describe(translations);
+        metadata.controls.extend(PauseMenu::controls(translations));
+        metadata
+    }
+
+    /// 游戏自己的操作说明，加上外壳处理的暂停和重新开始
+    pub fn metadata(&self) -> GameMetadata {
+        let mut metadata = self.game.metadata();
+        metadata.controls.extend(self.pause.shell_controls());
+        metadata
+    }
+
+    /// 重新设置随机数种子，还在欢迎界面的新一局会按新的种子重新生成
+    pub fn set_seed(&mut self, seed: u64) {
+        if self.is_fresh() {
+            self.game.restart(seed);
+        } else {
+            self.game.reseed(seed);
+        }
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    // 停在欢迎界面、还没有开始的新一局
+    fn is_fresh(&self) -> bool {
+        self.state == GameState::Welcome && !self.resumable
+    }
+
+    /// 还没开始或者已经结束的一局不需要保存
+    pub fn save_state(&self) -> Option<SaveData> {
+        if self.game.is_game_over() || self.is_fresh() {
+            return None;
+        }
+        self.game.save_state()
+    }
+
+    /// 载入存档后回到欢迎界面，玩家可以选择继续或者开始新的一局
+    pub fn load_state(&mut self, data: &SaveData) -> bool {
+        if !self.game.load_state(data) {
+            return false;
+        }
+        self.state = GameState::Welcome;
+        self.resumable = true;
+        true
+    }
+}
+
+impl<G: ShellGame> Default for GameShell<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: ShellGame> Game for GameShell<G> {
+    fn new() -> Self {
+        GameShell::new()
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        GameShell::handle_input(self, key);
+    }
+
+    fn update(&mut self, dt: Duration) {
+        GameShell::update(self, dt);
+    }
+
+    fn is_playing(&self) -> bool {
+        GameShell::is_playing(self)
+    }
+
+    fn min_size(&self) -> (u16, u16) {
+        self.game.min_size()
+    }
+
+    fn resize(&mut self, width: u16, height: u16) {
+        self.game.resize(width, height);
+    }
+
+    fn pause(&mut self) {
+        GameShell::auto_pause(self);
+    }
+
+    // 回到主菜单时停在暂停菜单，再次进入后由玩家选择继续；
+    // 被设置界面、帮助或老板键盖住时不会收到 update，回来后原样继续，不需要 on_suspend
+    fn on_exit(&mut self) {
+        GameShell::auto_pause(self);
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent, area: Rect) {
+        GameShell::handle_mouse(self, event, area);
+    }
+
+    fn render(&mut self, buf: &mut Buffer, area: Rect) {
+        GameShell::render(self, buf, area);
+    }
+
+    fn set_language(&mut self, language: Language) {
+        GameShell::set_language(self, language);
+    }
+
+    fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.game.set_difficulty(difficulty);
+    }
+
+    fn metadata(&self) -> GameMetadata {
+        GameShell::metadata(self)
+    }
+
+    fn set_keymap(&mut self, keymap: &Keymap) {
+        GameShell::set_keymap(self, keymap);
+    }
+
+    fn set_theme(&mut self, theme: &Theme) {
+        GameShell::set_theme(self, theme);
+    }
+
+    fn set_glyphs(&mut self, glyphs: &Glyphs) {
+        GameShell::set_glyphs(self, glyphs);
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        GameShell::set_seed(self, seed);
+    }
+
+    fn take_result(&mut self) -> Option<GameResult> {
+        self.game.take_result()
+    }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        GameShell::take_events(self)
+    }
+
+    fn take_request(&mut self) -> Option<GameRequest> {
+        self.request.take()
+    }
+
+    fn set_high_scores(&mut self, scores: &[ScoreEntry], rank: Option<usize>) {
+        self.game.set_high_scores(scores, rank);
+    }
+
+    fn save_state(&self) -> Option<SaveData> {
+        GameShell::save_state(self)
+    }
+
+    fn load_state(&mut self, data: &SaveData) -> bool {
+        GameShell::load_state(self, data)
+    }
+}
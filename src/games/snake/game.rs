@@ -1,44 +1,96 @@
-use crate::games::compiling::Compiling;
-use crate::Game;
+use crate::games::shell::ShellGame;
 use crate::{
-    game_manager::CompileLanguage,
-    translation::{Language, Translations},
+    games::metadata::{Control, GameMetadata},
+    games::registry::GameDescriptor,
+    glyphs::Glyphs,
+    keymap::{Action, Keymap},
+    saves::SaveData,
+    scores::{self, GameResult, ScoreDetail, ScoreEntry},
+    stats::GameEvent,
+    theme::Theme,
+    translation::{Language, Translations, SNAKE_TRANSLATIONS},
 };
-use crossterm::event::KeyCode;
-use rand::Rng;
-use std::cell::RefCell;
+use crate::{Difficulty, GameEnv};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::collections::VecDeque;
-use std::io::Stdout;
-use tui::backend::CrosstermBackend;
+use std::time::Duration;
 use tui::{
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph},
-    Frame,
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 
-#[derive(PartialEq)]
-pub enum GameState {
-    Welcome,
-    Playing,
-    Paused,
-}
+// 蛇每走一格的间隔
+const MOVE_INTERVAL: Duration = Duration::from_millis(160);
 
-#[derive(Clone, PartialEq)]
-enum Direction {
+// 最多记住几个还没执行的转向，多按的键被忽略
+const MAX_QUEUED_TURNS: usize = 3;
+
+/// 蛇前进的方向，也是 [`GameEnv`] 中的动作
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
     Up,
     Down,
     Left,
     Right,
 }
 
+impl Direction {
+    fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    // 存档中使用的名字
+    fn name(&self) -> &'static str {
+        match self {
+            Direction::Up => "up",
+            Direction::Down => "down",
+            Direction::Left => "left",
+            Direction::Right => "right",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "up" => Some(Direction::Up),
+            "down" => Some(Direction::Down),
+            "left" => Some(Direction::Left),
+            "right" => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 struct Position {
     x: i32,
     y: i32,
 }
 
+impl Position {
+    // 存档中的格式为 `x,y`
+    fn parse(text: &str) -> Option<Self> {
+        let (x, y) = text.split_once(',')?;
+        let position = Position {
+            x: x.parse().ok()?,
+            y: y.parse().ok()?,
+        };
+        ((0..20).contains(&position.x) && (0..20).contains(&position.y)).then_some(position)
+    }
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
 #[derive(Clone, PartialEq)]
 enum FoodType {
     Apple, // 大食物
@@ -52,6 +104,17 @@ struct Food {
     positions: Vec<Position>, // 用于存储大食物的所有位置
 }
 
+/// [`GameEnv`] 中看到的状态，坐标为 `(x, y)`，棋盘是 20x20
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observation {
+    /// 蛇身，第一个是蛇头
+    pub body: Vec<(i32, i32)>,
+    /// 食物占据的格子，苹果占 2x2，糖果占一格
+    pub food: Vec<(i32, i32)>,
+    pub direction: Direction,
+    pub score: u32,
+}
+
 pub struct Snake {
     // board: Vec<Vec<bool>>,
     snake_body: VecDeque<Position>,
@@ -59,15 +122,29 @@ pub struct Snake {
     direction: Direction,
     game_over: bool,
     score: u32,
-    tick_count: u32,
-    game_state: GameState,
+    move_timer: Duration,
     translations: Translations,
-    compiling: RefCell<Compiling>,
-    next_direction: Direction,
+    theme: Theme,
+    glyphs: Glyphs,
+    // 还没有执行的转向，每走一格用掉一个；同一格内连续转弯时每个转向都不会丢
+    turns: VecDeque<Direction>,
+    started_at: u64,
+    result: Option<GameResult>,
+    // 还没有被 GameManager 取走的事件
+    events: Vec<GameEvent>,
+    high_scores: Vec<ScoreEntry>,
+    high_score_rank: Option<usize>,
+    rng: StdRng,
+    difficulty: Difficulty,
 }
 
 impl Snake {
     pub fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    /// 使用给定的随机数种子创建游戏，相同的种子得到相同的食物位置
+    pub fn with_seed(seed: u64) -> Self {
         let mut snake = Self {
             // board: vec![vec![false; 20]; 20],
             snake_body: VecDeque::new(),
@@ -79,11 +156,20 @@ impl Snake {
             direction: Direction::Right,
             game_over: false,
             score: 0,
-            tick_count: 0,
-            game_state: GameState::Welcome,
-            translations: Translations::new().with_namespace("snake"),
-            compiling: RefCell::new(Compiling::new()),
-            next_direction: Direction::Right,
+            move_timer: Duration::ZERO,
+            translations: Translations::new()
+                .with_table(&SNAKE_TRANSLATIONS)
+                .with_namespace("snake"),
+            theme: Theme::default(),
+            glyphs: Glyphs::default(),
+            turns: VecDeque::new(),
+            started_at: scores::now(),
+            result: None,
+            events: Vec::new(),
+            high_scores: Vec::new(),
+            high_score_rank: None,
+            rng: StdRng::seed_from_u64(seed),
+            difficulty: Difficulty::Normal,
         };
 
         snake.snake_body.push_back(Position { x: 10, y: 10 });
@@ -91,88 +177,134 @@ impl Snake {
         snake
     }
 
+    // 开始新的一局，保留语言、配色、字符集和排行榜
+    fn reset(&mut self, seed: u64) {
+        let mut game = Self::with_seed(seed);
+        std::mem::swap(&mut game.translations, &mut self.translations);
+        game.theme = self.theme;
+        game.glyphs = self.glyphs;
+        game.high_scores = std::mem::take(&mut self.high_scores);
+        game.difficulty = self.difficulty;
+        *self = game;
+    }
+
+    // 难度越高蛇走得越快
+    fn move_interval(&self) -> Duration {
+        match self.difficulty {
+            Difficulty::Easy => Duration::from_millis(220),
+            Difficulty::Normal => MOVE_INTERVAL,
+            Difficulty::Hard => Duration::from_millis(100),
+        }
+    }
+
     pub fn set_language(&mut self, language: Language) {
         self.translations.set_language(language);
     }
 
-    pub fn handle_input(&mut self, key: KeyCode) -> bool {
-        if self.game_over {
-            if key == KeyCode::Char('r') {
-                *self = Self::new();
-                self.game_state = GameState::Playing;
-                return true;
-            }
-            return false;
-        }
+    pub fn handle_action(&mut self, action: Action) {
+        let direction = match action {
+            Action::MoveLeft => Direction::Left,
+            Action::MoveRight => Direction::Right,
+            Action::MoveUp => Direction::Up,
+            Action::MoveDown => Direction::Down,
+            _ => return,
+        };
+        self.queue_turn(direction);
+    }
 
-        match self.game_state {
-            GameState::Welcome => {
-                if key == KeyCode::Enter {
-                    self.game_state = GameState::Playing;
-                    true
-                } else {
-                    false
-                }
-            }
-            GameState::Playing => match key {
-                KeyCode::Left | KeyCode::Char('a') => {
-                    if self.direction != Direction::Right {
-                        self.next_direction = Direction::Left;
-                    }
-                    true
-                }
-                KeyCode::Right | KeyCode::Char('d') => {
-                    if self.direction != Direction::Left {
-                        self.next_direction = Direction::Right;
-                    }
-                    true
-                }
-                KeyCode::Up | KeyCode::Char('w') => {
-                    if self.direction != Direction::Down {
-                        self.next_direction = Direction::Up;
-                    }
-                    true
-                }
-                KeyCode::Down | KeyCode::Char('s') => {
-                    if self.direction != Direction::Up {
-                        self.next_direction = Direction::Down;
-                    }
-                    true
-                }
-                KeyCode::Char('p') | KeyCode::Esc => {
-                    self.game_state = GameState::Paused;
-                    true
-                }
-                _ => false,
-            },
-            GameState::Paused => {
-                if key == KeyCode::Char('p') || key == KeyCode::Esc {
-                    self.game_state = GameState::Playing;
-                    true
-                } else {
-                    false
-                }
-            }
+    // 和排在最后的方向比较：不能掉头，同一个方向也不用再排一次
+    fn queue_turn(&mut self, direction: Direction) {
+        let last = self.turns.back().copied().unwrap_or(self.direction);
+        if direction != last && direction != last.opposite() && self.turns.len() < MAX_QUEUED_TURNS
+        {
+            self.turns.push_back(direction);
         }
     }
 
-    pub fn update(&mut self) {
-        if self.game_over || self.game_state != GameState::Playing {
-            if self.game_state == GameState::Paused {
-                self.compiling.borrow_mut().update();
+    pub fn update(&mut self, dt: Duration) {
+        self.move_timer += dt;
+        let interval = self.move_interval();
+        while self.move_timer >= interval && !self.game_over {
+            self.move_timer -= interval;
+            if let Some(turn) = self.turns.pop_front() {
+                self.direction = turn;
             }
-            return;
-        }
-
-        self.tick_count += 1;
-        if self.tick_count % 10 == 0 {
-            self.direction = self.next_direction.clone();
             self.move_snake();
         }
     }
 
+    pub fn save_state(&self) -> Option<SaveData> {
+        let mut data = SaveData::new();
+        data.set_list("body", &self.snake_body);
+        data.set("direction", self.direction.name());
+        data.set_list("turns", self.turns.iter().map(|turn| turn.name()));
+        data.set(
+            "food_type",
+            match self.food.food_type {
+                FoodType::Apple => "apple",
+                FoodType::Candy => "candy",
+            },
+        );
+        data.set_list("food", &self.food.positions);
+        data.set("score", self.score);
+        data.set("started_at", self.started_at);
+        Some(data)
+    }
+
+    pub fn load_state(&mut self, data: &SaveData) -> bool {
+        self.restore(data).is_some()
+    }
+
+    // 先解析全部字段再赋值，存档不完整时游戏保持原样
+    fn restore(&mut self, data: &SaveData) -> Option<()> {
+        let body = data
+            .get_list::<String>("body")?
+            .iter()
+            .map(|p| Position::parse(p))
+            .collect::<Option<VecDeque<_>>>()
+            .filter(|body| !body.is_empty())?;
+        let direction = Direction::from_name(&data.get::<String>("direction")?)?;
+        // v1 的存档只记录了下一个方向，把它当作唯一一个待执行的转向
+        let turns = if data.version() < 2 {
+            let next = Direction::from_name(&data.get::<String>("next_direction")?)?;
+            (next != direction).then_some(next).into_iter().collect()
+        } else {
+            data.get_list::<String>("turns")?
+                .iter()
+                .map(|turn| Direction::from_name(turn))
+                .collect::<Option<VecDeque<_>>>()?
+        };
+        let food_type = match data.get::<String>("food_type")?.as_str() {
+            "apple" => FoodType::Apple,
+            "candy" => FoodType::Candy,
+            _ => return None,
+        };
+        let food = data
+            .get_list::<String>("food")?
+            .iter()
+            .map(|p| Position::parse(p))
+            .collect::<Option<Vec<_>>>()?;
+        let food_position = food.first()?.clone();
+        let score = data.get("score")?;
+        let started_at = data.get("started_at")?;
+
+        self.snake_body = body;
+        self.direction = direction;
+        self.turns = turns;
+        self.food = Food {
+            position: food_position,
+            food_type,
+            positions: food,
+        };
+        self.score = score;
+        self.started_at = started_at;
+        self.move_timer = Duration::ZERO;
+        self.game_over = false;
+        Some(())
+    }
+
     fn spawn_food(&mut self) {
-        let mut rng = rand::thread_rng();
+        let rng = &mut self.rng;
 
         // 随机选择食物类型
         self.food.food_type = if rng.gen_bool(0.7) {
@@ -267,6 +399,11 @@ impl Snake {
                     .any(|p| p.x == new_head.x && p.y == new_head.y)
             {
                 self.game_over = true;
+                self.result = Some(GameResult {
+                    score: self.score as i64,
+                    detail: ScoreDetail::Length(self.snake_body.len() as u64),
+                    started_at: self.started_at,
+                });
                 return;
             }
 
@@ -276,6 +413,7 @@ impl Snake {
                     FoodType::Apple => self.score += 50,  // 苹果分值降低
                     FoodType::Candy => self.score += 150, // 糖果分值提高
                 }
+                self.events.push(GameEvent::FoodEaten);
                 self.spawn_food();
             } else {
                 self.snake_body.pop_back();
@@ -285,47 +423,56 @@ impl Snake {
         }
     }
 
-    pub fn render(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        match self.game_state {
-            GameState::Welcome => self.render_welcome(f, area),
-            GameState::Playing => self.render_game(f, area),
-            GameState::Paused => self.render_pause(f, area),
-        }
-    }
-
-    fn render_welcome(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        let welcome_text = vec![
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        let mut welcome_text = vec![
             Spans::from(vec![Span::styled(
                 format!(
                     "{} {}!",
                     self.translations.get_text("welcome_to"),
                     self.translations.get_text("snake_title")
                 ),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )]),
             Spans::from(""),
             Spans::from(self.translations.get_text("how_to_play")),
             Spans::from(""),
-            Spans::from(self.translations.get_text("move_snake")),
             Spans::from(self.translations.get_text("eat_food_title")),
             Spans::from(self.translations.get_text("apple_desc")),
             Spans::from(self.translations.get_text("candy_desc")),
             Spans::from(self.translations.get_text("avoid_walls")),
+            Spans::from(""),
+            Spans::from(self.translations.get_global_text("help_controls")),
+        ];
+        let controls = self.metadata().control_lines(&self.translations);
+        welcome_text.extend(controls.into_iter().map(Spans::from));
+        welcome_text.extend([
             Spans::from(""),
             Spans::from(self.translations.get_text("press_enter")),
             Spans::from(self.translations.get_text("pause_game")),
-        ];
+        ]);
+        if resumable {
+            welcome_text.push(Spans::from(Span::styled(
+                self.translations.get_global_text("resume_saved"),
+                self.theme.heading,
+            )));
+        }
+        welcome_text.push(Spans::from(""));
+        welcome_text.extend(scores::table_lines(
+            &self.high_scores,
+            &self.translations,
+            &self.theme,
+        ));
 
         let paragraph = Paragraph::new(welcome_text)
             .block(Block::default().borders(Borders::ALL).title(Span::styled(
                 self.translations.get_text("snake_title"),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )))
             .alignment(tui::layout::Alignment::Center);
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
     }
 
-    fn render_game(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
+    fn render_game(&self, buf: &mut Buffer, area: Rect) {
         let mut display_board = vec![vec![false; 20]; 20];
 
         // 绘制蛇身
@@ -339,22 +486,24 @@ impl Snake {
         text.push(Spans::from(""));
 
         // 绘制游戏区域
-        for y in 0..20 {
-            let mut line = String::new();
-            for x in 0..20 {
+        for (y, row) in display_board.iter().enumerate() {
+            let mut line = Vec::new();
+            for (x, &is_body) in row.iter().enumerate() {
                 let current_pos = Position {
                     x: x as i32,
                     y: y as i32,
                 };
                 if self.food.positions.contains(&current_pos) {
                     match self.food.food_type {
-                        FoodType::Apple => line.push_str("🍎"), // 苹果
-                        FoodType::Candy => line.push_str("🍬"), // 糖果
+                        FoodType::Apple => line.push(Span::raw(self.glyphs.apple)), // 苹果
+                        FoodType::Candy => line.push(Span::raw(self.glyphs.candy)), // 糖果
                     }
-                } else if display_board[y][x] {
-                    line.push_str("██"); // 蛇身
+                } else if is_body {
+                    line.push(Span::styled(self.glyphs.block, self.theme.player));
+                // 蛇身
                 } else {
-                    line.push_str("··"); // 空白
+                    line.push(Span::styled(self.glyphs.empty, self.theme.muted));
+                    // 空白
                 }
             }
             text.push(Spans::from(line));
@@ -362,59 +511,200 @@ impl Snake {
 
         text.push(Spans::from(""));
         text.push(Spans::from(format!(
-            "{}: {}",
+            "{} {}",
             self.translations.get_text("score"),
             self.score
         )));
 
         if self.game_over {
-            text.push(Spans::from(self.translations.get_text("game_over")));
+            text.push(Spans::from(Span::styled(
+                self.translations.get_text("game_over"),
+                self.theme.danger,
+            )));
             text.push(Spans::from(self.translations.get_text("press_r_restart")));
+            text.push(scores::game_over_line(
+                &self.high_scores,
+                self.high_score_rank,
+                &self.translations,
+                &self.theme,
+            ));
         }
 
         let paragraph = Paragraph::new(text)
             .block(Block::default().borders(Borders::ALL).title(Span::styled(
                 self.translations.get_text("snake_title"),
-                Style::default().fg(Color::Green),
+                self.theme.accent,
             )))
             .alignment(tui::layout::Alignment::Center);
 
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
     }
+}
 
-    fn render_pause(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        if self.game_state == GameState::Paused {
-            self.compiling.borrow_mut().render(f, area);
-        }
+impl Default for Snake {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    pub fn set_compile_language(&mut self, lang: CompileLanguage) {
-        self.compiling.borrow_mut().set_language(lang);
+impl ShellGame for Snake {
+    fn with_seed(seed: u64) -> Self {
+        Snake::with_seed(seed)
+    }
+
+    fn restart(&mut self, seed: u64) {
+        self.reset(seed);
     }
-}
 
-impl Game for Snake {
-    fn new() -> Self {
-        Snake::new()
+    fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
     }
 
-    fn handle_input(&mut self, key: KeyCode) {
-        self.handle_input(key);
+    const ACTIONS: &'static [Action] = &[
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::MoveUp,
+        Action::MoveDown,
+    ];
+
+    fn handle_action(&mut self, action: Action) {
+        self.handle_action(action);
+    }
+
+    fn update(&mut self, dt: Duration) {
+        self.update(dt);
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.game_over
+    }
+
+    // 20×20 的棋盘，每格两列
+    fn min_size(&self) -> (u16, u16) {
+        (42, 24)
     }
 
-    fn update(&mut self) {
-        self.update();
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        Snake::render_welcome(self, buf, area, resumable);
     }
 
-    fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        Snake::render(self, f, area);
+    fn render(&mut self, buf: &mut Buffer, area: Rect) {
+        self.render_game(buf, area);
     }
 
     fn set_language(&mut self, language: Language) {
         Snake::set_language(self, language);
     }
 
-    fn set_compile_language(&mut self, lang: CompileLanguage) {
-        Snake::set_compile_language(self, lang);
+    fn set_keymap(&mut self, keymap: &Keymap) {
+        self.translations.set_keymap(keymap);
+    }
+
+    fn set_theme(&mut self, theme: &Theme) {
+        self.theme = *theme;
+    }
+
+    fn set_glyphs(&mut self, glyphs: &Glyphs) {
+        self.glyphs = *glyphs;
+        self.translations.set_glyphs(glyphs);
+    }
+
+    fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+    }
+
+    fn describe(translations: &Translations) -> GameMetadata {
+        GameMetadata {
+            name: translations.get_text("snake_title"),
+            description: translations.get_text("description"),
+            controls: vec![Control::keys(
+                &[
+                    Action::MoveUp,
+                    Action::MoveDown,
+                    Action::MoveLeft,
+                    Action::MoveRight,
+                ],
+                translations.get_text("control_turn"),
+            )],
+        }
     }
+
+    fn metadata(&self) -> GameMetadata {
+        Self::describe(&self.translations)
+    }
+
+    fn take_result(&mut self) -> Option<GameResult> {
+        self.result.take()
+    }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn set_high_scores(&mut self, scores: &[ScoreEntry], rank: Option<usize>) {
+        self.high_scores = scores.to_vec();
+        self.high_score_rank = rank;
+    }
+
+    fn save_state(&self) -> Option<SaveData> {
+        Snake::save_state(self)
+    }
+
+    fn load_state(&mut self, data: &SaveData) -> bool {
+        Snake::load_state(self, data)
+    }
+}
+
+/// 奖励是这一步吃到食物得到的分数
+impl GameEnv for Snake {
+    type Action = Direction;
+    type Observation = Observation;
+
+    fn reset(&mut self, seed: u64) -> Observation {
+        self.reset(seed);
+        self.observe()
+    }
+
+    fn step(&mut self, action: Direction) -> (Observation, f64, bool) {
+        let score = self.score;
+        // 不能直接掉头，掉头的动作和结束之后的动作一样不改变游戏
+        if !self.game_over && action != self.direction.opposite() {
+            self.direction = action;
+            self.move_snake();
+        }
+        (self.observe(), (self.score - score) as f64, self.game_over)
+    }
+
+    fn legal_actions(&self) -> Vec<Direction> {
+        if self.game_over {
+            return Vec::new();
+        }
+        [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ]
+        .into_iter()
+        .filter(|&direction| direction != self.direction.opposite())
+        .collect()
+    }
+
+    fn observe(&self) -> Observation {
+        Observation {
+            body: self.snake_body.iter().map(|p| (p.x, p.y)).collect(),
+            food: self.food.positions.iter().map(|p| (p.x, p.y)).collect(),
+            direction: self.direction,
+            score: self.score,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.game_over
+    }
+}
+
+pub fn descriptor() -> GameDescriptor {
+    GameDescriptor::shell::<Snake>("snake", "snake.snake_title", &SNAKE_TRANSLATIONS)
+        .with_difficulty()
 }
@@ -1,3 +1,3 @@
 mod game;
 
-pub use game::Snake;
+pub use game::{descriptor, Direction, Observation, Snake};
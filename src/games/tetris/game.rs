@@ -1,28 +1,28 @@
-use crate::games::compiling::Compiling;
-use crate::Game;
+use crate::games::shell::ShellGame;
 use crate::{
-    game_manager::CompileLanguage,
-    translation::{Language, Translations},
+    games::metadata::{Control, GameMetadata},
+    games::registry::GameDescriptor,
+    glyphs::Glyphs,
+    keymap::{self, Keymap},
+    saves::SaveData,
+    scores::{self, GameResult, ScoreDetail, ScoreEntry},
+    stats::GameEvent,
+    theme::Theme,
+    translation::{Language, Translations, TETRIS_TRANSLATIONS},
 };
-use crossterm::event::KeyCode;
-use rand::Rng;
-use std::cell::RefCell;
-use std::io::Stdout;
-use tui::backend::CrosstermBackend;
+use crate::{Difficulty, GameEnv};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::time::Duration;
 use tui::{
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph},
-    Frame,
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 
-#[derive(PartialEq)]
-pub enum GameState {
-    Welcome,
-    Playing,
-    Paused,
-}
+// 方块自动下落一格的间隔
+const FALL_INTERVAL: Duration = Duration::from_millis(350);
 
 // 定义方块形状
 const SHAPES: [[[bool; 4]; 4]; 7] = [
@@ -77,6 +77,33 @@ const SHAPES: [[[bool; 4]; 4]; 7] = [
     ],
 ];
 
+/// [`GameEnv`] 中的动作，每个动作之后方块再下落一格
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Left,
+    Right,
+    Rotate,
+    /// 多下落一格
+    SoftDrop,
+    /// 直接落到底
+    HardDrop,
+    /// 什么也不做，只等方块下落
+    Wait,
+}
+
+/// [`GameEnv`] 中看到的状态
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observation {
+    /// 20 行 10 列，已经固定下来的方块
+    pub board: Vec<Vec<bool>>,
+    /// 正在下落的方块占据的格子 `(x, y)`，可能有一部分还在棋盘上方
+    pub piece: Vec<(i32, i32)>,
+    /// 正在下落的方块是哪一种形状（0 到 6）
+    pub piece_kind: usize,
+    pub score: u32,
+    pub lines: u32,
+}
+
 pub struct Tetris {
     board: Vec<Vec<bool>>,
     current_piece: usize,
@@ -84,17 +111,32 @@ pub struct Tetris {
     piece_y: i32,
     game_over: bool,
     score: u32,
-    tick_count: u32,
+    fall_timer: Duration,
     current_shape: [[bool; 4]; 4],
     block_width: u16,
-    game_state: GameState,
     translations: Translations,
-    compiling: RefCell<Compiling>,
+    theme: Theme,
+    glyphs: Glyphs,
+    lines_cleared: u32,
+    started_at: u64,
+    result: Option<GameResult>,
+    // 还没有被 GameManager 取走的事件
+    events: Vec<GameEvent>,
+    high_scores: Vec<ScoreEntry>,
+    high_score_rank: Option<usize>,
+    rng: StdRng,
+    difficulty: Difficulty,
 }
 
 impl Tetris {
     pub fn new() -> Self {
-        let piece = rand::thread_rng().gen_range(0..7);
+        Self::with_seed(rand::random())
+    }
+
+    /// 使用给定的随机数种子创建游戏，相同的种子得到相同的方块序列
+    pub fn with_seed(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let piece = rng.gen_range(0..SHAPES.len());
         Self {
             board: vec![vec![false; 10]; 20],
             current_piece: piece,
@@ -102,12 +144,42 @@ impl Tetris {
             piece_y: 0,
             game_over: false,
             score: 0,
-            tick_count: 0,
+            fall_timer: Duration::ZERO,
             current_shape: SHAPES[piece],
             block_width: 2,
-            game_state: GameState::Welcome,
-            translations: Translations::new().with_namespace("tetris"),
-            compiling: RefCell::new(Compiling::new()),
+            translations: Translations::new()
+                .with_table(&TETRIS_TRANSLATIONS)
+                .with_namespace("tetris"),
+            theme: Theme::default(),
+            glyphs: Glyphs::default(),
+            lines_cleared: 0,
+            started_at: scores::now(),
+            result: None,
+            events: Vec::new(),
+            high_scores: Vec::new(),
+            high_score_rank: None,
+            rng,
+            difficulty: Difficulty::Normal,
+        }
+    }
+
+    // 开始新的一局，保留语言、配色、字符集和排行榜
+    fn reset(&mut self, seed: u64) {
+        let mut game = Self::with_seed(seed);
+        std::mem::swap(&mut game.translations, &mut self.translations);
+        game.theme = self.theme;
+        game.glyphs = self.glyphs;
+        game.high_scores = std::mem::take(&mut self.high_scores);
+        game.difficulty = self.difficulty;
+        *self = game;
+    }
+
+    // 难度越高方块下落得越快
+    fn fall_interval(&self) -> Duration {
+        match self.difficulty {
+            Difficulty::Easy => Duration::from_millis(500),
+            Difficulty::Normal => FALL_INTERVAL,
+            Difficulty::Hard => Duration::from_millis(220),
         }
     }
 
@@ -115,120 +187,140 @@ impl Tetris {
         self.translations.set_language(language);
     }
 
-    pub fn handle_input(&mut self, key: KeyCode) -> bool {
-        if self.game_over {
-            if key == KeyCode::Char('r') {
-                *self = Self::new();
-                self.game_state = GameState::Playing;
-                return true;
+    pub fn handle_action(&mut self, action: keymap::Action) {
+        match action {
+            keymap::Action::MoveLeft => {
+                self.move_piece(-1, 0);
             }
-            return false;
-        }
-
-        match self.game_state {
-            GameState::Welcome => {
-                if key == KeyCode::Enter {
-                    self.game_state = GameState::Playing;
-                    true
-                } else {
-                    false
-                }
+            keymap::Action::MoveRight => {
+                self.move_piece(1, 0);
             }
-            GameState::Playing => match key {
-                KeyCode::Left | KeyCode::Char('a') => self.move_piece(-1, 0),
-                KeyCode::Right | KeyCode::Char('d') => self.move_piece(1, 0),
-                KeyCode::Down | KeyCode::Char('s') => self.move_piece(0, 1),
-                KeyCode::Up | KeyCode::Char('w') => {
-                    self.rotate_piece();
-                    true
-                }
-                KeyCode::Char(' ') => {
-                    self.hard_drop();
-                    true
-                }
-                KeyCode::Char('p') | KeyCode::Esc => {
-                    self.game_state = GameState::Paused;
-                    true
-                }
-                _ => false,
-            },
-            GameState::Paused => {
-                if key == KeyCode::Char('p') || key == KeyCode::Esc {
-                    self.game_state = GameState::Playing;
-                    true
-                } else {
-                    false
-                }
+            keymap::Action::SoftDrop => {
+                self.move_piece(0, 1);
             }
+            keymap::Action::Rotate => self.rotate_piece(),
+            keymap::Action::HardDrop => self.hard_drop(),
+            _ => {}
         }
     }
 
-    pub fn update(&mut self) {
-        if self.game_over || self.game_state != GameState::Playing {
-            if self.game_state == GameState::Paused {
-                self.compiling.borrow_mut().update();
-            }
-            return;
+    pub fn save_state(&self) -> Option<SaveData> {
+        let shape: Vec<Vec<bool>> = self.current_shape.iter().map(|row| row.to_vec()).collect();
+        let mut data = SaveData::new();
+        data.set_grid("board", &self.board);
+        data.set("piece", self.current_piece);
+        data.set_grid("shape", &shape);
+        data.set("piece_x", self.piece_x);
+        data.set("piece_y", self.piece_y);
+        data.set("score", self.score);
+        data.set("lines", self.lines_cleared);
+        data.set("started_at", self.started_at);
+        Some(data)
+    }
+
+    pub fn load_state(&mut self, data: &SaveData) -> bool {
+        self.restore(data).is_some()
+    }
+
+    // 先解析全部字段再赋值，存档不完整时游戏保持原样
+    fn restore(&mut self, data: &SaveData) -> Option<()> {
+        let board = data.get_grid("board", 20, 10)?;
+        let piece = data.get("piece").filter(|&piece| piece < SHAPES.len())?;
+        let shape = data.get_grid::<bool>("shape", 4, 4)?;
+        let piece_x = data.get("piece_x")?;
+        let piece_y = data.get("piece_y")?;
+        let score = data.get("score")?;
+        let lines_cleared = data.get("lines")?;
+        let started_at = data.get("started_at")?;
+
+        self.board = board;
+        self.current_piece = piece;
+        for (row, saved) in self.current_shape.iter_mut().zip(&shape) {
+            row.copy_from_slice(saved);
         }
+        self.piece_x = piece_x;
+        self.piece_y = piece_y;
+        self.score = score;
+        self.lines_cleared = lines_cleared;
+        self.started_at = started_at;
+        self.fall_timer = Duration::ZERO;
+        self.game_over = false;
+        Some(())
+    }
 
-        self.tick_count += 1;
-        if self.tick_count % 20 == 0 {
-            if !self.move_piece(0, 1) {
-                self.freeze_piece();
-                self.clear_lines();
-                self.spawn_new_piece();
-            }
+    pub fn update(&mut self, dt: Duration) {
+        self.fall_timer += dt;
+        let interval = self.fall_interval();
+        while self.fall_timer >= interval && !self.game_over {
+            self.fall_timer -= interval;
+            self.fall();
         }
     }
 
-    pub fn render(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        match self.game_state {
-            GameState::Welcome => self.render_welcome(f, area),
-            GameState::Playing => self.render_game(f, area),
-            GameState::Paused => self.render_pause(f, area),
+    // 下落一格，落到底时固定方块并换下一个
+    fn fall(&mut self) {
+        if !self.move_piece(0, 1) {
+            self.freeze_piece();
+            self.clear_lines();
+            self.spawn_new_piece();
         }
     }
 
-    pub fn render_welcome(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        let welcome_text = vec![
+    pub fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        let mut welcome_text = vec![
             Spans::from(vec![Span::styled(
                 format!(
                     "{} {}!",
                     self.translations.get_text("welcome_to"),
                     self.translations.get_text("tetris_title")
                 ),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )]),
             Spans::from(""),
             Spans::from(self.translations.get_text("how_to_play")),
             Spans::from(""),
-            Spans::from(self.translations.get_text("move_horizontal")),
-            Spans::from(self.translations.get_text("speed_up")),
-            Spans::from(self.translations.get_text("rotate")),
-            Spans::from(self.translations.get_text("hard_drop")),
+            Spans::from(self.translations.get_text("description")),
             Spans::from(self.translations.get_text("clear_lines")),
             Spans::from(self.translations.get_text("one_line")),
             Spans::from(self.translations.get_text("two_lines")),
             Spans::from(self.translations.get_text("three_lines")),
             Spans::from(self.translations.get_text("four_lines")),
             Spans::from(self.translations.get_text("game_ends")),
+            Spans::from(""),
+            Spans::from(self.translations.get_global_text("help_controls")),
+        ];
+        let controls = self.metadata().control_lines(&self.translations);
+        welcome_text.extend(controls.into_iter().map(Spans::from));
+        welcome_text.extend([
             Spans::from(""),
             Spans::from(self.translations.get_text("quit_control")),
             Spans::from(self.translations.get_text("press_enter")),
             Spans::from(self.translations.get_text("pause_game")),
             Spans::from(self.translations.get_text("restart")),
-        ];
+        ]);
+        if resumable {
+            welcome_text.push(Spans::from(Span::styled(
+                self.translations.get_global_text("resume_saved"),
+                self.theme.heading,
+            )));
+        }
+        welcome_text.push(Spans::from(""));
+        welcome_text.extend(scores::table_lines(
+            &self.high_scores,
+            &self.translations,
+            &self.theme,
+        ));
 
         let paragraph = Paragraph::new(welcome_text)
             .block(Block::default().borders(Borders::ALL).title(Span::styled(
                 self.translations.get_text("tetris_title"),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )))
             .alignment(tui::layout::Alignment::Center);
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
     }
 
-    pub fn render_game(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
+    pub fn render_game(&self, buf: &mut Buffer, area: Rect) {
         let available_width = area.width as usize;
         let game_width = self.board[0].len() * self.block_width as usize;
         let padding = if available_width > game_width {
@@ -237,7 +329,16 @@ impl Tetris {
             0
         };
 
-        let mut display_board = self.board.clone();
+        // 每个格子的样式，正在下落的方块和已经落下的方块颜色不同
+        let mut display_board: Vec<Vec<Option<Style>>> = self
+            .board
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&cell| cell.then_some(self.theme.block))
+                    .collect()
+            })
+            .collect();
 
         if !self.game_over {
             for y in 0..4 {
@@ -245,8 +346,9 @@ impl Tetris {
                     if self.current_shape[y][x] {
                         let board_x = self.piece_x + x as i32;
                         let board_y = self.piece_y + y as i32;
-                        if board_x >= 0 && board_x < 10 && board_y >= 0 && board_y < 20 {
-                            display_board[board_y as usize][board_x as usize] = true;
+                        if (0..10).contains(&board_x) && (0..20).contains(&board_y) {
+                            display_board[board_y as usize][board_x as usize] =
+                                Some(self.theme.player);
                         }
                     }
                 }
@@ -258,9 +360,12 @@ impl Tetris {
         text.push(Spans::from(""));
 
         for row in &display_board {
-            let mut line = " ".repeat(padding);
+            let mut line = vec![Span::raw(" ".repeat(padding))];
             for &cell in row {
-                line.push_str(if cell { "██" } else { "··" });
+                line.push(match cell {
+                    Some(style) => Span::styled(self.glyphs.block, style),
+                    None => Span::styled(self.glyphs.empty, self.theme.muted),
+                });
             }
             text.push(Spans::from(line));
         }
@@ -273,33 +378,32 @@ impl Tetris {
         )));
 
         if self.game_over {
-            text.push(Spans::from(self.translations.get_text("game_over")));
+            text.push(Spans::from(Span::styled(
+                self.translations.get_text("game_over"),
+                self.theme.danger,
+            )));
             text.push(Spans::from(self.translations.get_text("press_r_restart")));
+            text.push(scores::game_over_line(
+                &self.high_scores,
+                self.high_score_rank,
+                &self.translations,
+                &self.theme,
+            ));
         }
 
         let available_height = area.height as usize;
         let required_height = text.len();
-        let start_index = if required_height > available_height {
-            required_height - available_height
-        } else {
-            0
-        };
+        let start_index = required_height.saturating_sub(available_height);
         let visible_text = text[start_index..].to_vec();
 
         let paragraph = Paragraph::new(visible_text)
             .block(Block::default().borders(Borders::ALL).title(Span::styled(
                 self.translations.get_text("tetris_title"),
-                Style::default().fg(Color::Cyan),
+                self.theme.accent,
             )))
             .alignment(tui::layout::Alignment::Left);
 
-        f.render_widget(paragraph, area);
-    }
-
-    pub fn render_pause(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        if self.game_state == GameState::Paused {
-            self.compiling.borrow_mut().render(f, area);
-        }
+        paragraph.render(area, buf);
     }
 
     fn move_piece(&mut self, dx: i32, dy: i32) -> bool {
@@ -316,46 +420,37 @@ impl Tetris {
     }
 
     fn rotate_piece(&mut self) {
+        if self.can_rotate() {
+            self.current_shape = self.rotated_shape();
+        }
+    }
+
+    fn rotated_shape(&self) -> [[bool; 4]; 4] {
         let mut rotated = [[false; 4]; 4];
-        for y in 0..4 {
-            for x in 0..4 {
-                rotated[x][3 - y] = self.current_shape[y][x];
+        for (y, row) in self.current_shape.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                rotated[x][3 - y] = cell;
             }
         }
+        rotated
+    }
 
-        if self.is_valid_position_with_shape(self.piece_x, self.piece_y, &rotated) {
-            self.current_shape = rotated;
-        }
+    fn can_rotate(&self) -> bool {
+        self.is_valid_position_with_shape(self.piece_x, self.piece_y, &self.rotated_shape())
     }
 
     fn is_valid_position(&self, x: i32, y: i32) -> bool {
-        for piece_y in 0..4 {
-            for piece_x in 0..4 {
-                if self.current_shape[piece_y][piece_x] {
-                    let board_x = x + piece_x as i32;
-                    let board_y = y + piece_y as i32;
-
-                    if board_x < 0 || board_x >= 10 || board_y >= 20 {
-                        return false;
-                    }
-
-                    if board_y >= 0 && self.board[board_y as usize][board_x as usize] {
-                        return false;
-                    }
-                }
-            }
-        }
-        true
+        self.is_valid_position_with_shape(x, y, &self.current_shape)
     }
 
     fn is_valid_position_with_shape(&self, x: i32, y: i32, shape: &[[bool; 4]; 4]) -> bool {
-        for piece_y in 0..4 {
-            for piece_x in 0..4 {
-                if shape[piece_y][piece_x] {
+        for (piece_y, row) in shape.iter().enumerate() {
+            for (piece_x, &cell) in row.iter().enumerate() {
+                if cell {
                     let board_x = x + piece_x as i32;
                     let board_y = y + piece_y as i32;
 
-                    if board_x < 0 || board_x >= 10 || board_y >= 20 {
+                    if !(0..10).contains(&board_x) || board_y >= 20 {
                         return false;
                     }
 
@@ -374,7 +469,7 @@ impl Tetris {
                 if self.current_shape[y][x] {
                     let board_x = self.piece_x + x as i32;
                     let board_y = self.piece_y + y as i32;
-                    if board_x >= 0 && board_x < 10 && board_y >= 0 && board_y < 20 {
+                    if (0..10).contains(&board_x) && (0..20).contains(&board_y) {
                         self.board[board_y as usize][board_x as usize] = true;
                     }
                 }
@@ -400,6 +495,11 @@ impl Tetris {
 
         // 根据消除的行数计算分数
         let lines_cleared = lines_to_clear.len();
+        self.lines_cleared += lines_cleared as u32;
+        if lines_cleared > 0 {
+            self.events
+                .push(GameEvent::LinesCleared(lines_cleared as u32));
+        }
         self.score += match lines_cleared {
             1 => 100,
             2 => 300,
@@ -410,13 +510,18 @@ impl Tetris {
     }
 
     fn spawn_new_piece(&mut self) {
-        self.current_piece = rand::thread_rng().gen_range(0..7);
+        self.current_piece = self.rng.gen_range(0..SHAPES.len());
         self.piece_x = 3;
         self.piece_y = 0;
         self.current_shape = SHAPES[self.current_piece];
 
         if !self.is_valid_position(self.piece_x, self.piece_y) {
             self.game_over = true;
+            self.result = Some(GameResult {
+                score: self.score as i64,
+                detail: ScoreDetail::Lines(self.lines_cleared as u64),
+                started_at: self.started_at,
+            });
         }
     }
 
@@ -426,34 +531,204 @@ impl Tetris {
         self.clear_lines();
         self.spawn_new_piece();
     }
+}
 
-    pub fn set_compile_language(&mut self, lang: CompileLanguage) {
-        self.compiling.borrow_mut().set_language(lang);
+impl Default for Tetris {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl Game for Tetris {
-    fn new() -> Self {
-        Tetris::new()
+impl ShellGame for Tetris {
+    fn with_seed(seed: u64) -> Self {
+        Tetris::with_seed(seed)
+    }
+
+    fn restart(&mut self, seed: u64) {
+        self.reset(seed);
     }
 
-    fn handle_input(&mut self, key: KeyCode) {
-        let _ = self.handle_input(key);
+    fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
     }
 
-    fn update(&mut self) {
-        Tetris::update(self);
+    const ACTIONS: &'static [keymap::Action] = &[
+        keymap::Action::MoveLeft,
+        keymap::Action::MoveRight,
+        keymap::Action::SoftDrop,
+        keymap::Action::Rotate,
+        keymap::Action::HardDrop,
+    ];
+
+    fn handle_action(&mut self, action: keymap::Action) {
+        self.handle_action(action);
+    }
+
+    fn update(&mut self, dt: Duration) {
+        Tetris::update(self, dt);
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.game_over
+    }
+
+    // 20 行棋盘加上边框和分数
+    fn min_size(&self) -> (u16, u16) {
+        (24, 24)
+    }
+
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        Tetris::render_welcome(self, buf, area, resumable);
     }
 
-    fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        Tetris::render(self, f, area);
+    fn render(&mut self, buf: &mut Buffer, area: Rect) {
+        self.render_game(buf, area);
     }
 
     fn set_language(&mut self, language: Language) {
         Tetris::set_language(self, language);
     }
 
-    fn set_compile_language(&mut self, lang: CompileLanguage) {
-        Tetris::set_compile_language(self, lang);
+    fn set_keymap(&mut self, keymap: &Keymap) {
+        self.translations.set_keymap(keymap);
+    }
+
+    fn set_theme(&mut self, theme: &Theme) {
+        self.theme = *theme;
+    }
+
+    fn set_glyphs(&mut self, glyphs: &Glyphs) {
+        self.glyphs = *glyphs;
+        self.translations.set_glyphs(glyphs);
+    }
+
+    fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+    }
+
+    fn describe(translations: &Translations) -> GameMetadata {
+        let text = |key| translations.get_text(key);
+        GameMetadata {
+            name: text("tetris_title"),
+            description: text("description"),
+            controls: vec![
+                Control::keys(
+                    &[keymap::Action::MoveLeft, keymap::Action::MoveRight],
+                    text("control_move"),
+                ),
+                Control::keys(&[keymap::Action::SoftDrop], text("control_soft_drop")),
+                Control::keys(&[keymap::Action::Rotate], text("control_rotate")),
+                Control::keys(&[keymap::Action::HardDrop], text("control_hard_drop")),
+            ],
+        }
+    }
+
+    fn metadata(&self) -> GameMetadata {
+        Self::describe(&self.translations)
+    }
+
+    fn take_result(&mut self) -> Option<GameResult> {
+        self.result.take()
+    }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn set_high_scores(&mut self, scores: &[ScoreEntry], rank: Option<usize>) {
+        self.high_scores = scores.to_vec();
+        self.high_score_rank = rank;
+    }
+
+    fn save_state(&self) -> Option<SaveData> {
+        Tetris::save_state(self)
+    }
+
+    fn load_state(&mut self, data: &SaveData) -> bool {
+        Tetris::load_state(self, data)
     }
 }
+
+/// 奖励是这一步消行得到的分数
+impl GameEnv for Tetris {
+    type Action = Action;
+    type Observation = Observation;
+
+    fn reset(&mut self, seed: u64) -> Observation {
+        self.reset(seed);
+        self.observe()
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f64, bool) {
+        let score = self.score;
+        if !self.game_over && self.legal_actions().contains(&action) {
+            match action {
+                Action::Left => {
+                    self.move_piece(-1, 0);
+                }
+                Action::Right => {
+                    self.move_piece(1, 0);
+                }
+                Action::Rotate => self.rotate_piece(),
+                Action::SoftDrop => {
+                    self.move_piece(0, 1);
+                }
+                Action::HardDrop => self.hard_drop(),
+                Action::Wait => {}
+            }
+            // 直接落到底时已经换了新的方块，不再额外下落
+            if action != Action::HardDrop && !self.game_over {
+                self.fall();
+            }
+        }
+        (self.observe(), (self.score - score) as f64, self.game_over)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        if self.game_over {
+            return Vec::new();
+        }
+        let mut actions = Vec::new();
+        if self.is_valid_position(self.piece_x - 1, self.piece_y) {
+            actions.push(Action::Left);
+        }
+        if self.is_valid_position(self.piece_x + 1, self.piece_y) {
+            actions.push(Action::Right);
+        }
+        if self.can_rotate() {
+            actions.push(Action::Rotate);
+        }
+        if self.is_valid_position(self.piece_x, self.piece_y + 1) {
+            actions.push(Action::SoftDrop);
+        }
+        actions.extend([Action::HardDrop, Action::Wait]);
+        actions
+    }
+
+    fn observe(&self) -> Observation {
+        let mut piece = Vec::new();
+        for (y, row) in self.current_shape.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                if cell {
+                    piece.push((self.piece_x + x as i32, self.piece_y + y as i32));
+                }
+            }
+        }
+        Observation {
+            board: self.board.clone(),
+            piece,
+            piece_kind: self.current_piece,
+            score: self.score,
+            lines: self.lines_cleared,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.game_over
+    }
+}
+
+pub fn descriptor() -> GameDescriptor {
+    GameDescriptor::shell::<Tetris>("tetris", "tetris.tetris_title", &TETRIS_TRANSLATIONS)
+        .with_difficulty()
+}
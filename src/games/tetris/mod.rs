@@ -1,3 +1,3 @@
 mod game;
 
-pub use game::Tetris;
+pub use game::{descriptor, Action, Observation, Tetris};
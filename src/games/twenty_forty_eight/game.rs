@@ -1,118 +1,108 @@
-use crate::games::compiling::Compiling;
-use crate::Game;
+use crate::games::shell::ShellGame;
+use crate::GameEnv;
 use crate::{
-    game_manager::CompileLanguage,
-    translation::{Language, Translations},
+    games::metadata::{Control, GameMetadata},
+    games::registry::GameDescriptor,
+    keymap::{Action, Keymap},
+    saves::SaveData,
+    scores::{self, GameResult, ScoreDetail, ScoreEntry},
+    stats::GameEvent,
+    theme::Theme,
+    translation::{Language, Translations, TWENTY_FORTY_EIGHT_TRANSLATIONS},
 };
-use crossterm::event::KeyCode;
-use rand::seq::SliceRandom;
-use std::cell::RefCell;
-use std::io::Stdout;
-use tui::backend::CrosstermBackend;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 use tui::{
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph},
-    Frame,
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 
-#[derive(PartialEq)]
-pub enum GameState {
-    Welcome,
-    Playing,
-    Paused,
-}
-
 // 在impl外部定义Direction
-#[derive(Clone, Copy)]
-enum Direction {
+/// 滑动的方向，也是 [`GameEnv`] 中的动作
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
     Up,
     Down,
     Left,
     Right,
 }
 
+/// [`GameEnv`] 中看到的状态
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observation {
+    /// 4x4 的棋盘，空格为 0
+    pub board: Vec<Vec<u32>>,
+    pub score: u32,
+}
+
 pub struct TwentyFortyEight {
     board: Vec<Vec<u32>>,
     score: u32,
     game_over: bool,
-    game_state: GameState,
     translations: Translations,
-    compiling: RefCell<Compiling>,
+    theme: Theme,
+    started_at: u64,
+    result: Option<GameResult>,
+    // 还没有被 GameManager 取走的事件
+    events: Vec<GameEvent>,
+    high_scores: Vec<ScoreEntry>,
+    high_score_rank: Option<usize>,
+    rng: StdRng,
 }
 
 impl TwentyFortyEight {
     pub fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    /// 使用给定的随机数种子创建游戏，相同的种子得到相同的新方块
+    pub fn with_seed(seed: u64) -> Self {
         let mut game = Self {
             board: vec![vec![0; 4]; 4],
             score: 0,
             game_over: false,
-            game_state: GameState::Welcome,
-            translations: Translations::new().with_namespace("2048"),
-            compiling: RefCell::new(Compiling::new()),
+            translations: Translations::new()
+                .with_table(&TWENTY_FORTY_EIGHT_TRANSLATIONS)
+                .with_namespace("2048"),
+            theme: Theme::default(),
+            started_at: scores::now(),
+            result: None,
+            events: Vec::new(),
+            high_scores: Vec::new(),
+            high_score_rank: None,
+            rng: StdRng::seed_from_u64(seed),
         };
         game.spawn_new_tile();
         game.spawn_new_tile();
         game
     }
 
+    // 开始新的一局，保留语言、配色和排行榜
+    fn reset(&mut self, seed: u64) {
+        let mut game = Self::with_seed(seed);
+        std::mem::swap(&mut game.translations, &mut self.translations);
+        game.theme = self.theme;
+        game.high_scores = std::mem::take(&mut self.high_scores);
+        *self = game;
+    }
+
     pub fn set_language(&mut self, language: Language) {
         self.translations.set_language(language);
     }
 
-    pub fn handle_input(&mut self, key: KeyCode) -> bool {
-        if self.game_over {
-            if key == KeyCode::Char('r') {
-                *self = Self::new();
-                self.game_state = GameState::Playing;
-                return true;
-            }
-            return false;
-        }
-
-        match self.game_state {
-            GameState::Welcome => {
-                if key == KeyCode::Enter {
-                    self.game_state = GameState::Playing;
-                    true
-                } else {
-                    false
-                }
-            }
-            GameState::Playing => {
-                let moved = match key {
-                    KeyCode::Left | KeyCode::Char('a') => self.move_tiles(Direction::Left),
-                    KeyCode::Right | KeyCode::Char('d') => self.move_tiles(Direction::Right),
-                    KeyCode::Up | KeyCode::Char('w') => self.move_tiles(Direction::Up),
-                    KeyCode::Down | KeyCode::Char('s') => self.move_tiles(Direction::Down),
-                    KeyCode::Char('p') | KeyCode::Esc => {
-                        self.game_state = GameState::Paused;
-                        true
-                    }
-                    _ => false,
-                };
-
-                if moved {
-                    self.spawn_new_tile();
-                    self.check_game_over();
-                }
-                moved
-            }
-            GameState::Paused => {
-                if key == KeyCode::Char('p') || key == KeyCode::Esc {
-                    self.game_state = GameState::Playing;
-                    true
-                } else {
-                    false
-                }
-            }
-        }
-    }
+    pub fn handle_action(&mut self, action: Action) {
+        let direction = match action {
+            Action::MoveLeft => Direction::Left,
+            Action::MoveRight => Direction::Right,
+            Action::MoveUp => Direction::Up,
+            Action::MoveDown => Direction::Down,
+            _ => return,
+        };
 
-    pub fn update(&mut self) {
-        if self.game_state == GameState::Paused {
-            self.compiling.borrow_mut().update();
+        if self.move_tiles(direction) {
+            self.spawn_new_tile();
+            self.check_game_over();
         }
     }
 
@@ -143,6 +133,24 @@ impl TwentyFortyEight {
         moved
     }
 
+    // 只判断能不能移动，不改变棋盘
+    fn can_move(&self, direction: Direction) -> bool {
+        (0..4).any(|index| {
+            let mut line: Vec<u32> = match direction {
+                Direction::Left | Direction::Right => self.board[index].clone(),
+                Direction::Up | Direction::Down => {
+                    self.board.iter().map(|row| row[index]).collect()
+                }
+            };
+            if matches!(direction, Direction::Right | Direction::Down) {
+                line.reverse();
+            }
+            // 数字前面有空位，或者有两个相同的数字挨在一起（中间可以隔着空位）
+            let tiles: Vec<u32> = line.iter().copied().filter(|&n| n != 0).collect();
+            line[..tiles.len()] != tiles[..] || tiles.windows(2).any(|pair| pair[0] == pair[1])
+        })
+    }
+
     fn merge_line(&mut self, index: usize, is_row: bool, reverse: bool) -> bool {
         let mut moved = false;
         let mut line: Vec<u32> = if is_row {
@@ -156,7 +164,7 @@ impl TwentyFortyEight {
         }
 
         // 合并相同的数字
-        let mut merged = vec![false; 4];
+        let mut merged = [false; 4];
         for i in 0..3 {
             if line[i] != 0 {
                 for j in (i + 1)..4 {
@@ -166,6 +174,7 @@ impl TwentyFortyEight {
                             line[j] = 0;
                             merged[i] = true;
                             self.score += line[i];
+                            self.events.push(GameEvent::TileMerged(line[i]));
                             moved = true;
                         }
                         break;
@@ -195,10 +204,10 @@ impl TwentyFortyEight {
                 self.board[index] = new_line;
             }
         } else {
-            for i in 0..4 {
-                if self.board[i][index] != new_line[i] {
+            for (row, &value) in self.board.iter_mut().zip(new_line.iter()) {
+                if row[index] != value {
                     moved = true;
-                    self.board[i][index] = new_line[i];
+                    row[index] = value;
                 }
             }
         }
@@ -216,11 +225,36 @@ impl TwentyFortyEight {
             }
         }
 
-        if let Some(&(i, j)) = empty_cells.choose(&mut rand::thread_rng()) {
-            self.board[i][j] = if rand::random::<f32>() < 0.9 { 2 } else { 4 };
+        if let Some(&(i, j)) = empty_cells.choose(&mut self.rng) {
+            self.board[i][j] = if self.rng.gen::<f32>() < 0.9 { 2 } else { 4 };
         }
     }
 
+    pub fn save_state(&self) -> Option<SaveData> {
+        let mut data = SaveData::new();
+        data.set_grid("board", &self.board);
+        data.set("score", self.score);
+        data.set("started_at", self.started_at);
+        Some(data)
+    }
+
+    pub fn load_state(&mut self, data: &SaveData) -> bool {
+        let (board, score, started_at) = match (
+            data.get_grid("board", 4, 4),
+            data.get("score"),
+            data.get("started_at"),
+        ) {
+            (Some(board), Some(score), Some(started_at)) => (board, score, started_at),
+            _ => return false,
+        };
+
+        self.board = board;
+        self.score = score;
+        self.started_at = started_at;
+        self.game_over = false;
+        true
+    }
+
     fn check_game_over(&mut self) {
         let mut has_empty = false;
         let mut can_merge = false;
@@ -243,33 +277,53 @@ impl TwentyFortyEight {
         }
 
         self.game_over = !has_empty && !can_merge;
-    }
-
-    pub fn render(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        match self.game_state {
-            GameState::Welcome => self.render_welcome(f, area),
-            GameState::Playing => self.render_game(f, area),
-            GameState::Paused => self.render_pause(f, area),
+        if self.game_over {
+            let max_tile = self.board.iter().flatten().copied().max().unwrap_or(0);
+            self.result = Some(GameResult {
+                score: self.score as i64,
+                detail: ScoreDetail::MaxTile(max_tile as u64),
+                started_at: self.started_at,
+            });
         }
     }
 
-    fn render_welcome(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        let welcome_text = vec![
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        let mut welcome_text = vec![
             Spans::from(vec![Span::styled(
                 self.translations.get_text("welcome_title"),
-                Style::default().fg(Color::Yellow),
+                self.theme.title,
             )]),
             Spans::from(""),
-            Spans::from(self.translations.get_text("press_enter")),
+            Spans::from(self.translations.get_text("description")),
+            Spans::from(""),
+            Spans::from(self.translations.get_global_text("help_controls")),
         ];
+        let controls = self.metadata().control_lines(&self.translations);
+        welcome_text.extend(controls.into_iter().map(Spans::from));
+        welcome_text.extend([
+            Spans::from(""),
+            Spans::from(self.translations.get_text("press_enter")),
+        ]);
+        if resumable {
+            welcome_text.push(Spans::from(Span::styled(
+                self.translations.get_global_text("resume_saved"),
+                self.theme.heading,
+            )));
+        }
+        welcome_text.push(Spans::from(""));
+        welcome_text.extend(scores::table_lines(
+            &self.high_scores,
+            &self.translations,
+            &self.theme,
+        ));
 
         let paragraph = Paragraph::new(welcome_text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(tui::layout::Alignment::Center);
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
     }
 
-    fn render_game(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
+    fn render_game(&self, buf: &mut Buffer, area: Rect) {
         let mut text = vec![];
 
         // 添加顶部边框
@@ -311,47 +365,175 @@ impl TwentyFortyEight {
             text.push(Spans::from(""));
             text.push(Spans::from(vec![Span::styled(
                 self.translations.get_text("game_over"),
-                Style::default().fg(Color::Red),
+                self.theme.danger,
             )]));
+            text.push(scores::game_over_line(
+                &self.high_scores,
+                self.high_score_rank,
+                &self.translations,
+                &self.theme,
+            ));
         }
 
         let paragraph = Paragraph::new(text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(tui::layout::Alignment::Center);
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
+    }
+}
+
+impl Default for TwentyFortyEight {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    fn render_pause(&self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        self.compiling.borrow_mut().render(f, area);
+impl ShellGame for TwentyFortyEight {
+    fn with_seed(seed: u64) -> Self {
+        TwentyFortyEight::with_seed(seed)
     }
 
-    pub fn set_compile_language(&mut self, language: CompileLanguage) {
-        self.compiling.borrow_mut().set_language(language);
+    fn restart(&mut self, seed: u64) {
+        self.reset(seed);
     }
-}
 
-impl Game for TwentyFortyEight {
-    fn new() -> Self {
-        TwentyFortyEight::new()
+    fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    const ACTIONS: &'static [Action] = &[
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::MoveUp,
+        Action::MoveDown,
+    ];
+
+    fn handle_action(&mut self, action: Action) {
+        self.handle_action(action);
     }
 
-    fn handle_input(&mut self, key: KeyCode) {
-        self.handle_input(key);
+    fn is_game_over(&self) -> bool {
+        self.game_over
     }
 
-    fn update(&mut self) {
-        self.update();
+    // 4×4 的棋盘加上分数
+    fn min_size(&self) -> (u16, u16) {
+        (24, 13)
     }
 
-    fn render(&mut self, f: &mut Frame<CrosstermBackend<Stdout>>, area: Rect) {
-        TwentyFortyEight::render(self, f, area);
+    fn render_welcome(&self, buf: &mut Buffer, area: Rect, resumable: bool) {
+        TwentyFortyEight::render_welcome(self, buf, area, resumable);
+    }
+
+    fn render(&mut self, buf: &mut Buffer, area: Rect) {
+        self.render_game(buf, area);
     }
 
     fn set_language(&mut self, language: Language) {
         TwentyFortyEight::set_language(self, language);
     }
 
-    fn set_compile_language(&mut self, lang: CompileLanguage) {
-        TwentyFortyEight::set_compile_language(self, lang);
+    fn set_keymap(&mut self, keymap: &Keymap) {
+        self.translations.set_keymap(keymap);
+    }
+
+    fn set_theme(&mut self, theme: &Theme) {
+        self.theme = *theme;
+    }
+
+    fn describe(translations: &Translations) -> GameMetadata {
+        GameMetadata {
+            name: translations.get_text("game_title"),
+            description: translations.get_text("description"),
+            controls: vec![Control::keys(
+                &[
+                    Action::MoveUp,
+                    Action::MoveDown,
+                    Action::MoveLeft,
+                    Action::MoveRight,
+                ],
+                translations.get_text("control_slide"),
+            )],
+        }
+    }
+
+    fn metadata(&self) -> GameMetadata {
+        Self::describe(&self.translations)
+    }
+
+    fn take_result(&mut self) -> Option<GameResult> {
+        self.result.take()
     }
+
+    fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn set_high_scores(&mut self, scores: &[ScoreEntry], rank: Option<usize>) {
+        self.high_scores = scores.to_vec();
+        self.high_score_rank = rank;
+    }
+
+    fn save_state(&self) -> Option<SaveData> {
+        TwentyFortyEight::save_state(self)
+    }
+
+    fn load_state(&mut self, data: &SaveData) -> bool {
+        TwentyFortyEight::load_state(self, data)
+    }
+}
+
+/// 奖励是这一步合并得到的分数
+impl GameEnv for TwentyFortyEight {
+    type Action = Direction;
+    type Observation = Observation;
+
+    fn reset(&mut self, seed: u64) -> Observation {
+        self.reset(seed);
+        self.observe()
+    }
+
+    fn step(&mut self, action: Direction) -> (Observation, f64, bool) {
+        let score = self.score;
+        if !self.game_over && self.can_move(action) {
+            self.move_tiles(action);
+            self.spawn_new_tile();
+            self.check_game_over();
+        }
+        (self.observe(), (self.score - score) as f64, self.game_over)
+    }
+
+    fn legal_actions(&self) -> Vec<Direction> {
+        if self.game_over {
+            return Vec::new();
+        }
+        [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ]
+        .into_iter()
+        .filter(|&direction| self.can_move(direction))
+        .collect()
+    }
+
+    fn observe(&self) -> Observation {
+        Observation {
+            board: self.board.clone(),
+            score: self.score,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.game_over
+    }
+}
+
+pub fn descriptor() -> GameDescriptor {
+    GameDescriptor::shell::<TwentyFortyEight>(
+        "2048",
+        "2048.twenty_forty_eight_title",
+        &TWENTY_FORTY_EIGHT_TRANSLATIONS,
+    )
 }
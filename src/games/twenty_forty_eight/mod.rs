@@ -1,3 +1,3 @@
 mod game;
 
-pub use game::TwentyFortyEight;
+pub use game::{descriptor, Direction, Observation, TwentyFortyEight};
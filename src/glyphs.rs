@@ -0,0 +1,155 @@
+use std::env;
+
+/// 可以在设置界面中选择的字符集
+///
+/// 有些终端（Windows 控制台、通过 SSH 连接的终端）显示 emoji 时宽度不对，
+/// 棋盘会因此错位，可以换成不含 emoji 的 Unicode 字符或者纯 ASCII 字符。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GlyphSet {
+    #[default]
+    Emoji,
+    /// 方块、几何图形等单宽字符，不使用 emoji
+    Unicode,
+    /// 只使用 ASCII 字符
+    Ascii,
+}
+
+impl GlyphSet {
+    pub const ALL: [GlyphSet; 3] = [GlyphSet::Emoji, GlyphSet::Unicode, GlyphSet::Ascii];
+
+    /// 设置文件和命令行中使用的名字
+    pub fn id(self) -> &'static str {
+        match self {
+            GlyphSet::Emoji => "emoji",
+            GlyphSet::Unicode => "unicode",
+            GlyphSet::Ascii => "ascii",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|set| set.id() == id)
+    }
+
+    /// 界面中显示的名字的翻译键
+    pub fn title_key(self) -> &'static str {
+        match self {
+            GlyphSet::Emoji => "glyphs_emoji",
+            GlyphSet::Unicode => "glyphs_unicode",
+            GlyphSet::Ascii => "glyphs_ascii",
+        }
+    }
+
+    /// 没有在设置或命令行中选择字符集时，根据环境变量猜测终端能显示的字符
+    pub fn detect() -> Self {
+        Self::detect_from(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+    }
+
+    /// 与 `detect` 相同，环境变量从 `var` 读取
+    pub fn detect_from(var: impl Fn(&str) -> Option<String>) -> Self {
+        // 不是 UTF-8 的 locale 连方块字符也显示不了，Windows 没有 locale 变量
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .find_map(|name| var(name));
+        match locale {
+            Some(locale) => {
+                let locale = locale.to_lowercase();
+                if !locale.contains("utf-8") && !locale.contains("utf8") {
+                    return GlyphSet::Ascii;
+                }
+            }
+            None if !cfg!(windows) => return GlyphSet::Ascii,
+            None => {}
+        }
+        match var("TERM").as_deref() {
+            Some("dumb") => return GlyphSet::Ascii,
+            // Linux 虚拟控制台的字体没有 emoji
+            Some("linux") => return GlyphSet::Unicode,
+            _ => {}
+        }
+        // 只有 Windows Terminal 能正确显示 emoji 的宽度；远程终端的情况无从得知
+        let windows_console = cfg!(windows) && var("WT_SESSION").is_none();
+        let remote = var("SSH_CONNECTION").is_some() || var("SSH_TTY").is_some();
+        if windows_console || remote {
+            GlyphSet::Unicode
+        } else {
+            GlyphSet::Emoji
+        }
+    }
+}
+
+/// 游戏棋盘上使用的字符，同一个字段在每个字符集中的显示宽度相同
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Glyphs {
+    pub set: GlyphSet,
+    /// 俄罗斯方块的方块和蛇身，两列宽
+    pub block: &'static str,
+    /// 俄罗斯方块和贪吃蛇的空白格子，两列宽
+    pub empty: &'static str,
+    /// 贪吃蛇的食物，两列宽
+    pub apple: &'static str,
+    pub candy: &'static str,
+    /// 扫雷的格子，四列宽
+    pub mine: &'static str,
+    pub flag: &'static str,
+    pub hidden: &'static str,
+    /// 黄金矿工的物品、钩子和绳子，一列宽
+    pub gold_large: char,
+    pub gold_small: char,
+    pub stone_large: char,
+    pub stone_small: char,
+    pub hook: char,
+    pub rope: char,
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Self::new(GlyphSet::Emoji)
+    }
+}
+
+impl Glyphs {
+    pub fn new(set: GlyphSet) -> Self {
+        let unicode = Self {
+            set,
+            block: "██",
+            empty: "··",
+            apple: "◉ ",
+            candy: "◇ ",
+            mine: "  ✱ ",
+            flag: "  ⚑ ",
+            hidden: "  ■ ",
+            gold_large: '◆',
+            gold_small: '♦',
+            stone_large: '■',
+            stone_small: '□',
+            hook: '▼',
+            rope: '│',
+        };
+        match set {
+            GlyphSet::Emoji => Self {
+                apple: "🍎",
+                candy: "🍬",
+                mine: " 💣 ",
+                flag: " 🚩 ",
+                ..unicode
+            },
+            GlyphSet::Unicode => unicode,
+            GlyphSet::Ascii => Self {
+                set,
+                block: "[]",
+                empty: " .",
+                apple: "()",
+                candy: "<>",
+                mine: "  * ",
+                flag: "  F ",
+                hidden: "  # ",
+                gold_large: '$',
+                gold_small: '*',
+                stone_large: '#',
+                stone_small: 'o',
+                hook: 'V',
+                rope: '|',
+            },
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crossterm::event::{self, Event};
+use std::{
+    io,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::replay::InputEvent;
+
+/// 一次输入和它到达的时刻
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimedEvent {
+    pub event: InputEvent,
+    pub at: Instant,
+}
+
+/// 在单独的线程中读取终端输入
+///
+/// 主循环绘制和更新时输入线程继续读取，每一帧一次取走所有已经到达的输入，
+/// 快速连续的按键不会因为等待重绘而延迟或丢失。
+pub struct InputThread {
+    receiver: Receiver<io::Result<TimedEvent>>,
+    // 读取出错前到达的输入先交给主循环，错误留到下一次 wait 返回
+    error: Option<io::Error>,
+}
+
+impl InputThread {
+    /// 从终端读取输入
+    pub fn spawn() -> Self {
+        Self::spawn_with(event::read)
+    }
+
+    /// 从 `read` 读取输入，`read` 出错后线程结束
+    pub fn spawn_with<F>(mut read: F) -> Self
+    where
+        F: FnMut() -> io::Result<Event> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || loop {
+            let result = read().map(|event| TimedEvent {
+                event: InputEvent::from(event),
+                at: Instant::now(),
+            });
+            let failed = result.is_err();
+            // 主循环退出后接收端被丢弃，发送失败时线程结束
+            if sender.send(result).is_err() || failed {
+                break;
+            }
+        });
+        Self {
+            receiver,
+            error: None,
+        }
+    }
+
+    /// 最多等待 `timeout`，返回已经到达的所有输入，按到达的先后排列
+    pub fn wait(&mut self, timeout: Duration) -> io::Result<Vec<TimedEvent>> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        let mut events = Vec::new();
+        match self.receiver.recv_timeout(timeout) {
+            Ok(result) => events.push(result?),
+            Err(RecvTimeoutError::Timeout) => return Ok(events),
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::BrokenPipe,
+                    "input thread stopped",
+                ))
+            }
+        }
+        for result in self.receiver.try_iter() {
+            match result {
+                Ok(event) => events.push(event),
+                Err(err) => {
+                    self.error = Some(err);
+                    break;
+                }
+            }
+        }
+        Ok(events)
+    }
+}
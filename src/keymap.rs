@@ -0,0 +1,232 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeMap;
+
+/// 游戏中可以重新绑定按键的操作
+///
+/// 每个游戏通过 `ShellGame::actions` 声明自己用到的操作，`GameShell` 把按键换成操作后再交给游戏。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    MoveLeft,
+    MoveRight,
+    MoveUp,
+    MoveDown,
+    /// 俄罗斯方块：旋转方块
+    Rotate,
+    /// 俄罗斯方块：加速下落一格
+    SoftDrop,
+    /// 俄罗斯方块：直接落到底
+    HardDrop,
+    /// 扫雷：翻开光标所在的格子
+    Reveal,
+    /// 扫雷：插上或者拔掉旗子
+    Flag,
+    /// 黄金矿工：放出钩子
+    ReleaseHook,
+    /// 所有游戏：打开或关闭暂停菜单
+    Pause,
+    /// 所有游戏：一局结束后重新开始
+    Restart,
+}
+
+impl Action {
+    pub const ALL: [Action; 12] = [
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::Rotate,
+        Action::SoftDrop,
+        Action::HardDrop,
+        Action::Reveal,
+        Action::Flag,
+        Action::ReleaseHook,
+        Action::Pause,
+        Action::Restart,
+    ];
+
+    /// 设置文件中使用的名字，也是帮助文本中的占位符，例如 `{move_left}`
+    pub fn id(self) -> &'static str {
+        match self {
+            Action::MoveLeft => "move_left",
+            Action::MoveRight => "move_right",
+            Action::MoveUp => "move_up",
+            Action::MoveDown => "move_down",
+            Action::Rotate => "rotate",
+            Action::SoftDrop => "soft_drop",
+            Action::HardDrop => "hard_drop",
+            Action::Reveal => "reveal",
+            Action::Flag => "flag",
+            Action::ReleaseHook => "release_hook",
+            Action::Pause => "pause",
+            Action::Restart => "restart",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.id() == id)
+    }
+
+    /// 设置界面中显示的名字的翻译键
+    pub fn title_key(self) -> String {
+        format!("action_{}", self.id())
+    }
+
+    /// 没有在设置中修改时绑定的按键
+    pub fn default_keys(self) -> &'static [KeyCode] {
+        match self {
+            Action::MoveLeft => &[KeyCode::Left, KeyCode::Char('a')],
+            Action::MoveRight => &[KeyCode::Right, KeyCode::Char('d')],
+            Action::MoveUp | Action::Rotate => &[KeyCode::Up, KeyCode::Char('w')],
+            Action::MoveDown | Action::SoftDrop => &[KeyCode::Down, KeyCode::Char('s')],
+            Action::HardDrop | Action::Reveal | Action::ReleaseHook => &[KeyCode::Char(' ')],
+            Action::Flag => &[KeyCode::Char('f')],
+            Action::Pause => &[KeyCode::Char('p'), KeyCode::Esc],
+            Action::Restart => &[KeyCode::Char('r')],
+        }
+    }
+}
+
+/// 每个操作绑定的按键
+///
+/// 同一个键可以绑定给不同游戏中的操作，例如空格既是俄罗斯方块的直接落下也是扫雷的翻开。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keymap {
+    bindings: BTreeMap<Action, Vec<KeyCode>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Keymap {
+    /// 所有操作都使用默认按键
+    pub fn new() -> Self {
+        Self {
+            bindings: Action::ALL
+                .iter()
+                .map(|&action| (action, action.default_keys().to_vec()))
+                .collect(),
+        }
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        &self.bindings[&action]
+    }
+
+    /// 替换操作的所有按键，空列表表示这个操作没有绑定按键
+    pub fn bind(&mut self, action: Action, keys: Vec<KeyCode>) {
+        self.bindings.insert(action, keys);
+    }
+
+    /// 在 `actions` 中查找按下的键对应的操作，带 Ctrl 或 Alt 的组合键不属于任何操作
+    pub fn action(&self, key: KeyEvent, actions: &[Action]) -> Option<Action> {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+        actions
+            .iter()
+            .copied()
+            .find(|&action| self.keys(action).contains(&key.code))
+    }
+
+    /// 帮助文本中显示的按键，例如 `←/A`；没有绑定按键时显示 `-`
+    pub fn describe(&self, action: Action) -> String {
+        let keys = self.keys(action);
+        if keys.is_empty() {
+            return "-".to_string();
+        }
+        keys.iter()
+            .map(|&code| key_name(code))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// `q`、`b` 和 `?` 由 GameManager 处理（返回主菜单、老板键和帮助），不能绑定给游戏中的操作
+pub fn is_reserved(code: KeyCode) -> bool {
+    matches!(
+        code,
+        KeyCode::Char('q') | KeyCode::Char('b') | KeyCode::Char('?')
+    )
+}
+
+/// 界面中显示的按键名字
+pub fn key_name(code: KeyCode) -> String {
+    match code {
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_uppercase().to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        code => format_key_code(code),
+    }
+}
+
+/// 设置文件和录像文件中使用的按键名字：单个字符直接写出，空格和功能键使用名字
+///
+/// 逗号和双引号在设置文件中分别是分隔符和引号，也使用名字
+pub fn format_key_code(code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(',') => "comma".to_string(),
+        KeyCode::Char('"') => "quote".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("f{}", n),
+        KeyCode::Backspace => "backspace".to_string(),
+        KeyCode::Enter => "enter".to_string(),
+        KeyCode::Left => "left".to_string(),
+        KeyCode::Right => "right".to_string(),
+        KeyCode::Up => "up".to_string(),
+        KeyCode::Down => "down".to_string(),
+        KeyCode::Home => "home".to_string(),
+        KeyCode::End => "end".to_string(),
+        KeyCode::PageUp => "pageup".to_string(),
+        KeyCode::PageDown => "pagedown".to_string(),
+        KeyCode::Tab => "tab".to_string(),
+        KeyCode::BackTab => "backtab".to_string(),
+        KeyCode::Delete => "delete".to_string(),
+        KeyCode::Insert => "insert".to_string(),
+        KeyCode::Null => "null".to_string(),
+        KeyCode::Esc => "esc".to_string(),
+    }
+}
+
+pub fn parse_key_code(text: &str) -> Option<KeyCode> {
+    let mut chars = text.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    Some(match text {
+        "space" => KeyCode::Char(' '),
+        "comma" => KeyCode::Char(','),
+        "quote" => KeyCode::Char('"'),
+        "backspace" => KeyCode::Backspace,
+        "enter" => KeyCode::Enter,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "delete" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        "null" => KeyCode::Null,
+        "esc" => KeyCode::Esc,
+        _ => KeyCode::F(text.strip_prefix('f')?.parse().ok()?),
+    })
+}
@@ -1,6 +1,22 @@
+pub mod achievements;
+pub mod cli;
+pub mod clock;
+pub mod config;
+pub mod crash;
 pub mod game_manager;
 pub mod games;
+pub mod glyphs;
+pub mod input;
+pub mod keymap;
+pub mod replay;
+pub mod saves;
+pub mod scores;
+pub mod stats;
+pub mod theme;
 pub mod translation;
 
 pub use game_manager::GameManager;
-pub use games::game_trait::Game;
+pub use games::env::GameEnv;
+pub use games::game_trait::{Difficulty, Game, GameRequest};
+pub use games::registry::{GameDescriptor, GameRegistry};
+pub use games::shell::{GameShell, ShellGame};
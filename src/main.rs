@@ -1,64 +1,348 @@
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    cursor::Show,
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen, SetTitle,
+    },
 };
 use std::{
+    env,
     error::Error,
-    io::{self, Stdout},
-    time::Duration,
+    io::{self, Stdout, Write},
+    process,
+    time::{Duration, Instant},
 };
 use tui::{backend::CrosstermBackend, Terminal};
 
-use terminal_games::{game_manager::GameType, GameManager};
+use terminal_games::{
+    cli::{self, CliError, Options},
+    clock::FixedClock,
+    config::{self, Config},
+    crash,
+    glyphs::GlyphSet,
+    input::InputThread,
+    replay::{InputEvent, Player, Recorder, Recording},
+    scores::HighScores,
+    stats::Stats,
+    GameManager, GameRegistry,
+};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    // 设置终端
+    let mut options = cli::parse(env::args().skip(1)).unwrap_or_else(|err| exit_with_error(err));
+    if options.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+    if options.version {
+        println!("terminal-games {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+
+    let recording = options.replay.as_ref().map(|path| {
+        Recording::load(path).unwrap_or_else(|err| {
+            eprintln!("error: cannot read replay '{}': {}", path, err);
+            process::exit(1);
+        })
+    });
+
+    // 录像总是从全新的游戏开始：不载入存档，回放时也不记入排行榜
+    let mut game_manager = if let Some(recording) = &recording {
+        options.seed = Some(recording.seed);
+        options.game = recording.game.clone();
+        // 使用录制时的设置而不是本机的设置文件；游戏按录制时的终端大小布局，
+        // 而不是回放时的大小，要在创建游戏之前设置
+        let mut game_manager = GameManager::with_registry(GameRegistry::builtin())
+            .with_config(recording.settings.clone());
+        game_manager.handle_resize(recording.width, recording.height);
+        // v1 的录像没有保存字符集，只能按终端猜测
+        if recording.settings.glyphs.is_none() {
+            game_manager.set_glyphs(GlyphSet::detect());
+        }
+        game_manager
+    } else if options.record.is_some() {
+        options.seed.get_or_insert_with(rand::random);
+        // 设置文件无法解析时使用默认设置，回放时也是同样的设置；
+        // 错误在进入全屏界面之前打印，退出后仍然能看到
+        let config = Config::load().unwrap_or_else(|errors| {
+            let path = config::config_path().unwrap_or_default();
+            for err in errors {
+                eprintln!("warning: {}: {}", path.display(), err);
+            }
+            Config::default()
+        });
+        let detect_glyphs = config.glyphs.is_none();
+        let mut game_manager = GameManager::with_registry(GameRegistry::builtin())
+            .with_config(config)
+            .with_high_scores(HighScores::load())
+            .with_stats(Stats::load());
+        if detect_glyphs {
+            game_manager.set_glyphs(GlyphSet::detect());
+        }
+        game_manager
+    } else {
+        // 设置文件的错误在启动后显示在界面上，出错的部分使用默认设置
+        GameManager::new()
+    };
+
+    // 在进入全屏界面之前处理参数，出错时错误信息可以直接显示在终端里
+    apply_options(&mut game_manager, &options).unwrap_or_else(|err| exit_with_error(err));
+    if options.list {
+        for (id, title) in game_manager.game_titles() {
+            println!("{:<12} {}", id, title);
+        }
+        return Ok(());
+    }
+
+    // 设置终端；panic 时 hook 先恢复终端再写崩溃报告，guard 负责其它提前返回的情况
+    crash::install_hook(restore_terminal);
     enable_raw_mode()?;
+    let guard = TerminalGuard;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // 运行游戏管理器
-    let mut game_manager = GameManager::new();
-    let res = run_game(&mut terminal, &mut game_manager);
+    let mut recorded = Ok(());
+    let res = if let Some(recording) = recording {
+        let mut player = Player::new(recording);
+        if let Some(speed) = options.speed {
+            player.set_speed(speed);
+        }
+        if let Some(language) = options.language {
+            player.set_language(language);
+        }
+        player.set_theme(game_manager.theme());
+        run_replay(&mut terminal, &mut game_manager, &mut player)
+    } else {
+        let mut recorder = match options.record {
+            Some(_) => {
+                let size = terminal.size()?;
+                let seed = options.seed.unwrap_or_default();
+                let mut recording = Recording::new(seed, TICK_RATE, size.width, size.height);
+                recording.game = options.game.as_ref().map(|game| game.to_lowercase());
+                recording.settings = recorded_settings(&game_manager);
+                Some(Recorder::new(recording))
+            }
+            None => None,
+        };
+
+        // 运行游戏管理器
+        let boss_title = options.boss_title.as_deref();
+        let res = run_game(
+            &mut terminal,
+            &mut game_manager,
+            recorder.as_mut(),
+            boss_title,
+        );
+        if let (Some(path), Some(recorder)) = (&options.record, recorder) {
+            recorded = recorder.finish().save(path);
+        }
+        res
+    };
+    // 退出时保存还没结束的游戏，下次启动可以继续
+    let saved = game_manager.save_games();
+    let stats_saved = game_manager.save_stats();
 
     // 清理终端设置
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    drop(guard);
 
     if let Err(err) = res {
         println!("{:?}", err)
     }
+    if let Err(err) = saved {
+        eprintln!("Failed to save games: {}", err)
+    }
+    if let Err(err) = stats_saved {
+        eprintln!("Failed to save stats: {}", err)
+    }
+    if let Err(err) = recorded {
+        eprintln!("Failed to save recording: {}", err)
+    }
 
     Ok(())
 }
 
+fn apply_options(game_manager: &mut GameManager, options: &Options) -> Result<(), CliError> {
+    if let Some(language) = options.language {
+        game_manager.set_language(language);
+    }
+    if let Some(lang) = options.compile_language {
+        game_manager.set_compile_language(lang);
+    }
+    if let Some(seed) = options.seed {
+        game_manager.set_seed(seed);
+    }
+    if let Some(set) = options.glyphs {
+        game_manager.set_glyphs(set);
+    }
+    if let Some(game) = &options.game {
+        if !game_manager.start_game(&game.to_lowercase()) {
+            return Err(CliError::UnknownGame {
+                name: game.clone(),
+                available: game_manager.game_ids(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// 录像中保存的设置：命令行参数和按终端猜测的配色、字符集也写进去，回放时不再猜测
+fn recorded_settings(game_manager: &GameManager) -> Config {
+    let mut settings = game_manager.config().without_file();
+    settings.theme = Some(game_manager.theme().name);
+    settings.glyphs = Some(game_manager.glyphs().set);
+    settings
+}
+
+fn exit_with_error(err: CliError) -> ! {
+    match err {
+        // 游戏名错误时已经列出了可用的游戏，不再打印完整的用法
+        CliError::UnknownGame { .. } => eprintln!("error: {}", err),
+        _ => eprintln!("error: {}\n\n{}", err, cli::USAGE),
+    }
+    process::exit(2);
+}
+
+// 离开作用域时恢复终端，包括出错提前返回和 panic 展开
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+// 可能在 panic hook 中调用，忽略所有错误
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        Show
+    );
+}
+
+// 每次 update 推进的固定时间
+const TICK_RATE: Duration = Duration::from_millis(16);
+
 fn run_game(
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
     game_manager: &mut GameManager,
+    mut recorder: Option<&mut Recorder>,
+    boss_title: Option<&str>,
 ) -> io::Result<()> {
+    let mut clock = FixedClock::new(TICK_RATE);
+    let size = terminal.size()?;
+    game_manager.handle_resize(size.width, size.height);
+    crash::track(game_manager);
+    let mut input = InputThread::spawn();
     loop {
         terminal.draw(|f| game_manager.render(f, f.size()))?;
 
-        game_manager.update();
+        // 等到下一次 update，期间（包括绘制时）到达的输入一次全部取出
+        let mut events = input.wait(clock.time_until_tick())?.into_iter().peekable();
+        for tick_at in clock.tick_times() {
+            // 按到达时间把输入排在对应的 update 之前，同一帧内的多个按键依次生效
+            while let Some(timed) = events.next_if(|timed| timed.at < tick_at) {
+                if !handle_event(
+                    terminal,
+                    game_manager,
+                    &mut recorder,
+                    boss_title,
+                    timed.event,
+                )? {
+                    return Ok(());
+                }
+            }
+            game_manager.update(clock.step());
+            if let Some(recorder) = recorder.as_deref_mut() {
+                recorder.advance();
+            }
+        }
+        for timed in events {
+            if !handle_event(
+                terminal,
+                game_manager,
+                &mut recorder,
+                boss_title,
+                timed.event,
+            )? {
+                return Ok(());
+            }
+        }
+    }
+}
+
+// 处理一次输入，返回 false 表示退出程序
+fn handle_event(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    game_manager: &mut GameManager,
+    recorder: &mut Option<&mut Recorder>,
+    boss_title: Option<&str>,
+    event: InputEvent,
+) -> io::Result<bool> {
+    if let Some(recorder) = recorder.as_deref_mut() {
+        recorder.record(event);
+    }
+    crash::record_input(event);
+    match event {
+        InputEvent::Key(key) => {
+            let was_hiding = game_manager.is_hiding();
+            game_manager.handle_input(key);
+            if let Some(title) = boss_title {
+                if game_manager.is_hiding() != was_hiding {
+                    set_boss_title(terminal, title, game_manager.is_hiding())?;
+                }
+            }
+        }
+        InputEvent::Mouse(mouse) => game_manager.handle_mouse(mouse),
+        InputEvent::Resize(width, height) => game_manager.handle_resize(width, height),
+    }
+    crash::track(game_manager);
+    Ok(!game_manager.should_quit())
+}
+
+// 老板键打开时把原来的窗口标题压入终端的标题栈，关闭时弹出恢复
+fn set_boss_title(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    title: &str,
+    hiding: bool,
+) -> io::Result<()> {
+    let backend = terminal.backend_mut();
+    if hiding {
+        write!(backend, "\x1b[22;0t")?;
+        execute!(backend, SetTitle(title))
+    } else {
+        write!(backend, "\x1b[23;0t")?;
+        backend.flush()
+    }
+}
+
+// 回放时按键只控制播放，游戏输入全部来自录像
+fn run_replay(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    game_manager: &mut GameManager,
+    player: &mut Player,
+) -> io::Result<()> {
+    let mut last_frame = Instant::now();
+    loop {
+        terminal.draw(|f| {
+            let area = player.game_area(f.size());
+            game_manager.render(f, area);
+            f.render_widget(&*player, f.size());
+        })?;
+
+        let now = Instant::now();
+        player.advance(now - last_frame, game_manager);
+        last_frame = now;
+        crash::track(game_manager);
 
-        if event::poll(Duration::from_millis(16))? {
+        if event::poll(TICK_RATE)? {
             if let Event::Key(key) = event::read()? {
-                if key.code == KeyCode::Char('q') {
-                    if game_manager.state == GameType::MainMenu {
-                        return Ok(());
-                    } else {
-                        game_manager.state = GameType::MainMenu;
-                    }
-                } else {
-                    game_manager.handle_input(key.code);
+                if !player.handle_input(key, game_manager) {
+                    return Ok(());
                 }
             }
         }
@@ -0,0 +1,463 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use std::{fmt, fs, io, path::Path, time::Duration};
+use tui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Modifier,
+    text::Spans,
+    widgets::{Paragraph, Widget},
+};
+
+use crate::config::Config;
+use crate::game_manager::GameManager;
+use crate::keymap::{format_key_code, parse_key_code};
+use crate::theme::Theme;
+use crate::translation::{Language, Translations};
+
+/// 当前录像格式的版本
+///
+/// - v2：保存录制时的设置（按键、难度、配色和字符集），v1 的录像按默认设置回放
+pub const REPLAY_VERSION: u32 = 2;
+
+const HEADER_PREFIX: &str = "# terminal-games replay v";
+
+/// 回放速度的档位
+const SPEEDS: [f64; 7] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0];
+
+/// 录像中的一次输入
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputEvent {
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    Resize(u16, u16),
+}
+
+impl From<Event> for InputEvent {
+    fn from(event: Event) -> Self {
+        match event {
+            Event::Key(key) => InputEvent::Key(key),
+            Event::Mouse(mouse) => InputEvent::Mouse(mouse),
+            Event::Resize(width, height) => InputEvent::Resize(width, height),
+        }
+    }
+}
+
+/// 一段录像：随机数种子、终端大小、设置和按 tick 编号的输入
+///
+/// tick 是 `GameManager::update` 已经执行的次数，第 `n` 个 tick 的输入在第 `n` 次
+/// update 之前处理，所以回放与录制时的顺序完全一致。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recording {
+    pub seed: u64,
+    /// 命令行直接打开的游戏，从主菜单开始时为 None
+    pub game: Option<String>,
+    pub tick: Duration,
+    pub width: u16,
+    pub height: u16,
+    /// 录制开始时的设置，回放时使用同样的按键、难度、配色和字符集
+    pub settings: Config,
+    /// 录制结束时已经执行的 update 次数
+    pub end: u64,
+    pub events: Vec<(u64, InputEvent)>,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    /// 文件头不是录像，或者来自更新的版本
+    Header,
+    /// 第几行无法解析（从 1 开始）
+    Line(usize),
+    MissingField(&'static str),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayError::Io(err) => write!(f, "{}", err),
+            ReplayError::Header => write!(
+                f,
+                "not a terminal-games replay, or written by a newer version (expected v{})",
+                REPLAY_VERSION
+            ),
+            ReplayError::Line(line) => write!(f, "invalid replay data on line {}", line),
+            ReplayError::MissingField(field) => write!(f, "replay is missing '{}'", field),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(err: io::Error) -> Self {
+        ReplayError::Io(err)
+    }
+}
+
+impl Recording {
+    pub fn new(seed: u64, tick: Duration, width: u16, height: u16) -> Self {
+        Self {
+            seed,
+            game: None,
+            tick,
+            width,
+            height,
+            settings: Config::default(),
+            end: 0,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    /// 录像的总时长
+    pub fn duration(&self) -> Duration {
+        self.tick * self.end as u32
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}{}\n", HEADER_PREFIX, REPLAY_VERSION);
+        text.push_str(&format!("seed={}\n", self.seed));
+        if let Some(game) = &self.game {
+            text.push_str(&format!("game={}\n", game));
+        }
+        text.push_str(&format!("tick_ms={}\n", self.tick.as_millis()));
+        text.push_str(&format!("size={}x{}\n", self.width, self.height));
+        text.push_str(&format!("end={}\n", self.end));
+        // 设置按设置文件的格式逐行保存，跳过文件头
+        for line in self.settings.to_text().lines().skip(1) {
+            text.push_str(&format!("config={}\n", line));
+        }
+        for (tick, event) in &self.events {
+            text.push_str(&format!("{}\t{}\n", tick, format_event(event)));
+        }
+        text
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ReplayError> {
+        let mut lines = text.lines().enumerate();
+        let version: u32 = lines
+            .next()
+            .and_then(|(_, line)| line.strip_prefix(HEADER_PREFIX))
+            .and_then(|version| version.trim().parse().ok())
+            .ok_or(ReplayError::Header)?;
+        if version == 0 || version > REPLAY_VERSION {
+            return Err(ReplayError::Header);
+        }
+
+        let (mut seed, mut game, mut tick, mut size, mut end) = (None, None, None, None, None);
+        let (mut settings, mut settings_line) = (String::new(), None);
+        let mut events = Vec::new();
+        for (index, line) in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let invalid = ReplayError::Line(index + 1);
+            if let Some((tick, event)) = line.split_once('\t') {
+                let tick = tick.parse().map_err(|_| ReplayError::Line(index + 1))?;
+                events.push((tick, parse_event(event).ok_or(invalid)?));
+                continue;
+            }
+            match line.split_once('=').ok_or(invalid)? {
+                ("seed", value) => seed = value.parse().ok(),
+                ("game", value) => game = Some(value.to_string()),
+                ("tick_ms", value) => tick = value.parse().ok().map(Duration::from_millis),
+                ("size", value) => {
+                    size = value
+                        .split_once('x')
+                        .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                }
+                ("end", value) => end = value.parse().ok(),
+                ("config", value) => {
+                    settings_line.get_or_insert(index + 1);
+                    settings.push_str(value);
+                    settings.push('\n');
+                }
+                // 未知的字段留给以后的版本
+                _ => {}
+            }
+        }
+
+        let (width, height) = size.ok_or(ReplayError::MissingField("size"))?;
+        let settings = match settings_line {
+            Some(line) => Config::parse(&settings).map_err(|_| ReplayError::Line(line))?,
+            None => Config::default(),
+        };
+        Ok(Self {
+            seed: seed.ok_or(ReplayError::MissingField("seed"))?,
+            game,
+            tick: tick
+                .filter(|tick| !tick.is_zero())
+                .ok_or(ReplayError::MissingField("tick_ms"))?,
+            width,
+            height,
+            settings,
+            end: end.ok_or(ReplayError::MissingField("end"))?,
+            events,
+        })
+    }
+}
+
+/// 录制时使用：记录输入并统计 update 次数
+pub struct Recorder {
+    recording: Recording,
+}
+
+impl Recorder {
+    pub fn new(recording: Recording) -> Self {
+        Self { recording }
+    }
+
+    pub fn record(&mut self, event: InputEvent) {
+        let tick = self.recording.end;
+        self.recording.events.push((tick, event));
+    }
+
+    /// 每执行一次 update 调用一次
+    pub fn advance(&mut self) {
+        self.recording.end += 1;
+    }
+
+    pub fn finish(self) -> Recording {
+        self.recording
+    }
+}
+
+/// 回放录像，支持暂停、单步和调整速度
+pub struct Player {
+    recording: Recording,
+    next_event: usize,
+    tick: u64,
+    speed: usize,
+    paused: bool,
+    // 还没有消耗的回放时间（已经乘上速度）
+    pending: Duration,
+    area: Rect,
+    translations: Translations,
+    theme: Theme,
+}
+
+impl Player {
+    pub fn new(recording: Recording) -> Self {
+        let area = Rect::new(0, 0, recording.width, recording.height);
+        Self {
+            recording,
+            next_event: 0,
+            tick: 0,
+            speed: 2,
+            paused: false,
+            pending: Duration::ZERO,
+            area,
+            translations: Translations::new(),
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.translations.set_language(language);
+    }
+
+    pub fn set_theme(&mut self, theme: &Theme) {
+        self.theme = *theme;
+    }
+
+    /// 选择最接近的速度档位
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = (0..SPEEDS.len())
+            .min_by(|&a, &b| {
+                let da = (SPEEDS[a] - speed).abs();
+                let db = (SPEEDS[b] - speed).abs();
+                da.total_cmp(&db)
+            })
+            .unwrap_or(2);
+    }
+
+    pub fn speed(&self) -> f64 {
+        SPEEDS[self.speed]
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.tick >= self.recording.end && self.next_event >= self.recording.events.len()
+    }
+
+    /// 回放控制键：空格暂停，`+`/`-` 调整速度，`.` 暂停时单步，返回 false 表示退出回放
+    pub fn handle_input(&mut self, key: KeyEvent, manager: &mut GameManager) -> bool {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right => {
+                self.speed = (self.speed + 1).min(SPEEDS.len() - 1)
+            }
+            KeyCode::Char('-') | KeyCode::Left => self.speed = self.speed.saturating_sub(1),
+            KeyCode::Char('.') if self.paused => self.step(manager),
+            _ => {}
+        }
+        true
+    }
+
+    /// 推进真实时间 `dt`，按当前速度执行相应次数的 update
+    pub fn advance(&mut self, dt: Duration, manager: &mut GameManager) {
+        if self.paused {
+            return;
+        }
+        self.pending += dt.mul_f64(self.speed());
+        while self.pending >= self.recording.tick && !self.is_finished() {
+            self.pending -= self.recording.tick;
+            self.step(manager);
+        }
+        if self.is_finished() {
+            self.pending = Duration::ZERO;
+        }
+    }
+
+    // 处理当前 tick 的输入，然后执行一次 update
+    fn step(&mut self, manager: &mut GameManager) {
+        while let Some(&(tick, event)) = self.recording.events.get(self.next_event) {
+            if tick > self.tick {
+                break;
+            }
+            self.next_event += 1;
+            match event {
+                InputEvent::Key(key) => manager.handle_input(key),
+                InputEvent::Mouse(mouse) => manager.handle_mouse(mouse),
+                InputEvent::Resize(width, height) => {
+                    self.area = Rect::new(0, 0, width, height);
+                    manager.handle_resize(width, height);
+                }
+            }
+        }
+        if self.tick < self.recording.end {
+            manager.update(self.recording.tick);
+            self.tick += 1;
+        }
+    }
+
+    /// 录制时的游戏区域，不超过当前终端
+    pub fn game_area(&self, terminal: Rect) -> Rect {
+        self.area.intersection(terminal)
+    }
+
+    /// 在最后一行绘制回放状态
+    pub fn render_status(&self, buf: &mut Buffer, area: Rect) {
+        if area.height == 0 {
+            return;
+        }
+        let state = if self.is_finished() {
+            self.translations.get_text("replay_finished")
+        } else if self.paused {
+            self.translations.get_text("replay_paused")
+        } else {
+            self.translations.get_text("replay_playing")
+        };
+        let elapsed = self.recording.tick * self.tick as u32;
+        let status = format!(
+            " {} {}x  {:.1}s / {:.1}s  {}",
+            state,
+            self.speed(),
+            elapsed.as_secs_f64(),
+            self.recording.duration().as_secs_f64(),
+            self.translations.get_text("replay_controls")
+        );
+        let line = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
+        Paragraph::new(Spans::from(status))
+            .style(self.theme.highlight.add_modifier(Modifier::BOLD))
+            .render(line, buf);
+    }
+}
+
+impl Widget for &Player {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_status(buf, area);
+    }
+}
+
+pub(crate) fn format_event(event: &InputEvent) -> String {
+    match event {
+        InputEvent::Key(key) => format!(
+            "key\t{}\t{}",
+            format_key_code(key.code),
+            key.modifiers.bits()
+        ),
+        InputEvent::Mouse(mouse) => format!(
+            "mouse\t{}\t{}\t{}\t{}",
+            format_mouse_kind(mouse.kind),
+            mouse.column,
+            mouse.row,
+            mouse.modifiers.bits()
+        ),
+        InputEvent::Resize(width, height) => format!("resize\t{}\t{}", width, height),
+    }
+}
+
+fn parse_event(text: &str) -> Option<InputEvent> {
+    let fields: Vec<&str> = text.split('\t').collect();
+    match fields.as_slice() {
+        ["key", code, modifiers] => Some(InputEvent::Key(KeyEvent::new(
+            parse_key_code(code)?,
+            KeyModifiers::from_bits(modifiers.parse().ok()?)?,
+        ))),
+        ["mouse", kind, column, row, modifiers] => Some(InputEvent::Mouse(MouseEvent {
+            kind: parse_mouse_kind(kind)?,
+            column: column.parse().ok()?,
+            row: row.parse().ok()?,
+            modifiers: KeyModifiers::from_bits(modifiers.parse().ok()?)?,
+        })),
+        ["resize", width, height] => Some(InputEvent::Resize(
+            width.parse().ok()?,
+            height.parse().ok()?,
+        )),
+        _ => None,
+    }
+}
+
+fn format_mouse_kind(kind: MouseEventKind) -> String {
+    let button = |button: MouseButton| match button {
+        MouseButton::Left => "left",
+        MouseButton::Right => "right",
+        MouseButton::Middle => "middle",
+    };
+    match kind {
+        MouseEventKind::Down(b) => format!("down:{}", button(b)),
+        MouseEventKind::Up(b) => format!("up:{}", button(b)),
+        MouseEventKind::Drag(b) => format!("drag:{}", button(b)),
+        MouseEventKind::Moved => "moved".to_string(),
+        MouseEventKind::ScrollDown => "scrolldown".to_string(),
+        MouseEventKind::ScrollUp => "scrollup".to_string(),
+    }
+}
+
+fn parse_mouse_kind(text: &str) -> Option<MouseEventKind> {
+    let button = |name: &str| match name {
+        "left" => Some(MouseButton::Left),
+        "right" => Some(MouseButton::Right),
+        "middle" => Some(MouseButton::Middle),
+        _ => None,
+    };
+    match text.split_once(':') {
+        Some(("down", b)) => Some(MouseEventKind::Down(button(b)?)),
+        Some(("up", b)) => Some(MouseEventKind::Up(button(b)?)),
+        Some(("drag", b)) => Some(MouseEventKind::Drag(button(b)?)),
+        _ => match text {
+            "moved" => Some(MouseEventKind::Moved),
+            "scrolldown" => Some(MouseEventKind::ScrollDown),
+            "scrollup" => Some(MouseEventKind::ScrollUp),
+            _ => None,
+        },
+    }
+}
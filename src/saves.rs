@@ -0,0 +1,193 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::PathBuf, str::FromStr};
+
+use crate::scores;
+
+/// 当前存档格式的版本，任何游戏的存档格式变化时加一，游戏载入时按 `SaveData::version` 读取旧的格式
+///
+/// - v2：贪吃蛇用转向队列 `turns` 代替 `next_direction`
+/// - v3：扫雷记录布雷时的难度 `difficulty`
+pub const SAVE_VERSION: u32 = 3;
+
+const HEADER_PREFIX: &str = "# terminal-games save v";
+
+/// 一个游戏的存档：按键保存的文本值
+///
+/// 每个值占一行，列表用空格分隔，二维表格的行用 `/` 分隔、格子用 `,` 分隔。
+#[derive(Clone, Debug, PartialEq)]
+pub struct SaveData {
+    version: u32,
+    values: BTreeMap<String, String>,
+}
+
+impl SaveData {
+    pub fn new() -> Self {
+        Self {
+            version: SAVE_VERSION,
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// 写入这个存档时使用的格式版本
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn set(&mut self, key: &str, value: impl Display) {
+        let value = value.to_string().replace(['\n', '\r'], " ");
+        self.values.insert(key.to_string(), value);
+    }
+
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.values.get(key)?.parse().ok()
+    }
+
+    pub fn set_list<T: Display>(&mut self, key: &str, items: impl IntoIterator<Item = T>) {
+        let items: Vec<String> = items.into_iter().map(|item| item.to_string()).collect();
+        self.set(key, items.join(" "));
+    }
+
+    /// 读取一个列表，任何一项无法解析时返回 None
+    pub fn get_list<T: FromStr>(&self, key: &str) -> Option<Vec<T>> {
+        self.values
+            .get(key)?
+            .split_whitespace()
+            .map(|item| item.parse().ok())
+            .collect()
+    }
+
+    pub fn set_grid<T: Display>(&mut self, key: &str, grid: &[Vec<T>]) {
+        let rows: Vec<String> = grid
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect();
+        self.set(key, rows.join("/"));
+    }
+
+    /// 读取一个 `rows` 行 `columns` 列的表格，大小不符或者无法解析时返回 None
+    pub fn get_grid<T: FromStr>(
+        &self,
+        key: &str,
+        rows: usize,
+        columns: usize,
+    ) -> Option<Vec<Vec<T>>> {
+        let grid: Vec<Vec<T>> = self
+            .values
+            .get(key)?
+            .split('/')
+            .map(|row| {
+                row.split(',')
+                    .map(|cell| cell.parse().ok())
+                    .collect::<Option<Vec<T>>>()
+            })
+            .collect::<Option<_>>()?;
+        let well_formed = grid.len() == rows && grid.iter().all(|row| row.len() == columns);
+        well_formed.then_some(grid)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}{}\n", HEADER_PREFIX, self.version);
+        for (key, value) in &self.values {
+            text.push_str(&format!("{}={}\n", key, value));
+        }
+        text
+    }
+
+    /// 解析存档文本，文件头不对、来自更新的版本或者有无法识别的行时返回 None
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut lines = text.lines();
+        let version: u32 = lines
+            .next()?
+            .strip_prefix(HEADER_PREFIX)?
+            .trim()
+            .parse()
+            .ok()?;
+        if version == 0 || version > SAVE_VERSION {
+            return None;
+        }
+
+        let mut values = BTreeMap::new();
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let (key, value) = line.split_once('=')?;
+            values.insert(key.to_string(), value.to_string());
+        }
+        Some(Self { version, values })
+    }
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 按游戏 id 保存进行中的游戏，每个游戏一个文件
+#[derive(Default)]
+pub struct SaveStore {
+    dir: Option<PathBuf>,
+}
+
+impl SaveStore {
+    /// 使用默认的数据目录 `<data_dir>/saves`
+    pub fn open() -> Self {
+        Self {
+            dir: scores::data_dir().map(|dir| dir.join("saves")),
+        }
+    }
+
+    pub fn in_dir(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: Some(dir.into()),
+        }
+    }
+
+    fn path(&self, game_id: &str) -> Option<PathBuf> {
+        Some(self.dir.as_ref()?.join(format!("{}.save", game_id)))
+    }
+
+    /// 读取存档，文件不存在时返回 None
+    ///
+    /// 无法识别的存档会被改名为 `.bad` 保留下来，不会再次载入。
+    pub fn load(&self, game_id: &str) -> Option<SaveData> {
+        let path = self.path(game_id)?;
+        let text = fs::read_to_string(&path).ok()?;
+        let data = SaveData::parse(&text);
+        if data.is_none() {
+            self.discard(game_id);
+        }
+        data
+    }
+
+    pub fn save(&self, game_id: &str, data: &SaveData) -> io::Result<()> {
+        let path = match self.path(game_id) {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // 与排行榜一样先写临时文件再改名
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, data.to_text())?;
+        fs::rename(tmp, path)
+    }
+
+    /// 删除存档，例如这一局已经结束
+    pub fn remove(&self, game_id: &str) -> io::Result<()> {
+        match self.path(game_id) {
+            Some(path) if path.exists() => fs::remove_file(path),
+            _ => Ok(()),
+        }
+    }
+
+    /// 把无法使用的存档改名为 `.bad`，方便排查又不会影响下次启动
+    pub fn discard(&self, game_id: &str) {
+        if let Some(path) = self.path(game_id) {
+            let _ = fs::rename(&path, path.with_extension("bad"));
+        }
+    }
+}
@@ -0,0 +1,350 @@
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tui::text::{Span, Spans};
+
+use crate::{theme::Theme, translation::Translations};
+
+/// 每个游戏保留的最高分条数
+pub const MAX_ENTRIES: usize = 10;
+/// 欢迎界面和主菜单中显示的条数
+pub const SHOWN_ENTRIES: usize = 5;
+
+/// 当前排行榜文件的版本
+///
+/// - v2：补充信息保存为 `lines:12` 这样的数值，显示时再翻译；v1 中是已经翻译好的文本
+const SCORES_VERSION: u32 = 2;
+
+const HEADER_PREFIX: &str = "# terminal-games scores v";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreEntry {
+    pub name: String,
+    pub score: i64,
+    /// 这一局开始的时间（Unix 秒），同一局多次上报时用来识别
+    pub timestamp: u64,
+    /// 游戏相关的补充信息，例如俄罗斯方块消除的行数
+    pub detail: ScoreDetail,
+}
+
+/// 排行榜上的补充信息，只保存数值，显示时按当前语言翻译
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ScoreDetail {
+    #[default]
+    None,
+    /// 俄罗斯方块消除的行数
+    Lines(u64),
+    /// 贪吃蛇的长度
+    Length(u64),
+    /// 2048 中最大的方块
+    MaxTile(u64),
+    /// 扫雷用的秒数
+    Seconds(u64),
+    /// 淘金者通过的关卡
+    Level(u64),
+    /// v1 文件中已经翻译好的文本，原样显示
+    Text(String),
+}
+
+impl ScoreDetail {
+    /// 当前语言的显示文本
+    pub fn text(&self, translations: &Translations) -> String {
+        let (key, value) = match self {
+            ScoreDetail::None => return String::new(),
+            ScoreDetail::Text(text) => return text.clone(),
+            ScoreDetail::Lines(value) => ("score_lines", value),
+            ScoreDetail::Length(value) => ("score_length", value),
+            ScoreDetail::MaxTile(value) => ("score_max_tile", value),
+            ScoreDetail::Seconds(value) => ("score_seconds", value),
+            ScoreDetail::Level(value) => ("score_level", value),
+        };
+        translations
+            .get_global_text(key)
+            .replace("{value}", &value.to_string())
+    }
+
+    // 文件中的格式：`lines:12`，没有补充信息时为空
+    fn to_field(&self) -> String {
+        let (kind, value) = match self {
+            ScoreDetail::None => return String::new(),
+            ScoreDetail::Text(text) => return format!("text:{}", sanitize(text)),
+            ScoreDetail::Lines(value) => ("lines", value),
+            ScoreDetail::Length(value) => ("length", value),
+            ScoreDetail::MaxTile(value) => ("max_tile", value),
+            ScoreDetail::Seconds(value) => ("seconds", value),
+            ScoreDetail::Level(value) => ("level", value),
+        };
+        format!("{}:{}", kind, value)
+    }
+
+    fn parse_field(field: &str) -> Option<Self> {
+        if field.is_empty() {
+            return Some(ScoreDetail::None);
+        }
+        let (kind, value) = field.split_once(':')?;
+        if kind == "text" {
+            return Some(ScoreDetail::Text(value.to_string()));
+        }
+        let value = value.parse().ok()?;
+        match kind {
+            "lines" => Some(ScoreDetail::Lines(value)),
+            "length" => Some(ScoreDetail::Length(value)),
+            "max_tile" => Some(ScoreDetail::MaxTile(value)),
+            "seconds" => Some(ScoreDetail::Seconds(value)),
+            "level" => Some(ScoreDetail::Level(value)),
+            _ => None,
+        }
+    }
+}
+
+/// 游戏上报给 GameManager 的一局成绩
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameResult {
+    pub score: i64,
+    pub detail: ScoreDetail,
+    pub started_at: u64,
+}
+
+/// 按游戏 id 保存的本地排行榜
+#[derive(Default)]
+pub struct HighScores {
+    tables: HashMap<String, Vec<ScoreEntry>>,
+    path: Option<PathBuf>,
+}
+
+impl HighScores {
+    /// 从默认位置读取排行榜，文件不存在或损坏的行会被忽略
+    pub fn load() -> Self {
+        match default_path() {
+            Some(path) => Self::load_from(path),
+            None => Self::default(),
+        }
+    }
+
+    pub fn load_from(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let tables = fs::read_to_string(&path)
+            .map(|content| parse(&content))
+            .unwrap_or_default();
+        Self {
+            tables,
+            path: Some(path),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut ids: Vec<&String> = self.tables.keys().collect();
+        ids.sort();
+        let mut content = format!("{}{}\n", HEADER_PREFIX, SCORES_VERSION);
+        for id in ids {
+            for entry in &self.tables[id] {
+                content.push_str(&format!(
+                    "{}\t{}\t{}\t{}\t{}\n",
+                    sanitize(id),
+                    entry.score,
+                    sanitize(&entry.name),
+                    entry.timestamp,
+                    entry.detail.to_field()
+                ));
+            }
+        }
+
+        // 先写临时文件再改名，避免写到一半时留下损坏的文件
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, content)?;
+        fs::rename(tmp, path)
+    }
+
+    pub fn top(&self, game_id: &str) -> &[ScoreEntry] {
+        self.tables.get(game_id).map_or(&[], |v| v.as_slice())
+    }
+
+    /// 记录一条成绩，返回名次（从 1 开始），没有上榜时返回 None
+    ///
+    /// 同一局（相同的开始时间和名字）再次上报时只保留较高的分数。
+    pub fn record(&mut self, game_id: &str, entry: ScoreEntry) -> Option<usize> {
+        let table = self.tables.entry(game_id.to_string()).or_default();
+
+        if let Some(index) = table
+            .iter()
+            .position(|e| e.timestamp == entry.timestamp && e.name == entry.name)
+        {
+            if table[index].score >= entry.score {
+                return Some(index + 1);
+            }
+            table.remove(index);
+        }
+
+        let rank = table
+            .iter()
+            .position(|e| entry.score > e.score)
+            .unwrap_or(table.len());
+        if rank >= MAX_ENTRIES {
+            return None;
+        }
+        table.insert(rank, entry);
+        table.truncate(MAX_ENTRIES);
+        Some(rank + 1)
+    }
+}
+
+fn parse(content: &str) -> HashMap<String, Vec<ScoreEntry>> {
+    // 没有文件头的旧文件按 v1 读取
+    let version: u32 = content
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix(HEADER_PREFIX))
+        .and_then(|version| version.trim().parse().ok())
+        .unwrap_or(1);
+
+    let mut tables: HashMap<String, Vec<ScoreEntry>> = HashMap::new();
+    for line in content.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 5 {
+            continue;
+        }
+        let (score, timestamp) = match (fields[1].parse(), fields[3].parse()) {
+            (Ok(score), Ok(timestamp)) => (score, timestamp),
+            _ => continue,
+        };
+        let detail = match version {
+            1 if fields[4].is_empty() => ScoreDetail::None,
+            1 => ScoreDetail::Text(fields[4].to_string()),
+            _ => match ScoreDetail::parse_field(fields[4]) {
+                Some(detail) => detail,
+                None => continue,
+            },
+        };
+        tables
+            .entry(fields[0].to_string())
+            .or_default()
+            .push(ScoreEntry {
+                name: fields[2].to_string(),
+                score,
+                timestamp,
+                detail,
+            });
+    }
+
+    for table in tables.values_mut() {
+        table.sort_by_key(|e| std::cmp::Reverse(e.score));
+        table.truncate(MAX_ENTRIES);
+    }
+    tables
+}
+
+fn sanitize(text: &str) -> String {
+    text.replace(['\t', '\n', '\r'], " ")
+}
+
+/// 按 XDG 规范返回数据目录：`$XDG_DATA_HOME/terminal-games`，
+/// 默认 `~/.local/share/terminal-games`，Windows 下使用 `%APPDATA%`
+pub fn data_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+    Some(base.join("terminal-games"))
+}
+
+fn default_path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("scores.txt"))
+}
+
+/// 当前用户名，用作排行榜上的名字
+pub fn player_name() -> String {
+    env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .ok()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "Player".to_string())
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// 把 Unix 秒格式化为 `YYYY-MM-DD`（UTC）
+pub fn format_date(timestamp: u64) -> String {
+    // 参考 Howard Hinnant 的 civil_from_days 算法
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// 欢迎界面和主菜单中使用的排行榜文本
+pub fn table_lines(
+    entries: &[ScoreEntry],
+    translations: &Translations,
+    theme: &Theme,
+) -> Vec<Spans<'static>> {
+    let mut lines = vec![Spans::from(Span::styled(
+        translations.get_global_text("high_scores"),
+        theme.title,
+    ))];
+
+    if entries.is_empty() {
+        lines.push(Spans::from(translations.get_global_text("no_high_scores")));
+    }
+
+    for (index, entry) in entries.iter().take(SHOWN_ENTRIES).enumerate() {
+        lines.push(Spans::from(format!(
+            "{}. {:<12} {:>7}  {:<12} {}",
+            index + 1,
+            entry.name,
+            entry.score,
+            entry.detail.text(translations),
+            format_date(entry.timestamp)
+        )));
+    }
+    lines
+}
+
+/// 游戏结束界面中显示的一行：本局名次或者当前最高分
+pub fn game_over_line(
+    entries: &[ScoreEntry],
+    rank: Option<usize>,
+    translations: &Translations,
+    theme: &Theme,
+) -> Spans<'static> {
+    match (rank, entries.first()) {
+        (Some(rank), _) => Spans::from(Span::styled(
+            format!(
+                "{} #{}",
+                translations.get_global_text("new_high_score"),
+                rank
+            ),
+            theme.heading,
+        )),
+        (None, Some(best)) => Spans::from(format!(
+            "{} {}",
+            translations.get_global_text("best_score"),
+            best.score
+        )),
+        (None, None) => Spans::from(""),
+    }
+}
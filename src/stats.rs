@@ -0,0 +1,272 @@
+use std::{collections::BTreeMap, fs, io, path::PathBuf, time::Duration};
+
+use crate::{
+    scores::{self, GameResult},
+    Difficulty,
+};
+
+const FILE_HEADER: &str = "# terminal-games stats v1";
+
+/// 游戏中发生的、统计和成就关心的事情，GameManager 通过 `take_events` 取走
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameEvent {
+    /// 开始了新的一局，继续存档不算
+    Started,
+    /// 一次消除了若干行
+    LinesCleared(u32),
+    /// 插上了一面旗子，拔掉旗子不算
+    MineFlagged,
+    /// 合并出了一个新的方块，带着它的数字
+    TileMerged(u32),
+    GoldCollected,
+    FoodEaten,
+    LevelCompleted,
+    /// 赢下了这一局，带着这一局的难度
+    Won(Difficulty),
+}
+
+/// 每个游戏各自累计的计数
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Counter {
+    LinesCleared,
+    /// 一次消除四行的次数
+    Tetrises,
+    MinesFlagged,
+    /// 合并出的最大方块，记录最大值而不是累加
+    MaxTile,
+    GoldCollected,
+    FoodEaten,
+    LevelsCompleted,
+    Wins,
+    /// 困难难度下赢下的局数
+    HardWins,
+}
+
+impl Counter {
+    pub const ALL: [Counter; 9] = [
+        Counter::LinesCleared,
+        Counter::Tetrises,
+        Counter::MinesFlagged,
+        Counter::MaxTile,
+        Counter::GoldCollected,
+        Counter::FoodEaten,
+        Counter::LevelsCompleted,
+        Counter::Wins,
+        Counter::HardWins,
+    ];
+
+    /// 统计文件中使用的名字
+    pub fn id(self) -> &'static str {
+        match self {
+            Counter::LinesCleared => "lines_cleared",
+            Counter::Tetrises => "tetrises",
+            Counter::MinesFlagged => "mines_flagged",
+            Counter::MaxTile => "max_tile",
+            Counter::GoldCollected => "gold_collected",
+            Counter::FoodEaten => "food_eaten",
+            Counter::LevelsCompleted => "levels_completed",
+            Counter::Wins => "wins",
+            Counter::HardWins => "hard_wins",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|counter| counter.id() == id)
+    }
+
+    /// 统计界面中的名字的翻译键
+    pub fn title_key(self) -> String {
+        format!("stat_{}", self.id())
+    }
+}
+
+/// 一个游戏的统计
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GameStats {
+    pub played: u64,
+    /// 真正在进行一局的时间，欢迎界面、暂停菜单和结束画面，
+    /// 以及被设置界面、帮助或者老板键盖住的时间都不算
+    pub time_played: Duration,
+    pub best_score: Option<i64>,
+    counters: BTreeMap<Counter, u64>,
+}
+
+impl GameStats {
+    pub fn counter(&self, counter: Counter) -> u64 {
+        self.counters.get(&counter).copied().unwrap_or(0)
+    }
+
+    /// 不为零的计数，按 `Counter::ALL` 的顺序
+    pub fn counters(&self) -> impl Iterator<Item = (Counter, u64)> + '_ {
+        self.counters
+            .iter()
+            .map(|(&counter, &value)| (counter, value))
+    }
+
+    fn add(&mut self, counter: Counter, amount: u64) {
+        *self.counters.entry(counter).or_default() += amount;
+    }
+
+    fn raise(&mut self, counter: Counter, value: u64) {
+        let current = self.counters.entry(counter).or_default();
+        *current = (*current).max(value);
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Option<()> {
+        match key {
+            "played" => self.played = value.parse().ok()?,
+            "time_ms" => self.time_played = Duration::from_millis(value.parse().ok()?),
+            "best_score" => self.best_score = Some(value.parse().ok()?),
+            _ => {
+                let counter = Counter::from_id(key)?;
+                self.counters.insert(counter, value.parse().ok()?);
+            }
+        }
+        Some(())
+    }
+}
+
+/// 所有游戏的统计，按游戏 id 保存在数据目录下的 `stats.txt`
+#[derive(Default)]
+pub struct Stats {
+    games: BTreeMap<String, GameStats>,
+    path: Option<PathBuf>,
+}
+
+impl Stats {
+    /// 从默认位置读取统计，文件不存在或损坏的行会被忽略
+    pub fn load() -> Self {
+        match default_path() {
+            Some(path) => Self::load_from(path),
+            None => Self::default(),
+        }
+    }
+
+    pub fn load_from(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let games = fs::read_to_string(&path)
+            .map(|content| parse(&content))
+            .unwrap_or_default();
+        Self {
+            games,
+            path: Some(path),
+        }
+    }
+
+    /// 只保存在内存中的统计不会写文件
+    pub fn save(&self) -> io::Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut content = format!("{}\n", FILE_HEADER);
+        for (id, stats) in &self.games {
+            content.push_str(&format!("{}\tplayed\t{}\n", id, stats.played));
+            content.push_str(&format!(
+                "{}\ttime_ms\t{}\n",
+                id,
+                stats.time_played.as_millis()
+            ));
+            if let Some(score) = stats.best_score {
+                content.push_str(&format!("{}\tbest_score\t{}\n", id, score));
+            }
+            for (counter, value) in stats.counters() {
+                content.push_str(&format!("{}\t{}\t{}\n", id, counter.id(), value));
+            }
+        }
+
+        // 先写临时文件再改名，避免写到一半时留下损坏的文件
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, content)?;
+        fs::rename(tmp, path)
+    }
+
+    /// 一个游戏的统计，没有玩过时全部为零
+    pub fn game(&self, id: &str) -> GameStats {
+        self.games.get(id).cloned().unwrap_or_default()
+    }
+
+    pub fn counter(&self, id: &str, counter: Counter) -> u64 {
+        self.games.get(id).map_or(0, |stats| stats.counter(counter))
+    }
+
+    pub fn total_played(&self) -> u64 {
+        self.games.values().map(|stats| stats.played).sum()
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.games.values().map(|stats| stats.time_played).sum()
+    }
+
+    /// 记录 `id` 游戏中发生的一件事
+    pub fn record_event(&mut self, id: &str, event: GameEvent) {
+        let stats = self.games.entry(id.to_string()).or_default();
+        match event {
+            GameEvent::Started => stats.played += 1,
+            GameEvent::LinesCleared(lines) => {
+                stats.add(Counter::LinesCleared, u64::from(lines));
+                if lines >= 4 {
+                    stats.add(Counter::Tetrises, 1);
+                }
+            }
+            GameEvent::MineFlagged => stats.add(Counter::MinesFlagged, 1),
+            GameEvent::TileMerged(tile) => stats.raise(Counter::MaxTile, u64::from(tile)),
+            GameEvent::GoldCollected => stats.add(Counter::GoldCollected, 1),
+            GameEvent::FoodEaten => stats.add(Counter::FoodEaten, 1),
+            GameEvent::LevelCompleted => stats.add(Counter::LevelsCompleted, 1),
+            GameEvent::Won(difficulty) => {
+                stats.add(Counter::Wins, 1);
+                if difficulty == Difficulty::Hard {
+                    stats.add(Counter::HardWins, 1);
+                }
+            }
+        }
+    }
+
+    /// 记录一局的成绩，只用来更新最高分
+    pub fn record_result(&mut self, id: &str, result: &GameResult) {
+        let stats = self.games.entry(id.to_string()).or_default();
+        stats.best_score = Some(
+            stats
+                .best_score
+                .map_or(result.score, |b| b.max(result.score)),
+        );
+    }
+
+    pub fn add_time(&mut self, id: &str, dt: Duration) {
+        self.games.entry(id.to_string()).or_default().time_played += dt;
+    }
+}
+
+fn parse(content: &str) -> BTreeMap<String, GameStats> {
+    let mut games: BTreeMap<String, GameStats> = BTreeMap::new();
+    for line in content.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        if let [id, key, value] = fields[..] {
+            // 不认识的统计项可能来自更新的版本，跳过这一行而不是丢掉整个文件
+            let _ = games.entry(id.to_string()).or_default().set(key, value);
+        }
+    }
+    games
+}
+
+fn default_path() -> Option<PathBuf> {
+    scores::data_dir().map(|dir| dir.join("stats.txt"))
+}
+
+/// 统计界面中显示的时长，例如 `1h 05m` 或 `42s`
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    match (seconds / 3600, seconds / 60 % 60) {
+        (0, 0) => format!("{}s", seconds),
+        (0, minutes) => format!("{}m {:02}s", minutes, seconds % 60),
+        (hours, minutes) => format!("{}h {:02}m", hours, minutes),
+    }
+}
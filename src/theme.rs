@@ -0,0 +1,152 @@
+use std::env;
+use tui::style::{Color, Modifier, Style};
+
+/// 可以在设置界面中选择的配色
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeName {
+    #[default]
+    Default,
+    /// 明亮的颜色加粗体，适合投影仪或者视力不好的玩家
+    HighContrast,
+    /// 只使用粗体、反色等文字样式，不输出颜色
+    Monochrome,
+    /// 仿照 Solarized 的柔和配色，需要支持真彩色的终端
+    Solarized,
+}
+
+impl ThemeName {
+    pub const ALL: [ThemeName; 4] = [
+        ThemeName::Default,
+        ThemeName::HighContrast,
+        ThemeName::Monochrome,
+        ThemeName::Solarized,
+    ];
+
+    /// 设置文件中使用的名字
+    pub fn id(self) -> &'static str {
+        match self {
+            ThemeName::Default => "default",
+            ThemeName::HighContrast => "high-contrast",
+            ThemeName::Monochrome => "monochrome",
+            ThemeName::Solarized => "solarized",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|name| name.id() == id)
+    }
+
+    /// 界面中显示的名字的翻译键
+    pub fn title_key(self) -> &'static str {
+        match self {
+            ThemeName::Default => "theme_default",
+            ThemeName::HighContrast => "theme_high_contrast",
+            ThemeName::Monochrome => "theme_monochrome",
+            ThemeName::Solarized => "theme_solarized",
+        }
+    }
+
+    /// 没有在设置中选择配色时使用，设置了 `NO_COLOR`（https://no-color.org）时为单色
+    pub fn detect() -> Self {
+        match env::var_os("NO_COLOR") {
+            Some(value) if !value.is_empty() => ThemeName::Monochrome,
+            _ => ThemeName::Default,
+        }
+    }
+}
+
+/// 界面中各种元素的样式，所有游戏和菜单都从这里取样式而不是直接写颜色
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    pub name: ThemeName,
+    /// 标题、边框上的名字和分数等标签
+    pub title: Style,
+    /// 说明中的小标题、开始提示和好消息（胜利、新纪录）
+    pub heading: Style,
+    /// 列表中当前选中的一项
+    pub selected: Style,
+    /// 选中的菜单行和扫雷的光标
+    pub highlight: Style,
+    /// 失败、地雷和钩子
+    pub danger: Style,
+    /// 空白格子和石头
+    pub muted: Style,
+    /// 不属于以上几类、需要和正文区分开的文字
+    pub accent: Style,
+    /// 玩家控制的东西：正在下落的方块、蛇
+    pub player: Style,
+    /// 已经落下的方块
+    pub block: Style,
+    /// 金块等值得抓取的东西
+    pub treasure: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new(ThemeName::Default)
+    }
+}
+
+impl Theme {
+    pub fn new(name: ThemeName) -> Self {
+        let fg = |color| Style::default().fg(color);
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        match name {
+            ThemeName::Default => Self {
+                name,
+                title: fg(Color::Yellow),
+                heading: fg(Color::Green),
+                selected: fg(Color::Green),
+                highlight: fg(Color::Black).bg(Color::Yellow),
+                danger: fg(Color::Red),
+                muted: fg(Color::Gray),
+                accent: fg(Color::Cyan),
+                player: fg(Color::Green),
+                block: fg(Color::Cyan),
+                treasure: fg(Color::Yellow),
+            },
+            ThemeName::HighContrast => Self {
+                name,
+                title: bold.fg(Color::White),
+                heading: bold.fg(Color::LightCyan),
+                selected: bold.fg(Color::LightYellow),
+                highlight: bold.fg(Color::Black).bg(Color::White),
+                danger: bold.fg(Color::LightRed),
+                muted: fg(Color::White),
+                accent: bold.fg(Color::LightCyan),
+                player: bold.fg(Color::LightGreen),
+                block: fg(Color::White),
+                treasure: bold.fg(Color::LightYellow),
+            },
+            ThemeName::Monochrome => Self {
+                name,
+                title: bold,
+                heading: bold,
+                selected: bold.add_modifier(Modifier::UNDERLINED),
+                highlight: Style::default().add_modifier(Modifier::REVERSED),
+                danger: bold,
+                muted: Style::default().add_modifier(Modifier::DIM),
+                accent: bold,
+                player: bold,
+                block: Style::default(),
+                treasure: bold,
+            },
+            ThemeName::Solarized => {
+                let yellow = Color::Rgb(0xb5, 0x89, 0x00);
+                Self {
+                    name,
+                    title: fg(yellow),
+                    heading: fg(Color::Rgb(0x85, 0x99, 0x00)),
+                    selected: fg(Color::Rgb(0x2a, 0xa1, 0x98)),
+                    highlight: fg(Color::Rgb(0x00, 0x2b, 0x36)).bg(yellow),
+                    danger: fg(Color::Rgb(0xdc, 0x32, 0x2f)),
+                    muted: fg(Color::Rgb(0x58, 0x6e, 0x75)),
+                    accent: fg(Color::Rgb(0x26, 0x8b, 0xd2)),
+                    player: fg(Color::Rgb(0x85, 0x99, 0x00)),
+                    block: fg(Color::Rgb(0x6c, 0x71, 0xc4)),
+                    treasure: fg(Color::Rgb(0xcb, 0x4b, 0x16)),
+                }
+            }
+        }
+    }
+}
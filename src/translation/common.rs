@@ -1,7 +1,7 @@
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Language {
     English,
     Chinese,
@@ -33,14 +33,574 @@ lazy_static! {
         m.insert("controls_desc", controls_desc);
 
         let mut compiling = HashMap::new();
-        compiling.insert(Language::English, "- Press p or Esc to pause game and pretend to compile code (fish).".to_string());
-        compiling.insert(Language::Chinese, "- 游戏中按p或者Esc可暂停游戏并假装编译代码（摸鱼）".to_string());
+        compiling.insert(Language::English, "- {pause}: pause a game  B: pretend to compile ({language})".to_string());
+        compiling.insert(Language::Chinese, "- {pause}：暂停游戏  B：假装编译 {language} 代码（摸鱼）".to_string());
         m.insert("compiling", compiling);
 
-        let mut compiling_language = HashMap::new();
-        compiling_language.insert(Language::English, "- C: Choose pretend compiling language".to_string());
-        compiling_language.insert(Language::Chinese, "- C: 选择假装编译的语言".to_string());
-        m.insert("compiling_language", compiling_language);
+        let mut settings_control = HashMap::new();
+        settings_control.insert(Language::English, "- S: Settings (language, difficulty...)".to_string());
+        settings_control.insert(Language::Chinese, "- S：设置（语言、难度等）".to_string());
+        m.insert("settings_control", settings_control);
+
+        let mut high_scores_control = HashMap::new();
+        high_scores_control.insert(Language::English, "- H: View high scores".to_string());
+        high_scores_control.insert(Language::Chinese, "- H：查看排行榜".to_string());
+        m.insert("high_scores_control", high_scores_control);
+
+        let mut achievements_control = HashMap::new();
+        achievements_control.insert(Language::English, "- A: Achievements and statistics".to_string());
+        achievements_control.insert(Language::Chinese, "- A：查看成就和统计".to_string());
+        m.insert("achievements_control", achievements_control);
+
+        let mut help_control = HashMap::new();
+        help_control.insert(Language::English, "- ?: Help for the selected game".to_string());
+        help_control.insert(Language::Chinese, "- ?：查看所选游戏的帮助".to_string());
+        m.insert("help_control", help_control);
+
+        let mut high_scores = HashMap::new();
+        high_scores.insert(Language::English, "High Scores".to_string());
+        high_scores.insert(Language::Chinese, "排行榜".to_string());
+        m.insert("high_scores", high_scores);
+
+        let mut no_high_scores = HashMap::new();
+        no_high_scores.insert(Language::English, "No scores yet".to_string());
+        no_high_scores.insert(Language::Chinese, "暂无记录".to_string());
+        m.insert("no_high_scores", no_high_scores);
+
+        let mut score_lines = HashMap::new();
+        score_lines.insert(Language::English, "{value} lines".to_string());
+        score_lines.insert(Language::Chinese, "{value} 行".to_string());
+        m.insert("score_lines", score_lines);
+
+        let mut score_length = HashMap::new();
+        score_length.insert(Language::English, "length {value}".to_string());
+        score_length.insert(Language::Chinese, "长度 {value}".to_string());
+        m.insert("score_length", score_length);
+
+        let mut score_max_tile = HashMap::new();
+        score_max_tile.insert(Language::English, "max tile {value}".to_string());
+        score_max_tile.insert(Language::Chinese, "最大方块 {value}".to_string());
+        m.insert("score_max_tile", score_max_tile);
+
+        let mut score_seconds = HashMap::new();
+        score_seconds.insert(Language::English, "{value}s".to_string());
+        score_seconds.insert(Language::Chinese, "{value} 秒".to_string());
+        m.insert("score_seconds", score_seconds);
+
+        let mut score_level = HashMap::new();
+        score_level.insert(Language::English, "level {value}".to_string());
+        score_level.insert(Language::Chinese, "第 {value} 关".to_string());
+        m.insert("score_level", score_level);
+
+        let mut new_high_score = HashMap::new();
+        new_high_score.insert(Language::English, "New high score! Rank".to_string());
+        new_high_score.insert(Language::Chinese, "新纪录！排名".to_string());
+        m.insert("new_high_score", new_high_score);
+
+        let mut best_score = HashMap::new();
+        best_score.insert(Language::English, "Best score:".to_string());
+        best_score.insert(Language::Chinese, "最高分：".to_string());
+        m.insert("best_score", best_score);
+
+        let mut back_to_menu = HashMap::new();
+        back_to_menu.insert(Language::English, "ESC: Back".to_string());
+        back_to_menu.insert(Language::Chinese, "ESC：返回".to_string());
+        m.insert("back_to_menu", back_to_menu);
+
+        let mut resume_saved = HashMap::new();
+        resume_saved.insert(Language::English, "Press C to continue your saved game".to_string());
+        resume_saved.insert(Language::Chinese, "按 C 键继续上次保存的游戏".to_string());
+        m.insert("resume_saved", resume_saved);
+
+        let mut pause_title = HashMap::new();
+        pause_title.insert(Language::English, "Paused".to_string());
+        pause_title.insert(Language::Chinese, "已暂停".to_string());
+        m.insert("pause_title", pause_title);
+
+        let mut pause_resume = HashMap::new();
+        pause_resume.insert(Language::English, "Resume".to_string());
+        pause_resume.insert(Language::Chinese, "继续游戏".to_string());
+        m.insert("pause_resume", pause_resume);
+
+        let mut pause_restart = HashMap::new();
+        pause_restart.insert(Language::English, "Restart".to_string());
+        pause_restart.insert(Language::Chinese, "重新开始".to_string());
+        m.insert("pause_restart", pause_restart);
+
+        let mut pause_controls = HashMap::new();
+        pause_controls.insert(Language::English, "Controls".to_string());
+        pause_controls.insert(Language::Chinese, "操作说明".to_string());
+        m.insert("pause_controls", pause_controls);
+
+        let mut pause_settings = HashMap::new();
+        pause_settings.insert(Language::English, "Settings".to_string());
+        pause_settings.insert(Language::Chinese, "设置".to_string());
+        m.insert("pause_settings", pause_settings);
+
+        let mut pause_main_menu = HashMap::new();
+        pause_main_menu.insert(Language::English, "Main Menu".to_string());
+        pause_main_menu.insert(Language::Chinese, "返回主菜单".to_string());
+        m.insert("pause_main_menu", pause_main_menu);
+
+        let mut pause_hint = HashMap::new();
+        pause_hint.insert(Language::English, "↑/↓: Select  Enter: Confirm  {pause}: Resume".to_string());
+        pause_hint.insert(Language::Chinese, "↑/↓：选择  回车：确认  {pause}：继续".to_string());
+        m.insert("pause_hint", pause_hint);
+
+        let mut pause_shell_controls = HashMap::new();
+        pause_shell_controls.insert(Language::English, "{pause}: Pause menu\nB: Pretend to compile (press B again to return)\nQ: Return to main menu".to_string());
+        pause_shell_controls.insert(Language::Chinese, "{pause}：暂停菜单\nB：假装编译（再按B返回）\nQ：返回主菜单".to_string());
+        m.insert("pause_shell_controls", pause_shell_controls);
+
+        let mut settings_title = HashMap::new();
+        settings_title.insert(Language::English, "Settings".to_string());
+        settings_title.insert(Language::Chinese, "设置".to_string());
+        m.insert("settings_title", settings_title);
+
+        let mut settings_difficulty = HashMap::new();
+        settings_difficulty.insert(Language::English, "difficulty".to_string());
+        settings_difficulty.insert(Language::Chinese, "难度".to_string());
+        m.insert("settings_difficulty", settings_difficulty);
+
+        let mut difficulty_easy = HashMap::new();
+        difficulty_easy.insert(Language::English, "Easy".to_string());
+        difficulty_easy.insert(Language::Chinese, "简单".to_string());
+        m.insert("difficulty_easy", difficulty_easy);
+
+        let mut difficulty_normal = HashMap::new();
+        difficulty_normal.insert(Language::English, "Normal".to_string());
+        difficulty_normal.insert(Language::Chinese, "普通".to_string());
+        m.insert("difficulty_normal", difficulty_normal);
+
+        let mut difficulty_hard = HashMap::new();
+        difficulty_hard.insert(Language::English, "Hard".to_string());
+        difficulty_hard.insert(Language::Chinese, "困难".to_string());
+        m.insert("difficulty_hard", difficulty_hard);
+
+        let mut settings_language = HashMap::new();
+        settings_language.insert(Language::English, "Language".to_string());
+        settings_language.insert(Language::Chinese, "界面语言".to_string());
+        m.insert("settings_language", settings_language);
+
+        let mut settings_compile_language = HashMap::new();
+        settings_compile_language.insert(Language::English, "Compile output".to_string());
+        settings_compile_language.insert(Language::Chinese, "假装编译的语言".to_string());
+        m.insert("settings_compile_language", settings_compile_language);
+
+        let mut settings_hint = HashMap::new();
+        settings_hint.insert(Language::English, "↑/↓: Select  ←/→: Change  ESC: Back".to_string());
+        settings_hint.insert(Language::Chinese, "↑/↓：选择  ←/→：修改  ESC：返回".to_string());
+        m.insert("settings_hint", settings_hint);
+
+        let mut settings_glyphs = HashMap::new();
+        settings_glyphs.insert(Language::English, "Symbols".to_string());
+        settings_glyphs.insert(Language::Chinese, "符号".to_string());
+        m.insert("settings_glyphs", settings_glyphs);
+
+        let mut glyphs_emoji = HashMap::new();
+        glyphs_emoji.insert(Language::English, "Emoji".to_string());
+        glyphs_emoji.insert(Language::Chinese, "Emoji".to_string());
+        m.insert("glyphs_emoji", glyphs_emoji);
+
+        let mut glyphs_unicode = HashMap::new();
+        glyphs_unicode.insert(Language::English, "Unicode (no emoji)".to_string());
+        glyphs_unicode.insert(Language::Chinese, "Unicode（无 emoji）".to_string());
+        m.insert("glyphs_unicode", glyphs_unicode);
+
+        let mut glyphs_ascii = HashMap::new();
+        glyphs_ascii.insert(Language::English, "ASCII".to_string());
+        glyphs_ascii.insert(Language::Chinese, "ASCII".to_string());
+        m.insert("glyphs_ascii", glyphs_ascii);
+
+        let mut settings_theme = HashMap::new();
+        settings_theme.insert(Language::English, "Color theme".to_string());
+        settings_theme.insert(Language::Chinese, "配色".to_string());
+        m.insert("settings_theme", settings_theme);
+
+        let mut theme_default = HashMap::new();
+        theme_default.insert(Language::English, "Default".to_string());
+        theme_default.insert(Language::Chinese, "默认".to_string());
+        m.insert("theme_default", theme_default);
+
+        let mut theme_high_contrast = HashMap::new();
+        theme_high_contrast.insert(Language::English, "High contrast".to_string());
+        theme_high_contrast.insert(Language::Chinese, "高对比度".to_string());
+        m.insert("theme_high_contrast", theme_high_contrast);
+
+        let mut theme_monochrome = HashMap::new();
+        theme_monochrome.insert(Language::English, "Monochrome".to_string());
+        theme_monochrome.insert(Language::Chinese, "单色".to_string());
+        m.insert("theme_monochrome", theme_monochrome);
+
+        let mut theme_solarized = HashMap::new();
+        theme_solarized.insert(Language::English, "Solarized".to_string());
+        theme_solarized.insert(Language::Chinese, "Solarized".to_string());
+        m.insert("theme_solarized", theme_solarized);
+
+        let mut settings_keys = HashMap::new();
+        settings_keys.insert(Language::English, "Key bindings".to_string());
+        settings_keys.insert(Language::Chinese, "按键设置".to_string());
+        m.insert("settings_keys", settings_keys);
+
+        let mut keys_title = HashMap::new();
+        keys_title.insert(Language::English, "Key Bindings".to_string());
+        keys_title.insert(Language::Chinese, "按键设置".to_string());
+        m.insert("keys_title", keys_title);
+
+        let mut keys_hint = HashMap::new();
+        keys_hint.insert(Language::English, "↑/↓: Select  Enter: Change  Backspace: Default  ESC: Back".to_string());
+        keys_hint.insert(Language::Chinese, "↑/↓：选择  回车：修改  退格：恢复默认  ESC：返回".to_string());
+        m.insert("keys_hint", keys_hint);
+
+        let mut keys_waiting = HashMap::new();
+        keys_waiting.insert(Language::English, "press a key...".to_string());
+        keys_waiting.insert(Language::Chinese, "请按新的按键...".to_string());
+        m.insert("keys_waiting", keys_waiting);
+
+        let mut keys_capture_hint = HashMap::new();
+        keys_capture_hint.insert(Language::English, "Press the new key  ESC: Cancel  (Q, B and ? are reserved)".to_string());
+        keys_capture_hint.insert(Language::Chinese, "按下新的按键  ESC：取消（Q、B 和 ? 不能使用）".to_string());
+        m.insert("keys_capture_hint", keys_capture_hint);
+
+        let mut action_move_left = HashMap::new();
+        action_move_left.insert(Language::English, "Move left".to_string());
+        action_move_left.insert(Language::Chinese, "向左".to_string());
+        m.insert("action_move_left", action_move_left);
+
+        let mut action_move_right = HashMap::new();
+        action_move_right.insert(Language::English, "Move right".to_string());
+        action_move_right.insert(Language::Chinese, "向右".to_string());
+        m.insert("action_move_right", action_move_right);
+
+        let mut action_move_up = HashMap::new();
+        action_move_up.insert(Language::English, "Move up".to_string());
+        action_move_up.insert(Language::Chinese, "向上".to_string());
+        m.insert("action_move_up", action_move_up);
+
+        let mut action_move_down = HashMap::new();
+        action_move_down.insert(Language::English, "Move down".to_string());
+        action_move_down.insert(Language::Chinese, "向下".to_string());
+        m.insert("action_move_down", action_move_down);
+
+        let mut action_rotate = HashMap::new();
+        action_rotate.insert(Language::English, "Rotate (Tetris)".to_string());
+        action_rotate.insert(Language::Chinese, "旋转（俄罗斯方块）".to_string());
+        m.insert("action_rotate", action_rotate);
+
+        let mut action_soft_drop = HashMap::new();
+        action_soft_drop.insert(Language::English, "Soft drop (Tetris)".to_string());
+        action_soft_drop.insert(Language::Chinese, "加速下落（俄罗斯方块）".to_string());
+        m.insert("action_soft_drop", action_soft_drop);
+
+        let mut action_hard_drop = HashMap::new();
+        action_hard_drop.insert(Language::English, "Hard drop (Tetris)".to_string());
+        action_hard_drop.insert(Language::Chinese, "直接落下（俄罗斯方块）".to_string());
+        m.insert("action_hard_drop", action_hard_drop);
+
+        let mut action_reveal = HashMap::new();
+        action_reveal.insert(Language::English, "Reveal cell (Minesweeper)".to_string());
+        action_reveal.insert(Language::Chinese, "翻开格子（扫雷）".to_string());
+        m.insert("action_reveal", action_reveal);
+
+        let mut action_flag = HashMap::new();
+        action_flag.insert(Language::English, "Flag mine (Minesweeper)".to_string());
+        action_flag.insert(Language::Chinese, "标记地雷（扫雷）".to_string());
+        m.insert("action_flag", action_flag);
+
+        let mut action_release_hook = HashMap::new();
+        action_release_hook.insert(Language::English, "Release hook (Gold Miner)".to_string());
+        action_release_hook.insert(Language::Chinese, "放出钩子（黄金矿工）".to_string());
+        m.insert("action_release_hook", action_release_hook);
+
+        let mut action_pause = HashMap::new();
+        action_pause.insert(Language::English, "Pause".to_string());
+        action_pause.insert(Language::Chinese, "暂停".to_string());
+        m.insert("action_pause", action_pause);
+
+        let mut action_restart = HashMap::new();
+        action_restart.insert(Language::English, "Restart after game over".to_string());
+        action_restart.insert(Language::Chinese, "结束后重新开始".to_string());
+        m.insert("action_restart", action_restart);
+
+        let mut replay_playing = HashMap::new();
+        replay_playing.insert(Language::English, "▶ REPLAY".to_string());
+        replay_playing.insert(Language::Chinese, "▶ 回放中".to_string());
+        m.insert("replay_playing", replay_playing);
+
+        let mut replay_paused = HashMap::new();
+        replay_paused.insert(Language::English, "⏸ PAUSED".to_string());
+        replay_paused.insert(Language::Chinese, "⏸ 已暂停".to_string());
+        m.insert("replay_paused", replay_paused);
+
+        let mut replay_finished = HashMap::new();
+        replay_finished.insert(Language::English, "■ FINISHED".to_string());
+        replay_finished.insert(Language::Chinese, "■ 回放结束".to_string());
+        m.insert("replay_finished", replay_finished);
+
+        let mut replay_controls = HashMap::new();
+        replay_controls.insert(Language::English, "Space: pause  +/-: speed  .: step  Q: quit".to_string());
+        replay_controls.insert(Language::Chinese, "空格：暂停  +/-：速度  .：单步  Q：退出".to_string());
+        m.insert("replay_controls", replay_controls);
+
+        let mut resize_title = HashMap::new();
+        resize_title.insert(Language::English, "Terminal too small".to_string());
+        resize_title.insert(Language::Chinese, "终端窗口太小".to_string());
+        m.insert("resize_title", resize_title);
+
+        let mut resize_needed = HashMap::new();
+        resize_needed.insert(Language::English, "Needed".to_string());
+        resize_needed.insert(Language::Chinese, "需要".to_string());
+        m.insert("resize_needed", resize_needed);
+
+        let mut resize_current = HashMap::new();
+        resize_current.insert(Language::English, "Current".to_string());
+        resize_current.insert(Language::Chinese, "当前".to_string());
+        m.insert("resize_current", resize_current);
+
+        let mut resize_hint = HashMap::new();
+        resize_hint.insert(Language::English, "Enlarge the window to continue, Q: back to menu".to_string());
+        resize_hint.insert(Language::Chinese, "放大窗口后继续游戏，Q：返回菜单".to_string());
+        m.insert("resize_hint", resize_hint);
+
+        let mut control_line = HashMap::new();
+        control_line.insert(Language::English, "{input}: {description}".to_string());
+        control_line.insert(Language::Chinese, "{input}：{description}".to_string());
+        m.insert("control_line", control_line);
+
+        let mut input_left_click = HashMap::new();
+        input_left_click.insert(Language::English, "Left click".to_string());
+        input_left_click.insert(Language::Chinese, "鼠标左键".to_string());
+        m.insert("input_left_click", input_left_click);
+
+        let mut input_right_click = HashMap::new();
+        input_right_click.insert(Language::English, "Right click".to_string());
+        input_right_click.insert(Language::Chinese, "鼠标右键".to_string());
+        m.insert("input_right_click", input_right_click);
+
+        let mut control_pause = HashMap::new();
+        control_pause.insert(Language::English, "Pause menu".to_string());
+        control_pause.insert(Language::Chinese, "暂停菜单".to_string());
+        m.insert("control_pause", control_pause);
+
+        let mut control_restart = HashMap::new();
+        control_restart.insert(Language::English, "Restart after game over".to_string());
+        control_restart.insert(Language::Chinese, "游戏结束后重新开始".to_string());
+        m.insert("control_restart", control_restart);
+
+        let mut help_title = HashMap::new();
+        help_title.insert(Language::English, "Help".to_string());
+        help_title.insert(Language::Chinese, "帮助".to_string());
+        m.insert("help_title", help_title);
+
+        let mut help_controls = HashMap::new();
+        help_controls.insert(Language::English, "Controls:".to_string());
+        help_controls.insert(Language::Chinese, "操作：".to_string());
+        m.insert("help_controls", help_controls);
+
+        let mut help_global = HashMap::new();
+        help_global.insert(Language::English, "Global keys:".to_string());
+        help_global.insert(Language::Chinese, "全局按键：".to_string());
+        m.insert("help_global", help_global);
+
+        let mut help_global_keys = HashMap::new();
+        help_global_keys.insert(Language::English, "?: Show or hide this help\nQ: Return to main menu (quit from the menu)\nCtrl+S: Save the current game\nB: Pretend to compile (press B again to return)".to_string());
+        help_global_keys.insert(Language::Chinese, "?：显示或关闭帮助\nQ：返回主菜单（在主菜单中退出）\nCtrl+S：保存当前的游戏\nB：假装编译（再按B返回）".to_string());
+        m.insert("help_global_keys", help_global_keys);
+
+        let mut help_close = HashMap::new();
+        help_close.insert(Language::English, "Press ? or Esc to close".to_string());
+        help_close.insert(Language::Chinese, "按 ? 或 Esc 关闭".to_string());
+        m.insert("help_close", help_close);
+
+        let mut config_errors_title = HashMap::new();
+        config_errors_title.insert(Language::English, "Problems in the config file".to_string());
+        config_errors_title.insert(Language::Chinese, "设置文件有错误".to_string());
+        m.insert("config_errors_title", config_errors_title);
+
+        let mut config_errors_hint = HashMap::new();
+        config_errors_hint.insert(Language::English, "Fix these lines and restart to apply them. Press any key to continue".to_string());
+        config_errors_hint.insert(Language::Chinese, "修改后重新启动才会生效，按任意键继续".to_string());
+        m.insert("config_errors_hint", config_errors_hint);
+
+        let mut achievements_title = HashMap::new();
+        achievements_title.insert(Language::English, "Achievements".to_string());
+        achievements_title.insert(Language::Chinese, "成就".to_string());
+        m.insert("achievements_title", achievements_title);
+
+        let mut achievements_unlocked = HashMap::new();
+        achievements_unlocked.insert(Language::English, "Unlocked".to_string());
+        achievements_unlocked.insert(Language::Chinese, "已解锁".to_string());
+        m.insert("achievements_unlocked", achievements_unlocked);
+
+        let mut achievement_unlocked = HashMap::new();
+        achievement_unlocked.insert(Language::English, "Achievement unlocked".to_string());
+        achievement_unlocked.insert(Language::Chinese, "解锁成就".to_string());
+        m.insert("achievement_unlocked", achievement_unlocked);
+
+        let mut stats_title = HashMap::new();
+        stats_title.insert(Language::English, "Statistics".to_string());
+        stats_title.insert(Language::Chinese, "统计".to_string());
+        m.insert("stats_title", stats_title);
+
+        let mut stats_played = HashMap::new();
+        stats_played.insert(Language::English, "Games played".to_string());
+        stats_played.insert(Language::Chinese, "局数".to_string());
+        m.insert("stats_played", stats_played);
+
+        let mut stats_time = HashMap::new();
+        stats_time.insert(Language::English, "Time played".to_string());
+        stats_time.insert(Language::Chinese, "游戏时间".to_string());
+        m.insert("stats_time", stats_time);
+
+        let mut stats_best = HashMap::new();
+        stats_best.insert(Language::English, "best score".to_string());
+        stats_best.insert(Language::Chinese, "最高分".to_string());
+        m.insert("stats_best", stats_best);
+
+        let mut stat_lines_cleared = HashMap::new();
+        stat_lines_cleared.insert(Language::English, "lines cleared".to_string());
+        stat_lines_cleared.insert(Language::Chinese, "消除行数".to_string());
+        m.insert("stat_lines_cleared", stat_lines_cleared);
+
+        let mut stat_tetrises = HashMap::new();
+        stat_tetrises.insert(Language::English, "tetrises".to_string());
+        stat_tetrises.insert(Language::Chinese, "四行消除".to_string());
+        m.insert("stat_tetrises", stat_tetrises);
+
+        let mut stat_mines_flagged = HashMap::new();
+        stat_mines_flagged.insert(Language::English, "mines flagged".to_string());
+        stat_mines_flagged.insert(Language::Chinese, "插旗".to_string());
+        m.insert("stat_mines_flagged", stat_mines_flagged);
+
+        let mut stat_max_tile = HashMap::new();
+        stat_max_tile.insert(Language::English, "max tile".to_string());
+        stat_max_tile.insert(Language::Chinese, "最大方块".to_string());
+        m.insert("stat_max_tile", stat_max_tile);
+
+        let mut stat_gold_collected = HashMap::new();
+        stat_gold_collected.insert(Language::English, "gold collected".to_string());
+        stat_gold_collected.insert(Language::Chinese, "金块".to_string());
+        m.insert("stat_gold_collected", stat_gold_collected);
+
+        let mut stat_food_eaten = HashMap::new();
+        stat_food_eaten.insert(Language::English, "food eaten".to_string());
+        stat_food_eaten.insert(Language::Chinese, "食物".to_string());
+        m.insert("stat_food_eaten", stat_food_eaten);
+
+        let mut stat_levels_completed = HashMap::new();
+        stat_levels_completed.insert(Language::English, "levels completed".to_string());
+        stat_levels_completed.insert(Language::Chinese, "通过关卡".to_string());
+        m.insert("stat_levels_completed", stat_levels_completed);
+
+        let mut stat_wins = HashMap::new();
+        stat_wins.insert(Language::English, "wins".to_string());
+        stat_wins.insert(Language::Chinese, "胜利".to_string());
+        m.insert("stat_wins", stat_wins);
+
+        let mut stat_hard_wins = HashMap::new();
+        stat_hard_wins.insert(Language::English, "expert wins".to_string());
+        stat_hard_wins.insert(Language::Chinese, "困难胜利".to_string());
+        m.insert("stat_hard_wins", stat_hard_wins);
+
+        let mut achievement_explorer = HashMap::new();
+        achievement_explorer.insert(Language::English, "Explorer".to_string());
+        achievement_explorer.insert(Language::Chinese, "探索者".to_string());
+        m.insert("achievement_explorer", achievement_explorer);
+
+        let mut achievement_explorer_desc = HashMap::new();
+        achievement_explorer_desc.insert(Language::English, "Play every game at least once".to_string());
+        achievement_explorer_desc.insert(Language::Chinese, "每个游戏都至少玩一局".to_string());
+        m.insert("achievement_explorer_desc", achievement_explorer_desc);
+
+        let mut achievement_tetris = HashMap::new();
+        achievement_tetris.insert(Language::English, "Clear a Tetris".to_string());
+        achievement_tetris.insert(Language::Chinese, "四行消除".to_string());
+        m.insert("achievement_tetris", achievement_tetris);
+
+        let mut achievement_tetris_desc = HashMap::new();
+        achievement_tetris_desc.insert(Language::English, "Clear four lines at once in Tetris".to_string());
+        achievement_tetris_desc.insert(Language::Chinese, "在俄罗斯方块中一次消除四行".to_string());
+        m.insert("achievement_tetris_desc", achievement_tetris_desc);
+
+        let mut achievement_line_collector = HashMap::new();
+        achievement_line_collector.insert(Language::English, "Line Collector".to_string());
+        achievement_line_collector.insert(Language::Chinese, "消行达人".to_string());
+        m.insert("achievement_line_collector", achievement_line_collector);
+
+        let mut achievement_line_collector_desc = HashMap::new();
+        achievement_line_collector_desc.insert(Language::English, "Clear 100 lines in Tetris".to_string());
+        achievement_line_collector_desc.insert(Language::Chinese, "在俄罗斯方块中累计消除 100 行".to_string());
+        m.insert("achievement_line_collector_desc", achievement_line_collector_desc);
+
+        let mut achievement_reach_2048 = HashMap::new();
+        achievement_reach_2048.insert(Language::English, "Reach 2048".to_string());
+        achievement_reach_2048.insert(Language::Chinese, "达到 2048".to_string());
+        m.insert("achievement_reach_2048", achievement_reach_2048);
+
+        let mut achievement_reach_2048_desc = HashMap::new();
+        achievement_reach_2048_desc.insert(Language::English, "Merge a 2048 tile".to_string());
+        achievement_reach_2048_desc.insert(Language::Chinese, "合并出 2048 方块".to_string());
+        m.insert("achievement_reach_2048_desc", achievement_reach_2048_desc);
+
+        let mut achievement_minesweeper_win = HashMap::new();
+        achievement_minesweeper_win.insert(Language::English, "Mine Sweeper".to_string());
+        achievement_minesweeper_win.insert(Language::Chinese, "扫雷成功".to_string());
+        m.insert("achievement_minesweeper_win", achievement_minesweeper_win);
+
+        let mut achievement_minesweeper_win_desc = HashMap::new();
+        achievement_minesweeper_win_desc.insert(Language::English, "Win a game of Minesweeper".to_string());
+        achievement_minesweeper_win_desc.insert(Language::Chinese, "赢下一局扫雷".to_string());
+        m.insert("achievement_minesweeper_win_desc", achievement_minesweeper_win_desc);
+
+        let mut achievement_minesweeper_expert = HashMap::new();
+        achievement_minesweeper_expert.insert(Language::English, "Win Expert Minesweeper".to_string());
+        achievement_minesweeper_expert.insert(Language::Chinese, "扫雷专家".to_string());
+        m.insert("achievement_minesweeper_expert", achievement_minesweeper_expert);
+
+        let mut achievement_minesweeper_expert_desc = HashMap::new();
+        achievement_minesweeper_expert_desc.insert(Language::English, "Win Minesweeper on hard difficulty".to_string());
+        achievement_minesweeper_expert_desc.insert(Language::Chinese, "在困难难度下赢下一局扫雷".to_string());
+        m.insert("achievement_minesweeper_expert_desc", achievement_minesweeper_expert_desc);
+
+        let mut achievement_gold_rush = HashMap::new();
+        achievement_gold_rush.insert(Language::English, "Gold Rush".to_string());
+        achievement_gold_rush.insert(Language::Chinese, "淘金热".to_string());
+        m.insert("achievement_gold_rush", achievement_gold_rush);
+
+        let mut achievement_gold_rush_desc = HashMap::new();
+        achievement_gold_rush_desc.insert(Language::English, "Collect 50 pieces of gold".to_string());
+        achievement_gold_rush_desc.insert(Language::Chinese, "累计抓到 50 块金子".to_string());
+        m.insert("achievement_gold_rush_desc", achievement_gold_rush_desc);
+
+        let mut achievement_hungry_snake = HashMap::new();
+        achievement_hungry_snake.insert(Language::English, "Hungry Snake".to_string());
+        achievement_hungry_snake.insert(Language::Chinese, "贪吃".to_string());
+        m.insert("achievement_hungry_snake", achievement_hungry_snake);
+
+        let mut achievement_hungry_snake_desc = HashMap::new();
+        achievement_hungry_snake_desc.insert(Language::English, "Eat 100 pieces of food".to_string());
+        achievement_hungry_snake_desc.insert(Language::Chinese, "累计吃掉 100 个食物".to_string());
+        m.insert("achievement_hungry_snake_desc", achievement_hungry_snake_desc);
+
+        let mut achievement_veteran = HashMap::new();
+        achievement_veteran.insert(Language::English, "Veteran".to_string());
+        achievement_veteran.insert(Language::Chinese, "老玩家".to_string());
+        m.insert("achievement_veteran", achievement_veteran);
+
+        let mut achievement_veteran_desc = HashMap::new();
+        achievement_veteran_desc.insert(Language::English, "Play 100 games".to_string());
+        achievement_veteran_desc.insert(Language::Chinese, "累计玩 100 局游戏".to_string());
+        m.insert("achievement_veteran_desc", achievement_veteran_desc);
+
+        let mut achievement_marathon = HashMap::new();
+        achievement_marathon.insert(Language::English, "Marathon".to_string());
+        achievement_marathon.insert(Language::Chinese, "马拉松".to_string());
+        m.insert("achievement_marathon", achievement_marathon);
+
+        let mut achievement_marathon_desc = HashMap::new();
+        achievement_marathon_desc.insert(Language::English, "Play for an hour in total".to_string());
+        achievement_marathon_desc.insert(Language::Chinese, "累计游戏一小时".to_string());
+        m.insert("achievement_marathon_desc", achievement_marathon_desc);
 
         m
     };
@@ -16,6 +16,11 @@ lazy_static! {
         level.insert(Language::Chinese, "关卡：".to_string());
         m.insert("goldminer.level", level);
 
+        let mut score = HashMap::new();
+        score.insert(Language::English, "Score:".to_string());
+        score.insert(Language::Chinese, "分数：".to_string());
+        m.insert("goldminer.score", score);
+
         let mut welcome_to = HashMap::new();
         welcome_to.insert(Language::English, "Welcome to".to_string());
         welcome_to.insert(Language::Chinese, "欢迎来到".to_string());
@@ -27,34 +32,44 @@ lazy_static! {
         how_to_play.insert(Language::Chinese, "游戏说明：".to_string());
         m.insert("goldminer.how_to_play", how_to_play);
 
+        let mut description = HashMap::new();
+        description.insert(Language::English, "Swing the hook to grab gold, avoid stones and clear every level.".to_string());
+        description.insert(Language::Chinese, "用摆动的钩子抓取金块，避开石头，通过每一关。".to_string());
+        m.insert("goldminer.description", description);
+
+        let mut control_release = HashMap::new();
+        control_release.insert(Language::English, "Release the hook".to_string());
+        control_release.insert(Language::Chinese, "释放钩子".to_string());
+        m.insert("goldminer.control_release", control_release);
+
         let mut hook_swing = HashMap::new();
         hook_swing.insert(Language::English, "1. The hook swings automatically".to_string());
         hook_swing.insert(Language::Chinese, "1. 钩子自动摆动".to_string());
         m.insert("goldminer.hook_swing", hook_swing);
 
         let mut press_space = HashMap::new();
-        press_space.insert(Language::English, "2. Press SPACE to release the hook".to_string());
-        press_space.insert(Language::Chinese, "2. 按空格键释放钩子".to_string());
+        press_space.insert(Language::English, "2. Press {release_hook} or click to release the hook".to_string());
+        press_space.insert(Language::Chinese, "2. 按 {release_hook} 键或点击鼠标释放钩子".to_string());
         m.insert("goldminer.press_space", press_space);
 
         let mut catch_gold = HashMap::new();
-        catch_gold.insert(Language::English, "3. Catch gold (◆/♦) to earn points:".to_string());
-        catch_gold.insert(Language::Chinese, "3. 抓取金块（◆/♦）获得分数：".to_string());
+        catch_gold.insert(Language::English, "3. Catch gold ({gold_large}/{gold_small}) to earn points:".to_string());
+        catch_gold.insert(Language::Chinese, "3. 抓取金块（{gold_large}/{gold_small}）获得分数：".to_string());
         m.insert("goldminer.catch_gold", catch_gold);
 
         let mut big_gold_points = HashMap::new();
-        big_gold_points.insert(Language::English, "   - Big gold (◆) : 200 points".to_string());
-        big_gold_points.insert(Language::Chinese, "   - 大金块（◆）：200分".to_string());
+        big_gold_points.insert(Language::English, "   - Big gold ({gold_large}) : 200 points".to_string());
+        big_gold_points.insert(Language::Chinese, "   - 大金块（{gold_large}）：200分".to_string());
         m.insert("goldminer.big_gold_points", big_gold_points);
 
         let mut small_gold_points = HashMap::new();
-        small_gold_points.insert(Language::English, "   - Small gold (♦) : 100 points".to_string());
-        small_gold_points.insert(Language::Chinese, "   - 小金块（♦）：100分".to_string());
+        small_gold_points.insert(Language::English, "   - Small gold ({gold_small}) : 100 points".to_string());
+        small_gold_points.insert(Language::Chinese, "   - 小金块（{gold_small}）：100分".to_string());
         m.insert("goldminer.small_gold_points", small_gold_points);
 
         let mut avoid_stones = HashMap::new();
-        avoid_stones.insert(Language::English, "4. Avoid stones (■/□) (-50 points)".to_string());
-        avoid_stones.insert(Language::Chinese, "4. 避开石头（■/□）（-50分）".to_string());
+        avoid_stones.insert(Language::English, "4. Avoid stones ({stone_large}/{stone_small}) (-50 points)".to_string());
+        avoid_stones.insert(Language::Chinese, "4. 避开石头（{stone_large}/{stone_small}）（-50分）".to_string());
         m.insert("goldminer.avoid_stones", avoid_stones);
 
         let mut collect_all_gold = HashMap::new();
@@ -82,16 +97,6 @@ lazy_static! {
         more_obstacles.insert(Language::Chinese, "- 出现更多障碍".to_string());
         m.insert("goldminer.more_obstacles", more_obstacles);
 
-        let mut controls_title = HashMap::new();
-        controls_title.insert(Language::English, "Game Controls:".to_string());
-        controls_title.insert(Language::Chinese, "游戏控制：".to_string());
-        m.insert("goldminer.controls_title", controls_title);
-
-        let mut space_control = HashMap::new();
-        space_control.insert(Language::English, "SPACE: Release/retract hook".to_string());
-        space_control.insert(Language::Chinese, "空格键：释放/收回钩子".to_string());
-        m.insert("goldminer.space_control", space_control);
-
         let mut quit_control = HashMap::new();
         quit_control.insert(Language::English, "Q: Return to main menu".to_string());
         quit_control.insert(Language::Chinese, "Q：返回主菜单".to_string());
@@ -104,6 +109,5 @@ lazy_static! {
 
         m
 
-
     };
 }
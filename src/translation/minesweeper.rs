@@ -22,8 +22,8 @@ lazy_static! {
         m.insert("minesweeper.game_over", game_over);
 
         let mut press_r_restart = HashMap::new();
-        press_r_restart.insert(Language::English, "Press 'r' to restart".to_string());
-        press_r_restart.insert(Language::Chinese, "按'r'键重新开始".to_string());
+        press_r_restart.insert(Language::English, "Press {restart} to restart".to_string());
+        press_r_restart.insert(Language::Chinese, "按 {restart} 键重新开始".to_string());
         m.insert("minesweeper.press_r_restart", press_r_restart);
 
         let mut how_to_play = HashMap::new();
@@ -31,33 +31,57 @@ lazy_static! {
         how_to_play.insert(Language::Chinese, "游戏说明：".to_string());
         m.insert("minesweeper.how_to_play", how_to_play);
 
-        let mut press_enter = HashMap::new();
-        press_enter.insert(Language::English, "Press Enter to start".to_string());
-        press_enter.insert(Language::Chinese, "按回车键开始".to_string());
-        m.insert("minesweeper.press_enter", press_enter);
-
-        let mut controls = HashMap::new();
-        controls.insert(
+        let mut description = HashMap::new();
+        description.insert(
             Language::English,
-            "- Use arrow keys or WASD to move\n- Space to reveal a cell\n- F to flag/unflag a mine\n- P/ESC to pause\n- Yellow highlight shows current cell".to_string()
+            "Reveal every safe cell without setting off a mine.".to_string(),
         );
-        controls.insert(
+        description.insert(
             Language::Chinese,
-            "- 使用方向键或WASD移动\n- 空格键翻开格子\n- F键标记/取消标记地雷\n- P/ESC暂停游戏\n- 黄色高亮显示当前选中的格子".to_string()
+            "翻开所有安全的格子，不要踩到地雷。".to_string(),
         );
-        m.insert("minesweeper.controls", controls);
+        m.insert("minesweeper.description", description);
+
+        let mut control_move = HashMap::new();
+        control_move.insert(Language::English, "Move the cursor".to_string());
+        control_move.insert(Language::Chinese, "移动光标".to_string());
+        m.insert("minesweeper.control_move", control_move);
+
+        let mut control_reveal = HashMap::new();
+        control_reveal.insert(Language::English, "Reveal a cell".to_string());
+        control_reveal.insert(Language::Chinese, "翻开格子".to_string());
+        m.insert("minesweeper.control_reveal", control_reveal);
+
+        let mut control_flag = HashMap::new();
+        control_flag.insert(Language::English, "Flag or unflag a mine".to_string());
+        control_flag.insert(Language::Chinese, "标记或取消标记地雷".to_string());
+        m.insert("minesweeper.control_flag", control_flag);
+
+        let mut cursor_tip = HashMap::new();
+        cursor_tip.insert(
+            Language::English,
+            "The highlighted cell is the current cell".to_string(),
+        );
+        cursor_tip.insert(Language::Chinese, "高亮的格子是当前选中的格子".to_string());
+        m.insert("minesweeper.cursor_tip", cursor_tip);
+
+        let mut press_enter = HashMap::new();
+        press_enter.insert(Language::English, "Press Enter to start".to_string());
+        press_enter.insert(Language::Chinese, "按回车键开始".to_string());
+        m.insert("minesweeper.press_enter", press_enter);
 
         let mut goal = HashMap::new();
         goal.insert(
             Language::English,
-            "Win by either:\n- Revealing all safe cells\n- Correctly flagging all mines".to_string()
+            "Win by either:\n- Revealing all safe cells\n- Correctly flagging all mines"
+                .to_string(),
         );
         goal.insert(
             Language::Chinese,
-            "胜利条件：\n- 翻开所有安全格子\n- 或正确标记所有地雷".to_string()
+            "胜利条件：\n- 翻开所有安全格子\n- 或正确标记所有地雷".to_string(),
         );
         m.insert("minesweeper.goal", goal);
-        
+
         m
     };
-}
\ No newline at end of file
+}
@@ -1,22 +1,34 @@
 mod common;
 mod goldminer;
+mod minesweeper;
 mod snake;
 mod tetris;
 mod twenty_forty_eight;
-mod minesweeper;
 pub use common::Language;
 
+pub use goldminer::GOLDMINER_TRANSLATIONS;
+pub use minesweeper::MINESWEEPER_TRANSLATIONS;
+pub use snake::SNAKE_TRANSLATIONS;
+pub use tetris::TETRIS_TRANSLATIONS;
+pub use twenty_forty_eight::TWENTY_FORTY_EIGHT_TRANSLATIONS;
+
+use crate::glyphs::Glyphs;
+use crate::keymap::{Action, Keymap};
 use common::COMMON_TRANSLATIONS;
-use goldminer::GOLDMINER_TRANSLATIONS;
-use snake::SNAKE_TRANSLATIONS;
 use std::collections::HashMap;
-use tetris::TETRIS_TRANSLATIONS;
-use twenty_forty_eight::TWENTY_FORTY_EIGHT_TRANSLATIONS;
-use minesweeper::MINESWEEPER_TRANSLATIONS;
+
+/// 翻译表：完整的键（含命名空间）到各语言文本的映射
+pub type TranslationTable = HashMap<&'static str, HashMap<Language, String>>;
+
+#[derive(Clone)]
 pub struct Translations {
     texts: HashMap<String, HashMap<Language, String>>,
     current_language: Language,
     namespace: String,
+    // 每个操作当前绑定的按键，用来替换文本中的 `{move_left}` 等占位符
+    keys: HashMap<&'static str, String>,
+    // 当前字符集中的食物、金块等字符，用来替换 `{apple}` 等占位符
+    glyphs: HashMap<&'static str, String>,
 }
 
 impl Translations {
@@ -31,45 +43,19 @@ impl Translations {
         Language::English // 默认返回英语
     }
 
+    /// 只包含通用翻译，游戏相关的翻译通过 `with_table` 加载
     pub fn new() -> Self {
-        let mut texts = HashMap::new();
-
-        // 加载所有翻译
-        texts.extend(
-            COMMON_TRANSLATIONS
-                .iter()
-                .map(|(k, v)| (k.to_string(), v.clone())),
-        );
-        texts.extend(
-            GOLDMINER_TRANSLATIONS
-                .iter()
-                .map(|(k, v)| (k.to_string(), v.clone())),
-        );
-        texts.extend(
-            TETRIS_TRANSLATIONS
-                .iter()
-                .map(|(k, v)| (k.to_string(), v.clone())),
-        );
-        texts.extend(
-            SNAKE_TRANSLATIONS
-                .iter()
-                .map(|(k, v)| (k.to_string(), v.clone())),
-        );
-        texts.extend(
-            TWENTY_FORTY_EIGHT_TRANSLATIONS
-                .iter()
-                .map(|(k, v)| (k.to_string(), v.clone())),
-        );
-        texts.extend(
-            MINESWEEPER_TRANSLATIONS
-                .iter()
-                .map(|(k, v)| (k.to_string(), v.clone())),
-        );
-        Self {
-            texts,
+        let mut translations = Self {
+            texts: HashMap::new(),
             current_language: Self::detect_system_language(),
             namespace: String::new(),
-        }
+            keys: HashMap::new(),
+            glyphs: HashMap::new(),
+        };
+        translations.set_keymap(&Keymap::new());
+        translations.set_glyphs(&Glyphs::default());
+        translations.add_table(&COMMON_TRANSLATIONS);
+        translations
     }
 
     pub fn get_text(&self, key: &str) -> String {
@@ -86,20 +72,94 @@ impl Translations {
                     .get(&self.current_language)
                     .or_else(|| translations.get(&Language::English))
             })
-            .cloned()
+            .map(|text| self.fill_keys(text))
             .unwrap_or_else(|| format!("Missing translation: {}", full_key))
     }
 
+    /// 忽略命名空间，直接按完整的键查找（用于通用文本）
+    pub fn get_global_text(&self, key: &str) -> String {
+        self.texts
+            .get(key)
+            .and_then(|translations| {
+                translations
+                    .get(&self.current_language)
+                    .or_else(|| translations.get(&Language::English))
+            })
+            .map(|text| self.fill_keys(text))
+            .unwrap_or_else(|| format!("Missing translation: {}", key))
+    }
+
+    // 把 `{pause}` 这样的占位符换成当前绑定的按键，`{apple}` 等换成当前字符集中的字符，
+    // 帮助文本因此总是与实际按键和棋盘一致
+    fn fill_keys(&self, text: &str) -> String {
+        if !text.contains('{') {
+            return text.to_string();
+        }
+        self.keys
+            .iter()
+            .chain(&self.glyphs)
+            .fold(text.to_string(), |text, (id, value)| {
+                text.replace(&format!("{{{}}}", id), value)
+            })
+    }
+
     pub fn with_namespace(mut self, namespace: &str) -> Self {
         self.namespace = namespace.to_string();
         self
     }
 
+    pub fn with_table(mut self, table: &TranslationTable) -> Self {
+        self.add_table(table);
+        self
+    }
+
+    pub fn add_table(&mut self, table: &TranslationTable) {
+        self.texts
+            .extend(table.iter().map(|(k, v)| (k.to_string(), v.clone())));
+    }
+
     pub fn set_language(&mut self, language: Language) {
         self.current_language = language;
     }
 
+    /// 按键修改后调用，之后取出的文本使用新的按键
+    pub fn set_keymap(&mut self, keymap: &Keymap) {
+        self.keys = Action::ALL
+            .iter()
+            .map(|&action| (action.id(), keymap.describe(action)))
+            .collect();
+    }
+
+    /// 当前绑定给 `action` 的按键，与文本中 `{move_left}` 等占位符替换成的内容相同
+    pub fn key_text(&self, action: Action) -> String {
+        self.keys
+            .get(action.id())
+            .cloned()
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    /// 字符集修改后调用
+    pub fn set_glyphs(&mut self, glyphs: &Glyphs) {
+        let chars = [
+            ("gold_large", glyphs.gold_large),
+            ("gold_small", glyphs.gold_small),
+            ("stone_large", glyphs.stone_large),
+            ("stone_small", glyphs.stone_small),
+        ];
+        self.glyphs = [("apple", glyphs.apple), ("candy", glyphs.candy)]
+            .iter()
+            .map(|&(id, glyph)| (id, glyph.trim().to_string()))
+            .chain(chars.iter().map(|&(id, glyph)| (id, glyph.to_string())))
+            .collect();
+    }
+
     pub fn current_language(&self) -> Language {
         self.current_language
     }
 }
+
+impl Default for Translations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -11,6 +11,11 @@ lazy_static! {
         snake_title.insert(Language::Chinese, "贪吃蛇".to_string());
         m.insert("snake.snake_title", snake_title);
 
+        let mut welcome_to = HashMap::new();
+        welcome_to.insert(Language::English, "Welcome to".to_string());
+        welcome_to.insert(Language::Chinese, "欢迎来到".to_string());
+        m.insert("snake.welcome_to", welcome_to);
+
         let mut score = HashMap::new();
         score.insert(Language::English, "Score:".to_string());
         score.insert(Language::Chinese, "分数：".to_string());
@@ -22,8 +27,8 @@ lazy_static! {
         m.insert("snake.game_over", game_over);
 
         let mut press_r_restart = HashMap::new();
-        press_r_restart.insert(Language::English, "Press 'R' to restart".to_string());
-        press_r_restart.insert(Language::Chinese, "按 'R' 键重新开始".to_string());
+        press_r_restart.insert(Language::English, "Press {restart} to restart".to_string());
+        press_r_restart.insert(Language::Chinese, "按 {restart} 键重新开始".to_string());
         m.insert("snake.press_r_restart", press_r_restart);
 
         let mut how_to_play = HashMap::new();
@@ -31,13 +36,22 @@ lazy_static! {
         how_to_play.insert(Language::Chinese, "游戏说明：".to_string());
         m.insert("snake.how_to_play", how_to_play);
 
-        let mut move_snake = HashMap::new();
-        move_snake.insert(
+        let mut description = HashMap::new();
+        description.insert(
             Language::English,
-            "Use arrow keys or WASD to move the snake".to_string(),
+            "Steer the snake to eat food and grow without hitting the walls or yourself."
+                .to_string(),
+        );
+        description.insert(
+            Language::Chinese,
+            "控制蛇吃掉食物并变长，不要撞到墙壁或者自己。".to_string(),
         );
-        move_snake.insert(Language::Chinese, "使用方向键或 WASD 移动蛇".to_string());
-        m.insert("snake.move_snake", move_snake);
+        m.insert("snake.description", description);
+
+        let mut control_turn = HashMap::new();
+        control_turn.insert(Language::English, "Change direction".to_string());
+        control_turn.insert(Language::Chinese, "改变方向".to_string());
+        m.insert("snake.control_turn", control_turn);
 
         let mut eat_food_title = HashMap::new();
         eat_food_title.insert(
@@ -50,17 +64,23 @@ lazy_static! {
         let mut apple_desc = HashMap::new();
         apple_desc.insert(
             Language::English,
-            "- Apple (🍎): 50 points (large)".to_string(),
+            "- Apple ({apple}): 50 points (large)".to_string(),
+        );
+        apple_desc.insert(
+            Language::Chinese,
+            "- 苹果({apple})：50分（大食物）".to_string(),
         );
-        apple_desc.insert(Language::Chinese, "- 苹果(🍎)：50分（大食物）".to_string());
         m.insert("snake.apple_desc", apple_desc);
 
         let mut candy_desc = HashMap::new();
         candy_desc.insert(
             Language::English,
-            "- Candy (🍬): 150 points (small)".to_string(),
+            "- Candy ({candy}): 150 points (small)".to_string(),
+        );
+        candy_desc.insert(
+            Language::Chinese,
+            "- 糖果({candy})：150分（小食物）".to_string(),
         );
-        candy_desc.insert(Language::Chinese, "- 糖果(🍬)：150分（小食物）".to_string());
         m.insert("snake.candy_desc", candy_desc);
 
         let mut avoid_walls = HashMap::new();
@@ -80,8 +100,8 @@ lazy_static! {
         m.insert("snake.press_enter", press_enter);
 
         let mut pause_game = HashMap::new();
-        pause_game.insert(Language::English, "Press P/ESC to pause".to_string());
-        pause_game.insert(Language::Chinese, "按 P/ESC 键暂停游戏".to_string());
+        pause_game.insert(Language::English, "Press {pause} to pause".to_string());
+        pause_game.insert(Language::Chinese, "按 {pause} 键暂停游戏".to_string());
         m.insert("snake.pause_game", pause_game);
 
         m
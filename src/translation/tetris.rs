@@ -21,6 +21,11 @@ lazy_static! {
         game_over.insert(Language::Chinese, "游戏结束！".to_string());
         m.insert("tetris.game_over", game_over);
 
+        let mut press_r_restart = HashMap::new();
+        press_r_restart.insert(Language::English, "Press {restart} to restart".to_string());
+        press_r_restart.insert(Language::Chinese, "按 {restart} 键重新开始".to_string());
+        m.insert("tetris.press_r_restart", press_r_restart);
+
         let mut welcome_to = HashMap::new();
         welcome_to.insert(Language::English, "Welcome to".to_string());
         welcome_to.insert(Language::Chinese, "欢迎来到".to_string());
@@ -31,53 +36,43 @@ lazy_static! {
         how_to_play.insert(Language::Chinese, "游戏说明：".to_string());
         m.insert("tetris.how_to_play", how_to_play);
 
-        let mut move_horizontal = HashMap::new();
-        move_horizontal.insert(
+        let mut description = HashMap::new();
+        description.insert(
             Language::English,
-            "1. Use LEFT/RIGHT Arrow or A/D to move block horizontally".to_string(),
+            "Stack the falling blocks and clear full lines before they reach the top.".to_string(),
         );
-        move_horizontal.insert(
+        description.insert(
             Language::Chinese,
-            "1. 使用左右箭头键或A/D键左右移动方块".to_string(),
+            "堆叠下落的方块，在它们堆到顶部之前消除填满的行。".to_string(),
         );
-        m.insert("tetris.move_horizontal", move_horizontal);
+        m.insert("tetris.description", description);
 
-        let mut speed_up = HashMap::new();
-        speed_up.insert(
-            Language::English,
-            "2. Use DOWN Arrow or S to speed up block descent".to_string(),
-        );
-        speed_up.insert(
-            Language::Chinese,
-            "2. 使用向下箭头键或S键加速方块下降".to_string(),
-        );
-        m.insert("tetris.speed_up", speed_up);
+        let mut control_move = HashMap::new();
+        control_move.insert(Language::English, "Move the block".to_string());
+        control_move.insert(Language::Chinese, "左右移动方块".to_string());
+        m.insert("tetris.control_move", control_move);
 
-        let mut rotate = HashMap::new();
-        rotate.insert(
-            Language::English,
-            "3. Use UP Arrow or W to rotate block".to_string(),
-        );
-        rotate.insert(
-            Language::Chinese,
-            "3. 使用向上箭头键或W键旋转方块".to_string(),
-        );
-        m.insert("tetris.rotate", rotate);
+        let mut control_soft_drop = HashMap::new();
+        control_soft_drop.insert(Language::English, "Speed up the descent".to_string());
+        control_soft_drop.insert(Language::Chinese, "加速方块下降".to_string());
+        m.insert("tetris.control_soft_drop", control_soft_drop);
 
-        let mut hard_drop = HashMap::new();
-        hard_drop.insert(
-            Language::English,
-            "4. Press SPACE to hard drop block".to_string(),
-        );
-        hard_drop.insert(Language::Chinese, "4. 按空格键瞬间放下方块".to_string());
-        m.insert("tetris.hard_drop", hard_drop);
+        let mut control_rotate = HashMap::new();
+        control_rotate.insert(Language::English, "Rotate the block".to_string());
+        control_rotate.insert(Language::Chinese, "旋转方块".to_string());
+        m.insert("tetris.control_rotate", control_rotate);
+
+        let mut control_hard_drop = HashMap::new();
+        control_hard_drop.insert(Language::English, "Drop the block instantly".to_string());
+        control_hard_drop.insert(Language::Chinese, "瞬间放下方块".to_string());
+        m.insert("tetris.control_hard_drop", control_hard_drop);
 
         let mut clear_lines = HashMap::new();
         clear_lines.insert(
             Language::English,
-            "5. Clear lines to score points".to_string(),
+            "Clear lines to score points:".to_string(),
         );
-        clear_lines.insert(Language::Chinese, "5. 清除行以获得分数".to_string());
+        clear_lines.insert(Language::Chinese, "清除行以获得分数：".to_string());
         m.insert("tetris.clear_lines", clear_lines);
 
         let mut one_line = HashMap::new();
@@ -122,29 +117,19 @@ lazy_static! {
         m.insert("tetris.press_enter", press_enter);
 
         let mut pause_game = HashMap::new();
-        pause_game.insert(
-            Language::English,
-            "Press P or ESC to pause Game".to_string(),
-        );
-        pause_game.insert(Language::Chinese, "按P或ESC键暂停游戏".to_string());
+        pause_game.insert(Language::English, "Press {pause} to pause Game".to_string());
+        pause_game.insert(Language::Chinese, "按 {pause} 键暂停游戏".to_string());
         m.insert("tetris.pause_game", pause_game);
 
-        let mut game_paused = HashMap::new();
-        game_paused.insert(Language::English, "Game Paused".to_string());
-        game_paused.insert(Language::Chinese, "游戏暂停".to_string());
-        m.insert("tetris.game_paused", game_paused);
-
-        let mut press_p_to_resume = HashMap::new();
-        press_p_to_resume.insert(Language::English, "Press P or ESC to resume".to_string());
-        press_p_to_resume.insert(Language::Chinese, "按P或ESC键继续".to_string());
-        m.insert("tetris.press_p_to_resume", press_p_to_resume);
-
         let mut restart = HashMap::new();
         restart.insert(
             Language::English,
-            "Press R to restart after game over".to_string(),
+            "Press {restart} to restart after game over".to_string(),
+        );
+        restart.insert(
+            Language::Chinese,
+            "失败后按 {restart} 键重新开始".to_string(),
         );
-        restart.insert(Language::Chinese, "失败后按R键重新开始".to_string());
         m.insert("tetris.restart", restart);
 
         m
@@ -29,9 +29,12 @@ lazy_static! {
         let mut game_over = HashMap::new();
         game_over.insert(
             Language::English,
-            "Game Over! Press 'r' to restart".to_string(),
+            "Game Over! Press {restart} to restart".to_string(),
+        );
+        game_over.insert(
+            Language::Chinese,
+            "游戏结束！按 {restart} 键重新开始".to_string(),
         );
-        game_over.insert(Language::Chinese, "游戏结束！按'r'键重新开始".to_string());
         m.insert("2048.game_over", game_over);
 
         let mut how_to_play = HashMap::new();
@@ -39,13 +42,21 @@ lazy_static! {
         how_to_play.insert(Language::Chinese, "游戏说明：".to_string());
         m.insert("2048.how_to_play", how_to_play);
 
-        let mut move_controls = HashMap::new();
-        move_controls.insert(
+        let mut description = HashMap::new();
+        description.insert(
             Language::English,
-            "Use Arrow keys or WASD to move tiles".to_string(),
+            "Slide the tiles to merge equal numbers and reach 2048.".to_string(),
+        );
+        description.insert(
+            Language::Chinese,
+            "滑动方块合并相同的数字，拼出2048。".to_string(),
         );
-        move_controls.insert(Language::Chinese, "使用方向键或WASD移动方块".to_string());
-        m.insert("2048.move_controls", move_controls);
+        m.insert("2048.description", description);
+
+        let mut control_slide = HashMap::new();
+        control_slide.insert(Language::English, "Slide all tiles".to_string());
+        control_slide.insert(Language::Chinese, "滑动所有方块".to_string());
+        m.insert("2048.control_slide", control_slide);
 
         let mut merge_tip = HashMap::new();
         merge_tip.insert(
@@ -55,24 +66,6 @@ lazy_static! {
         merge_tip.insert(Language::Chinese, "合并相同数字以获得2048！".to_string());
         m.insert("2048.merge_tip", merge_tip);
 
-        let mut pause_control = HashMap::new();
-        pause_control.insert(
-            Language::English,
-            "Press P or ESC to pause game".to_string(),
-        );
-        pause_control.insert(Language::Chinese, "按P或ESC键暂停游戏".to_string());
-        m.insert("2048.pause_control", pause_control);
-
-        let mut game_paused = HashMap::new();
-        game_paused.insert(Language::English, "Game Paused".to_string());
-        game_paused.insert(Language::Chinese, "游戏暂停".to_string());
-        m.insert("2048.game_paused", game_paused);
-
-        let mut resume_tip = HashMap::new();
-        resume_tip.insert(Language::English, "Press P or ESC to resume".to_string());
-        resume_tip.insert(Language::Chinese, "按P或ESC键继续".to_string());
-        m.insert("2048.resume_tip", resume_tip);
-
         m
     };
 }
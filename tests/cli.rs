@@ -0,0 +1,92 @@
+use terminal_games::cli::{self, CliError, Options};
+use terminal_games::game_manager::{CompileLanguage, Screen};
+use terminal_games::translation::Language;
+use terminal_games::{GameManager, GameRegistry};
+
+#[test]
+fn parses_game_and_options() {
+    let options = cli::parse(["tetris", "--lang", "zh", "--compile-lang=go", "-s", "42"]).unwrap();
+    assert_eq!(
+        options,
+        Options {
+            game: Some("tetris".to_string()),
+            language: Some(Language::Chinese),
+            compile_language: Some(CompileLanguage::Go),
+            seed: Some(42),
+            ..Options::default()
+        }
+    );
+    assert_eq!(
+        cli::parse(Vec::<String>::new()).unwrap(),
+        Options::default()
+    );
+    assert!(cli::parse(["--list"]).unwrap().list);
+    assert_eq!(
+        cli::parse(["--boss-title", "cargo build"])
+            .unwrap()
+            .boss_title,
+        Some("cargo build".to_string())
+    );
+}
+
+#[test]
+fn reports_bad_arguments() {
+    assert_eq!(
+        cli::parse(["--lang"]),
+        Err(CliError::MissingValue("--lang".to_string()))
+    );
+    assert_eq!(
+        cli::parse(["--seed", "abc"]),
+        Err(CliError::InvalidValue {
+            option: "--seed".to_string(),
+            value: "abc".to_string()
+        })
+    );
+    assert_eq!(
+        cli::parse(["--fast"]),
+        Err(CliError::UnknownOption("--fast".to_string()))
+    );
+    assert_eq!(
+        cli::parse(["tetris", "snake"]),
+        Err(CliError::UnexpectedArgument("snake".to_string()))
+    );
+}
+
+#[test]
+fn replay_options_exclude_seed_and_game() {
+    let options = cli::parse(["--replay", "run.replay", "--speed=2"]).unwrap();
+    assert_eq!(options.replay.as_deref(), Some("run.replay"));
+    assert_eq!(options.speed, Some(2.0));
+    assert_eq!(
+        cli::parse(["--replay", "run.replay", "--seed", "1"]),
+        Err(CliError::Conflict("--replay", "--seed"))
+    );
+    assert_eq!(
+        cli::parse(["tetris", "--replay", "run.replay"]),
+        Err(CliError::Conflict("--replay", "[GAME]"))
+    );
+    assert_eq!(
+        cli::parse(["--speed", "0"]),
+        Err(CliError::InvalidValue {
+            option: "--speed".to_string(),
+            value: "0".to_string()
+        })
+    );
+}
+
+#[test]
+fn starts_a_registered_game_by_id() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    assert!(manager.start_game("snake"));
+    assert!(manager.state == Screen::Game(2));
+    assert!(!manager.start_game("tetirs"));
+
+    let error = CliError::UnknownGame {
+        name: "tetirs".to_string(),
+        available: manager.game_ids(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "unknown game 'tetirs'\navailable games: goldminer, tetris, snake, 2048, minesweeper"
+    );
+}
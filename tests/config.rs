@@ -0,0 +1,179 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::{env, fs, path::PathBuf, time::Duration};
+use terminal_games::config::{Config, ConfigError};
+use terminal_games::game_manager::{CompileLanguage, Screen};
+use terminal_games::games::snake::Snake;
+use terminal_games::translation::Language;
+use terminal_games::{Difficulty, Game, GameEnv, GameManager, GameRegistry, GameShell};
+use tui::{buffer::Buffer, layout::Rect};
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!(
+        "terminal-games-config-{}-{}",
+        std::process::id(),
+        name
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("config.toml")
+}
+
+fn press(manager: &mut GameManager, code: KeyCode) {
+    manager.handle_input(KeyEvent::from(code));
+}
+
+fn draw(manager: &mut GameManager) -> String {
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    manager.draw(&mut buf, area);
+    buf.content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+#[test]
+fn config_round_trips_through_text() {
+    let mut config = Config::default();
+    assert_eq!(Config::parse(&config.to_text()), Ok(config.clone()));
+
+    config.language = Some(Language::Chinese);
+    config.compile_language = Some(CompileLanguage::CMake);
+    config.set_difficulty("snake", Difficulty::Hard);
+    config.set_difficulty("tetris", Difficulty::Easy);
+    let text = config.to_text();
+    assert!(text.contains("[difficulty]\nsnake = \"hard\"\ntetris = \"easy\"\n"));
+    assert_eq!(Config::parse(&text), Ok(config.clone()));
+    assert_eq!(config.difficulty("minesweeper"), Difficulty::Normal);
+}
+
+#[test]
+fn settings_screen_changes_are_saved() {
+    let path = temp_file("settings");
+    let mut manager = GameManager::with_registry(GameRegistry::builtin())
+        .with_config(Config::load_from(&path).unwrap());
+    manager.set_language(Language::English);
+
+    press(&mut manager, KeyCode::Char('s'));
+    assert!(manager.state == Screen::Settings);
+    press(&mut manager, KeyCode::Down);
+    press(&mut manager, KeyCode::Left);
+    press(&mut manager, KeyCode::Down);
+    press(&mut manager, KeyCode::Down);
+    press(&mut manager, KeyCode::Right);
+    press(&mut manager, KeyCode::Esc);
+    assert!(manager.state == Screen::MainMenu);
+
+    // 只保存在设置界面中改过的项，语言仍然跟随系统
+    let saved = Config::load_from(&path).unwrap();
+    assert_eq!(&saved, manager.config());
+    assert_eq!(saved.language, None);
+    assert_eq!(saved.compile_language, Some(CompileLanguage::CMake));
+    assert_eq!(saved.difficulty("snake"), Difficulty::Hard);
+    assert_eq!(saved.difficulty("tetris"), Difficulty::Normal);
+}
+
+#[test]
+fn missing_config_file_uses_defaults() {
+    let path = temp_file("missing");
+    let config = Config::load_from(&path).unwrap();
+    assert_eq!(config.language, None);
+    assert_eq!(config.difficulty("snake"), Difficulty::Normal);
+}
+
+#[test]
+fn comments_and_blank_lines_are_ignored() {
+    let text = "# my settings\n\nlanguage = \"zh\"  # 中文\n[difficulty]\nsnake = \"easy\"\n";
+    let config = Config::parse(text).unwrap();
+    assert_eq!(config.language, Some(Language::Chinese));
+    assert_eq!(config.difficulty("snake"), Difficulty::Easy);
+}
+
+#[test]
+fn every_error_is_reported_with_its_line() {
+    let text = "language = \"fr\"\ncolour = \"red\"\nnot a setting\n[sound]\nvolume = \"9\"\n[difficulty]\nsnake = \"insane\"\n";
+    let errors = Config::parse(text).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            ConfigError::InvalidValue {
+                line: 1,
+                key: "language".to_string(),
+                value: "fr".to_string(),
+                expected: "en, zh",
+            },
+            ConfigError::UnknownKey {
+                line: 2,
+                key: "colour".to_string(),
+            },
+            ConfigError::Syntax { line: 3 },
+            ConfigError::UnknownSection {
+                line: 4,
+                name: "sound".to_string(),
+            },
+            ConfigError::InvalidValue {
+                line: 7,
+                key: "snake".to_string(),
+                value: "insane".to_string(),
+                expected: "easy, normal, hard",
+            },
+        ]
+    );
+    assert_eq!(
+        errors[0].to_string(),
+        "line 1: invalid value 'fr' for 'language' (expected one of: en, zh)"
+    );
+}
+
+#[test]
+fn unquoted_values_are_syntax_errors() {
+    assert_eq!(
+        Config::parse("language = en\n"),
+        Err(vec![ConfigError::Syntax { line: 1 }])
+    );
+}
+
+#[test]
+fn unknown_games_are_reported_by_the_manager() {
+    let config = Config::parse("[difficulty]\nsnake = \"hard\"\nsnak = \"easy\"\n").unwrap();
+    let manager = GameManager::with_registry(GameRegistry::builtin()).with_config(config);
+    let errors = manager.config_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]
+        .to_string()
+        .starts_with("unknown game 'snak' in [difficulty] (available games: "));
+    assert!(GameManager::with_registry(GameRegistry::builtin())
+        .config_errors()
+        .is_empty());
+}
+
+#[test]
+fn config_errors_are_shown_until_a_key_is_pressed() {
+    let config = Config::parse("[difficulty]\nsnak = \"easy\"\n").unwrap();
+    let mut manager = GameManager::with_registry(GameRegistry::builtin()).with_config(config);
+    manager.set_language(Language::English);
+    assert!(manager.is_showing_config_errors());
+    let screen = draw(&mut manager);
+    assert!(screen.contains("Problems in the config file"));
+    assert!(screen.contains("unknown game 'snak'"));
+
+    // 关闭提示的按键不会选择游戏
+    press(&mut manager, KeyCode::Char('1'));
+    assert!(!manager.is_showing_config_errors());
+    assert!(manager.state == Screen::MainMenu);
+    assert!(!draw(&mut manager).contains("Problems in the config file"));
+}
+
+#[test]
+fn harder_snakes_move_faster() {
+    let moved_after = |difficulty| {
+        let mut shell = GameShell::<Snake>::with_seed(1);
+        shell.set_difficulty(difficulty);
+        shell.handle_input(KeyEvent::from(KeyCode::Enter));
+        let start = shell.game().observe();
+        shell.update(Duration::from_millis(120));
+        shell.game().observe() != start
+    };
+    assert!(!moved_after(Difficulty::Easy));
+    assert!(!moved_after(Difficulty::Normal));
+    assert!(moved_after(Difficulty::Hard));
+}
@@ -0,0 +1,60 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::{env, fs};
+use terminal_games::crash::{CrashContext, CrashReport, RECENT_INPUTS};
+use terminal_games::replay::InputEvent;
+use terminal_games::{GameManager, GameRegistry};
+
+fn key(c: char) -> InputEvent {
+    InputEvent::Key(KeyEvent::from(KeyCode::Char(c)))
+}
+
+#[test]
+fn context_keeps_only_the_most_recent_inputs() {
+    let mut context = CrashContext::new();
+    for _ in 0..RECENT_INPUTS {
+        context.record_input(key('a'));
+    }
+    context.record_input(key('b'));
+    assert_eq!(context.inputs().count(), RECENT_INPUTS);
+    assert_eq!(context.inputs().last(), Some(&key('b')));
+}
+
+#[test]
+fn context_follows_the_active_game_and_seed() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    let mut context = CrashContext::new();
+    context.track(&manager);
+    assert_eq!((context.game, context.seed), (None, None));
+
+    manager.set_seed(42);
+    assert!(manager.start_game("snake"));
+    context.track(&manager);
+    assert_eq!((context.game, context.seed), (Some("snake"), Some(42)));
+}
+
+#[test]
+fn report_lists_everything_needed_to_reproduce_the_crash() {
+    let mut context = CrashContext::new();
+    context.game = Some("tetris");
+    context.seed = Some(7);
+    context.record_input(key('x'));
+    context.record_input(InputEvent::Resize(80, 24));
+    let report = CrashReport::new(
+        "attempt to subtract with overflow".to_string(),
+        Some("src/game_manager.rs:10:5".to_string()),
+        context,
+    );
+
+    let text = report.to_text();
+    assert!(text.starts_with("# terminal-games crash report\n"));
+    assert!(text.contains("message: attempt to subtract with overflow\n"));
+    assert!(text.contains("location: src/game_manager.rs:10:5\n"));
+    assert!(text.contains("game: tetris\nseed: 7\n"));
+    assert!(text.contains("key\tx\t0\nresize\t80\t24\n"));
+    assert!(text.contains("\nbacktrace:\n"));
+
+    let dir = env::temp_dir().join(format!("terminal-games-crash-{}", std::process::id()));
+    let path = report.save(&dir).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), text);
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,157 @@
+use terminal_games::games::minesweeper::{self, Cell, MineSweeper};
+use terminal_games::games::snake::{self, Snake};
+use terminal_games::games::tetris::{self, Tetris};
+use terminal_games::games::twenty_forty_eight::{self, TwentyFortyEight};
+use terminal_games::GameEnv;
+
+const SEED: u64 = 42;
+
+// 每一步按顺序轮流选择合法动作，返回每一步的状态和累计奖励
+fn play<E: GameEnv>(env: &mut E, seed: u64, steps: usize) -> (Vec<E::Observation>, f64) {
+    let mut observations = vec![env.reset(seed)];
+    let mut total = 0.0;
+    for i in 0..steps {
+        let actions = env.legal_actions();
+        if actions.is_empty() {
+            assert!(env.is_done());
+            break;
+        }
+        let (observation, reward, done) = env.step(actions[i % actions.len()]);
+        assert_eq!(done, env.is_done());
+        observations.push(observation);
+        total += reward;
+    }
+    (observations, total)
+}
+
+#[test]
+fn same_seed_gives_the_same_episode() {
+    let first = play(&mut Tetris::new(), SEED, 300);
+    assert_eq!(play(&mut Tetris::new(), SEED, 300), first);
+    let first = play(&mut Snake::new(), SEED, 100);
+    assert_eq!(play(&mut Snake::new(), SEED, 100), first);
+    let first = play(&mut TwentyFortyEight::new(), SEED, 300);
+    assert_eq!(play(&mut TwentyFortyEight::new(), SEED, 300), first);
+    let first = play(&mut MineSweeper::new(), SEED, 50);
+    assert_eq!(play(&mut MineSweeper::new(), SEED, 50), first);
+}
+
+#[test]
+fn twenty_forty_eight_rewards_add_up_to_the_score() {
+    let mut env = TwentyFortyEight::new();
+    let start = env.reset(SEED);
+    let tiles = start.board.iter().flatten().filter(|&&n| n != 0).count();
+    assert_eq!((tiles, start.score), (2, 0));
+
+    let (observations, total) = play(&mut env, SEED, 2000);
+    let last = observations.last().unwrap();
+    assert_eq!(total, last.score as f64);
+    assert!(env.is_done());
+    assert!(env.legal_actions().is_empty());
+
+    // 结束之后的动作不会改变游戏
+    let (after, reward, done) = env.step(twenty_forty_eight::Direction::Left);
+    assert_eq!((&after, reward, done), (last, 0.0, true));
+}
+
+#[test]
+fn illegal_twenty_forty_eight_moves_change_nothing() {
+    let mut env = TwentyFortyEight::new();
+    let start = env.reset(SEED);
+    let all = [
+        twenty_forty_eight::Direction::Up,
+        twenty_forty_eight::Direction::Down,
+        twenty_forty_eight::Direction::Left,
+        twenty_forty_eight::Direction::Right,
+    ];
+    let legal = env.legal_actions();
+    for direction in all.into_iter().filter(|d| !legal.contains(d)) {
+        assert_eq!(env.step(direction), (start.clone(), 0.0, false));
+    }
+}
+
+#[test]
+fn snake_cannot_turn_back_and_dies_at_the_wall() {
+    let mut env = Snake::new();
+    let start = env.reset(SEED);
+    assert_eq!(start.direction, snake::Direction::Right);
+    assert!(!env.legal_actions().contains(&snake::Direction::Left));
+
+    // 掉头的动作不改变游戏，奖励为 0
+    assert_eq!(
+        env.step(snake::Direction::Left),
+        (start.clone(), 0.0, false)
+    );
+    assert_eq!(env.observe(), start);
+
+    let (observation, _, _) = env.step(snake::Direction::Right);
+    assert_eq!(observation.body[0].0, start.body[0].0 + 1);
+
+    let mut done = false;
+    for _ in 0..20 {
+        done = env.step(snake::Direction::Right).2;
+        if done {
+            break;
+        }
+    }
+    assert!(done);
+    assert!(env.legal_actions().is_empty());
+}
+
+#[test]
+fn tetris_hard_drops_until_the_board_fills() {
+    let mut env = Tetris::new();
+    let start = env.reset(SEED);
+    assert_eq!(start.piece.len(), 4);
+    assert!(start.board.iter().flatten().all(|&cell| !cell));
+    assert!(env.legal_actions().contains(&tetris::Action::HardDrop));
+
+    let mut steps = 0;
+    while !env.step(tetris::Action::HardDrop).2 {
+        steps += 1;
+        assert!(steps < 200);
+    }
+    assert!(env.legal_actions().is_empty());
+    assert_eq!(env.observe().score, 0);
+}
+
+#[test]
+fn minesweeper_reveal_and_flag() {
+    let mut env = MineSweeper::new();
+    let start = env.reset(SEED);
+    assert!(start
+        .cells
+        .iter()
+        .flatten()
+        .all(|&cell| cell == Cell::Hidden));
+    assert_eq!(env.legal_actions().len(), 200);
+
+    let (observation, reward, _) = env.step(minesweeper::Action::Flag(0, 0));
+    assert_eq!(observation.cells[0][0], Cell::Flagged);
+    assert_eq!(reward, 0.0);
+    assert!(!env
+        .legal_actions()
+        .contains(&minesweeper::Action::Reveal(0, 0)));
+    env.step(minesweeper::Action::Flag(0, 0));
+
+    // 依次揭开格子，直到踩到地雷或者赢下这一局
+    let mut total = 0.0;
+    for (x, y) in (0..10).flat_map(|y| (0..10).map(move |x| (x, y))) {
+        let (observation, reward, done) = env.step(minesweeper::Action::Reveal(x, y));
+        if done && !observation.is_win {
+            assert_eq!(reward, -1.0);
+            assert_eq!(observation.cells[y][x], Cell::Mine);
+            break;
+        }
+        total += reward;
+    }
+    assert!(env.is_done());
+    let opened = env
+        .observe()
+        .cells
+        .iter()
+        .flatten()
+        .filter(|cell| matches!(cell, Cell::Open(_)))
+        .count();
+    assert_eq!(total, opened as f64);
+}
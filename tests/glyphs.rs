@@ -0,0 +1,122 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::collections::HashMap;
+use terminal_games::cli::{self, CliError};
+use terminal_games::config::Config;
+use terminal_games::games::{minesweeper::MineSweeper, snake::Snake, tetris::Tetris};
+use terminal_games::glyphs::{GlyphSet, Glyphs};
+use terminal_games::translation::Language;
+use terminal_games::{GameShell, ShellGame};
+use tui::{buffer::Buffer, layout::Rect};
+use unicode_width::UnicodeWidthStr;
+
+fn detect(vars: &[(&str, &str)]) -> GlyphSet {
+    let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+    GlyphSet::detect_from(|name| vars.get(name).map(|value| value.to_string()))
+}
+
+// 开始一局并绘制一帧
+fn render_playing<G: ShellGame>(set: GlyphSet) -> Buffer {
+    let mut shell = GameShell::<G>::with_seed(1);
+    shell.set_glyphs(&Glyphs::new(set));
+    shell.handle_input(KeyEvent::from(KeyCode::Enter));
+    let area = Rect::new(0, 0, 120, 32);
+    let mut buf = Buffer::empty(area);
+    shell.render(&mut buf, area);
+    buf
+}
+
+fn symbols(buf: &Buffer) -> String {
+    buf.content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+#[test]
+fn glyph_set_is_guessed_from_the_environment() {
+    let utf8 = ("LANG", "en_US.UTF-8");
+    assert_eq!(detect(&[utf8, ("TERM", "xterm-256color")]), GlyphSet::Emoji);
+    assert_eq!(
+        detect(&[utf8, ("SSH_TTY", "/dev/pts/3")]),
+        GlyphSet::Unicode
+    );
+    assert_eq!(detect(&[utf8, ("TERM", "linux")]), GlyphSet::Unicode);
+    assert_eq!(detect(&[utf8, ("TERM", "dumb")]), GlyphSet::Ascii);
+    assert_eq!(detect(&[("LANG", "C")]), GlyphSet::Ascii);
+    // LC_ALL 优先于 LANG
+    assert_eq!(
+        detect(&[("LC_ALL", "POSIX"), ("LANG", "zh_CN.utf8")]),
+        GlyphSet::Ascii
+    );
+}
+
+#[test]
+fn every_glyph_set_keeps_the_board_width() {
+    for set in GlyphSet::ALL {
+        let glyphs = Glyphs::new(set);
+        for glyph in [glyphs.block, glyphs.empty, glyphs.apple, glyphs.candy] {
+            assert_eq!(glyph.width(), 2, "{:?} {:?}", set, glyph);
+        }
+        for glyph in [glyphs.mine, glyphs.flag, glyphs.hidden] {
+            assert_eq!(glyph.width(), 4, "{:?} {:?}", set, glyph);
+        }
+    }
+}
+
+#[test]
+fn ascii_boards_draw_only_ascii() {
+    let tetris = symbols(&render_playing::<Tetris>(GlyphSet::Ascii));
+    assert!(tetris.contains("[]"));
+    assert!(!tetris.contains('█'));
+
+    let snake = symbols(&render_playing::<Snake>(GlyphSet::Ascii));
+    assert!(snake.contains("()") || snake.contains("<>"));
+    assert!(!snake.contains('·'));
+
+    let mut shell = GameShell::<MineSweeper>::with_seed(1);
+    shell.set_glyphs(&Glyphs::new(GlyphSet::Ascii));
+    shell.handle_input(KeyEvent::from(KeyCode::Enter));
+    shell.handle_input(KeyEvent::from(KeyCode::Char('f')));
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    shell.render(&mut buf, area);
+    let minesweeper = symbols(&buf);
+    assert!(minesweeper.contains('F') && minesweeper.contains('#'));
+    assert!(!minesweeper.contains('🚩') && !minesweeper.contains('■'));
+}
+
+#[test]
+fn help_text_shows_the_current_glyphs() {
+    let mut shell = GameShell::<Snake>::with_seed(1);
+    shell.set_language(Language::English);
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    shell.render(&mut buf, area);
+    // emoji 占两列，缓冲区中它后面还有一个空的格子
+    assert!(symbols(&buf).contains("Apple (🍎"));
+
+    shell.set_glyphs(&Glyphs::new(GlyphSet::Ascii));
+    let mut buf = Buffer::empty(area);
+    shell.render(&mut buf, area);
+    assert!(symbols(&buf).contains("Apple (())"));
+}
+
+#[test]
+fn glyphs_can_be_chosen_in_the_config_and_on_the_command_line() {
+    let config = Config::parse("glyphs = \"ascii\"\n").unwrap();
+    assert_eq!(config.glyphs, Some(GlyphSet::Ascii));
+    assert!(config.to_text().contains("glyphs = \"ascii\"\n"));
+    assert!(Config::parse("glyphs = \"wingdings\"\n").is_err());
+
+    assert_eq!(
+        cli::parse(["--glyphs", "unicode"]).unwrap().glyphs,
+        Some(GlyphSet::Unicode)
+    );
+    assert_eq!(
+        cli::parse(["-g", "ansi"]),
+        Err(CliError::InvalidValue {
+            option: "-g".to_string(),
+            value: "ansi".to_string(),
+        })
+    );
+}
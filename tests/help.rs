@@ -0,0 +1,150 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::Duration;
+use terminal_games::config::Config;
+use terminal_games::games::{
+    goldminer::GoldMiner, metadata::Input, minesweeper::MineSweeper, snake::Snake, tetris::Tetris,
+    twenty_forty_eight::TwentyFortyEight,
+};
+use terminal_games::keymap::Action;
+use terminal_games::translation::{Language, Translations, TETRIS_TRANSLATIONS};
+use terminal_games::{GameDescriptor, GameManager, GameRegistry, GameShell, ShellGame};
+use tui::{buffer::Buffer, layout::Rect};
+
+fn press(manager: &mut GameManager, code: KeyCode) {
+    manager.handle_input(KeyEvent::from(code));
+}
+
+fn draw(manager: &mut GameManager) -> String {
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    manager.draw(&mut buf, area);
+    buf.content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+fn manager() -> GameManager {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    manager.set_seed(1);
+    manager
+}
+
+// 外壳的操作（暂停和重新开始）加上游戏自己的操作都要出现在帮助中
+fn assert_documents_all_actions<G: ShellGame>() {
+    let shell = GameShell::<G>::with_seed(1);
+    let documented: Vec<_> = shell
+        .metadata()
+        .controls
+        .iter()
+        .flat_map(|control| match &control.input {
+            Input::Actions(actions) => actions.clone(),
+            Input::Other(_) => Vec::new(),
+        })
+        .collect();
+    for action in shell.actions() {
+        assert!(
+            documented.contains(&action),
+            "{:?} is not documented",
+            action
+        );
+    }
+}
+
+#[test]
+fn metadata_documents_every_action() {
+    assert_documents_all_actions::<GoldMiner>();
+    assert_documents_all_actions::<Tetris>();
+    assert_documents_all_actions::<Snake>();
+    assert_documents_all_actions::<TwentyFortyEight>();
+    assert_documents_all_actions::<MineSweeper>();
+}
+
+#[test]
+fn help_overlay_freezes_the_game_until_closed() {
+    let mut manager = manager();
+    assert!(manager.start_game("tetris"));
+    press(&mut manager, KeyCode::Enter);
+    let before = draw(&mut manager);
+
+    manager.handle_input(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT));
+    assert!(manager.is_showing_help());
+    let screen = draw(&mut manager);
+    assert!(screen.contains("Help: Tetris"));
+    assert!(screen.contains("Space: Drop the block instantly"));
+    assert!(screen.contains("P/Esc: Pause menu"));
+
+    // 帮助打开时其他按键和时间都不会交给游戏
+    press(&mut manager, KeyCode::Left);
+    for _ in 0..200 {
+        manager.update(Duration::from_millis(16));
+    }
+    press(&mut manager, KeyCode::Esc);
+    assert!(!manager.is_showing_help());
+    assert_eq!(draw(&mut manager), before);
+}
+
+#[test]
+fn help_follows_language_and_key_bindings() {
+    let mut config = Config::default();
+    config.language = Some(Language::Chinese);
+    config.set_keys(Action::MoveUp, vec![KeyCode::Char('i')]);
+    let mut manager = GameManager::with_registry(GameRegistry::builtin()).with_config(config);
+    assert!(manager.start_game("snake"));
+    press(&mut manager, KeyCode::Char('?'));
+    // 中文字符占两列，第二列是空格
+    let screen = draw(&mut manager).replace(' ', "");
+    assert!(screen.contains("I↓/S←/A→/D：改变方向"));
+    assert!(screen.contains("全局按键"));
+
+    press(&mut manager, KeyCode::Char('?'));
+    assert!(!manager.is_showing_help());
+}
+
+#[test]
+fn menu_shows_help_for_the_selected_game() {
+    let mut manager = manager();
+    press(&mut manager, KeyCode::Down);
+    press(&mut manager, KeyCode::Char('?'));
+    assert!(draw(&mut manager).contains("Help: Tetris"));
+
+    // 帮助打开时 q 不会退出程序
+    press(&mut manager, KeyCode::Char('q'));
+    assert!(!manager.should_quit());
+    press(&mut manager, KeyCode::Esc);
+    assert!(!draw(&mut manager).contains("Help: Tetris"));
+}
+
+#[test]
+fn menu_help_does_not_create_the_game() {
+    let mut registry = GameRegistry::new();
+    registry.register(
+        GameDescriptor::new(
+            "tetris",
+            "tetris.tetris_title",
+            &TETRIS_TRANSLATIONS,
+            |_| panic!("the menu help must not create the game"),
+        )
+        .with_help(GameShell::<Tetris>::describe),
+    );
+    let mut manager = GameManager::with_registry(registry);
+    manager.set_language(Language::English);
+    press(&mut manager, KeyCode::Char('?'));
+    let screen = draw(&mut manager);
+    assert!(screen.contains("Help: Tetris"));
+    assert!(screen.contains("Drop the block instantly"));
+}
+
+#[test]
+fn descriptor_help_matches_the_game() {
+    let mut translations = Translations::new();
+    translations.set_language(Language::English);
+    for id in ["goldminer", "tetris", "snake", "2048", "minesweeper"] {
+        let registry = GameRegistry::builtin();
+        let descriptor = registry.find(id).unwrap();
+        let mut game = descriptor.create(1);
+        game.set_language(Language::English);
+        assert_eq!(descriptor.help(&translations), game.metadata(), "{}", id);
+    }
+}
@@ -0,0 +1,82 @@
+use crossterm::event::{Event, KeyCode, KeyEvent};
+use std::{io, time::Duration};
+use terminal_games::games::snake::{Direction, Snake};
+use terminal_games::input::InputThread;
+use terminal_games::replay::InputEvent;
+use terminal_games::{GameEnv, GameShell};
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::from(code))
+}
+
+// 依次读出 `events`，读完后报告错误，让输入线程结束
+fn script(events: Vec<Event>) -> InputThread {
+    let mut events = events.into_iter();
+    InputThread::spawn_with(move || {
+        events
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "no more input"))
+    })
+}
+
+#[test]
+fn read_errors_are_reported_after_the_pending_events() {
+    let mut input = script(vec![
+        key(KeyCode::Left),
+        key(KeyCode::Left),
+        key(KeyCode::Up),
+        Event::Resize(100, 40),
+    ]);
+    // 等输入线程读完所有按键
+    std::thread::sleep(Duration::from_millis(50));
+    let events = input.wait(Duration::from_secs(1)).unwrap();
+    assert_eq!(events.len(), 4);
+    assert_eq!(events[3].event, InputEvent::Resize(100, 40));
+
+    let err = input.wait(Duration::from_secs(1)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn fast_key_sequences_arrive_together_and_in_order() {
+    let (sender, receiver) = std::sync::mpsc::channel::<Event>();
+    let mut input = InputThread::spawn_with(move || {
+        receiver
+            .recv()
+            .map_err(|_| io::Error::new(io::ErrorKind::UnexpectedEof, "closed"))
+    });
+    for code in [KeyCode::Left, KeyCode::Left, KeyCode::Up] {
+        sender.send(key(code)).unwrap();
+    }
+    std::thread::sleep(Duration::from_millis(50));
+
+    let events = input.wait(Duration::from_secs(1)).unwrap();
+    let inputs: Vec<InputEvent> = events.iter().map(|timed| timed.event).collect();
+    assert_eq!(
+        inputs,
+        [KeyCode::Left, KeyCode::Left, KeyCode::Up].map(|code| InputEvent::from(key(code)))
+    );
+    assert!(events.windows(2).all(|pair| pair[0].at <= pair[1].at));
+
+    // 没有新的输入时等到超时，返回空列表
+    assert!(input.wait(Duration::from_millis(10)).unwrap().is_empty());
+}
+
+// 同一格内连续转两次弯，两个转向都会在之后的两格里执行
+#[test]
+fn snake_keeps_quick_turns_within_one_step() {
+    let mut shell = GameShell::<Snake>::with_seed(1);
+    shell.handle_input(KeyEvent::from(KeyCode::Enter));
+    let start = shell.game().observe().body[0];
+
+    // 向右走时先上后左：左和向右相反，但和排在前面的向上不冲突
+    shell.handle_input(KeyEvent::from(KeyCode::Up));
+    shell.handle_input(KeyEvent::from(KeyCode::Left));
+    shell.update(Duration::from_millis(160));
+    assert_eq!(shell.game().observe().direction, Direction::Up);
+    assert_eq!(shell.game().observe().body[0], (start.0, start.1 - 1));
+
+    shell.update(Duration::from_millis(160));
+    assert_eq!(shell.game().observe().direction, Direction::Left);
+    assert_eq!(shell.game().observe().body[0], (start.0 - 1, start.1 - 1));
+}
@@ -0,0 +1,134 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use terminal_games::{
+    game_manager::Screen,
+    translation::{Language, TranslationTable},
+    Game, GameDescriptor, GameManager, GameRegistry,
+};
+use tui::{buffer::Buffer, layout::Rect};
+
+lazy_static::lazy_static! {
+    static ref NO_TRANSLATIONS: TranslationTable = TranslationTable::new();
+}
+
+// 一个总是在接收文字输入的游戏，把收到的按键记录下来
+struct TextGame {
+    received: Rc<RefCell<Vec<KeyEvent>>>,
+}
+
+impl Game for TextGame {
+    fn new() -> Self {
+        Self {
+            received: Rc::default(),
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        self.received.borrow_mut().push(key);
+    }
+
+    fn captures_text_input(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, _dt: Duration) {}
+    fn set_language(&mut self, _language: Language) {}
+    fn render(&mut self, _buf: &mut Buffer, _area: Rect) {}
+}
+
+fn manager_with_text_game() -> (GameManager, Rc<RefCell<Vec<KeyEvent>>>) {
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let shared = received.clone();
+    let mut registry = GameRegistry::new();
+    registry.register(GameDescriptor::new(
+        "text",
+        "text.title",
+        &NO_TRANSLATIONS,
+        move |_seed| {
+            Box::new(TextGame {
+                received: shared.clone(),
+            })
+        },
+    ));
+    (GameManager::with_registry(registry), received)
+}
+
+#[test]
+fn q_returns_to_menu_from_regular_games() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.handle_input(KeyCode::Enter.into());
+    assert!(manager.state == Screen::Game(0));
+
+    manager.handle_input(KeyCode::Char('q').into());
+    assert!(manager.state == Screen::MainMenu);
+    assert!(!manager.should_quit());
+
+    manager.handle_input(KeyCode::Char('q').into());
+    assert!(manager.should_quit());
+}
+
+#[test]
+fn text_input_games_receive_q_and_modifiers() {
+    let (mut manager, received) = manager_with_text_game();
+    manager.handle_input(KeyCode::Enter.into());
+
+    let shift_a = KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT);
+    manager.handle_input(KeyCode::Char('q').into());
+    manager.handle_input(shift_a);
+    assert!(manager.state == Screen::Game(0));
+    assert_eq!(*received.borrow(), vec![KeyCode::Char('q').into(), shift_a]);
+
+    manager.handle_input(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+    assert!(manager.state == Screen::MainMenu);
+    assert_eq!(received.borrow().len(), 2);
+}
+
+#[test]
+fn digits_without_a_game_are_ignored_in_the_menu() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.handle_input(KeyCode::Char('0').into());
+    manager.handle_input(KeyCode::Char('9').into());
+    assert!(manager.state == Screen::MainMenu);
+
+    manager.handle_input(KeyCode::Char('2').into());
+    assert!(manager.state == Screen::Game(1));
+}
+
+#[test]
+fn an_empty_registry_does_not_panic() {
+    let mut manager = GameManager::with_registry(GameRegistry::new());
+    for code in [
+        KeyCode::Down,
+        KeyCode::Up,
+        KeyCode::Enter,
+        KeyCode::Char('1'),
+    ] {
+        manager.handle_input(code.into());
+    }
+    manager.handle_mouse(MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: 0,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+    });
+    assert!(manager.state == Screen::MainMenu);
+
+    // 按键设置是设置界面的最后一行，没有游戏时页面是空的
+    manager.handle_input(KeyCode::Char('s').into());
+    for _ in 0..10 {
+        manager.handle_input(KeyCode::Down.into());
+    }
+    for code in [
+        KeyCode::Enter,
+        KeyCode::Down,
+        KeyCode::Enter,
+        KeyCode::Backspace,
+    ] {
+        manager.handle_input(code.into());
+    }
+    let area = Rect::new(0, 0, 80, 32);
+    manager.draw(&mut Buffer::empty(area), area);
+    manager.handle_input(KeyCode::Esc.into());
+    manager.handle_input(KeyCode::Esc.into());
+    assert!(manager.state == Screen::MainMenu);
+}
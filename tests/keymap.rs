@@ -0,0 +1,249 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::{env, fs, path::PathBuf};
+use terminal_games::config::{Config, ConfigError};
+use terminal_games::games::{
+    minesweeper::{Cell, MineSweeper},
+    shell::{GameShell, GameState, ShellGame},
+    snake::Snake,
+};
+use terminal_games::keymap::{self, Action, Keymap};
+use terminal_games::translation::{Language, Translations};
+use terminal_games::{GameEnv, GameManager, GameRegistry};
+
+fn press<G: ShellGame>(shell: &mut GameShell<G>, code: KeyCode) {
+    shell.handle_input(KeyEvent::from(code));
+}
+
+fn press_manager(manager: &mut GameManager, code: KeyCode) {
+    manager.handle_input(KeyEvent::from(code));
+}
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!(
+        "terminal-games-keymap-{}-{}",
+        std::process::id(),
+        name
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("config.toml")
+}
+
+fn revealed(shell: &GameShell<MineSweeper>) -> usize {
+    let cells = shell.game().observe().cells;
+    cells
+        .iter()
+        .flatten()
+        .filter(|cell| matches!(cell, Cell::Open(_)))
+        .count()
+}
+
+#[test]
+fn remapped_keys_replace_the_defaults() {
+    let mut keymap = Keymap::new();
+    keymap.bind(Action::Reveal, vec![KeyCode::Char('x')]);
+    keymap.bind(Action::Pause, vec![KeyCode::Char('k')]);
+
+    let mut shell = GameShell::<MineSweeper>::with_seed(3);
+    shell.set_keymap(&keymap);
+    press(&mut shell, KeyCode::Enter);
+
+    press(&mut shell, KeyCode::Char(' '));
+    assert_eq!(revealed(&shell), 0);
+    press(&mut shell, KeyCode::Char('x'));
+    assert!(revealed(&shell) > 0);
+
+    press(&mut shell, KeyCode::Char('p'));
+    assert_eq!(shell.state(), GameState::Playing);
+    press(&mut shell, KeyCode::Char('k'));
+    assert_eq!(shell.state(), GameState::Paused);
+    // 暂停键同时关闭暂停菜单
+    press(&mut shell, KeyCode::Char('k'));
+    assert_eq!(shell.state(), GameState::Playing);
+}
+
+#[test]
+fn modified_keys_do_not_trigger_actions() {
+    let keymap = Keymap::new();
+    let ctrl_p = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
+    assert_eq!(keymap.action(ctrl_p, &[Action::Pause]), None);
+    assert_eq!(
+        keymap.action(KeyEvent::from(KeyCode::Esc), &[Action::Pause]),
+        Some(Action::Pause)
+    );
+    // 只在给定的操作中查找
+    assert_eq!(
+        keymap.action(KeyEvent::from(KeyCode::Up), &[Action::Rotate]),
+        Some(Action::Rotate)
+    );
+    assert_eq!(
+        keymap.action(KeyEvent::from(KeyCode::Up), &[Action::MoveLeft]),
+        None
+    );
+}
+
+#[test]
+fn help_text_follows_the_bindings() {
+    let mut shell = GameShell::<Snake>::with_seed(1);
+    shell.set_language(Language::English);
+    let mut translations = Translations::new();
+    translations.set_language(Language::English);
+    assert_eq!(
+        shell.game().metadata().control_lines(&translations),
+        vec!["↑/W ↓/S ←/A →/D: Change direction"]
+    );
+
+    let mut keymap = Keymap::new();
+    keymap.bind(Action::MoveUp, vec![KeyCode::Char('i')]);
+    keymap.bind(Action::MoveDown, vec![KeyCode::Char('k')]);
+    keymap.bind(Action::MoveLeft, vec![KeyCode::Char('j')]);
+    keymap.bind(Action::MoveRight, vec![]);
+    translations.set_keymap(&keymap);
+    assert_eq!(
+        shell.game().metadata().control_lines(&translations),
+        vec!["I K J -: Change direction"]
+    );
+}
+
+#[test]
+fn key_names_round_trip() {
+    for code in [
+        KeyCode::Char('a'),
+        KeyCode::Char(' '),
+        KeyCode::Left,
+        KeyCode::Esc,
+        KeyCode::F(5),
+        KeyCode::PageDown,
+    ] {
+        assert_eq!(
+            keymap::parse_key_code(&keymap::format_key_code(code)),
+            Some(code)
+        );
+    }
+    assert_eq!(keymap::parse_key_code("hyper"), None);
+    assert_eq!(keymap::key_name(KeyCode::Char(' ')), "Space");
+    assert_eq!(keymap::key_name(KeyCode::Char('f')), "F");
+}
+
+#[test]
+fn keys_are_read_from_the_config_file() {
+    let text = "[keys]\nrotate = \"x, up\"\nflag = \"\"\n";
+    let config = Config::parse(text).unwrap();
+    let keymap = config.keymap();
+    assert_eq!(
+        keymap.keys(Action::Rotate),
+        &[KeyCode::Char('x'), KeyCode::Up]
+    );
+    assert!(keymap.keys(Action::Flag).is_empty());
+    assert_eq!(keymap.keys(Action::HardDrop), &[KeyCode::Char(' ')]);
+    assert_eq!(Config::parse(&config.to_text()), Ok(config.clone()));
+    assert!(config
+        .to_text()
+        .contains("[keys]\nrotate = \"x, up\"\nflag = \"\"\n"));
+}
+
+#[test]
+fn invalid_key_settings_are_reported() {
+    let text = "[keys]\njump = \"space\"\nrotate = \"hyper\"\npause = \"q\"\n";
+    assert_eq!(
+        Config::parse(text),
+        Err(vec![
+            ConfigError::UnknownKey {
+                line: 2,
+                key: "jump".to_string(),
+            },
+            ConfigError::InvalidKey {
+                line: 3,
+                action: "rotate".to_string(),
+                key: "hyper".to_string(),
+            },
+            ConfigError::ReservedKey {
+                line: 4,
+                key: "q".to_string(),
+            },
+        ])
+    );
+
+    // 同一个游戏里的冲突由 GameManager 检查，不同游戏可以共用一个键
+    let config = Config::parse("[keys]\nrotate = \"space\"\nreveal = \"x\"\n").unwrap();
+    let manager = GameManager::with_registry(GameRegistry::builtin()).with_config(config);
+    assert_eq!(
+        manager.config_errors(),
+        &[ConfigError::KeyConflict {
+            key: "space".to_string(),
+            first: "rotate",
+            second: "hard_drop",
+        }]
+    );
+    assert_eq!(
+        manager.config_errors()[0].to_string(),
+        "key 'space' is bound to both 'rotate' and 'hard_drop' in [keys]"
+    );
+}
+
+#[test]
+fn settings_screen_rebinds_keys() {
+    let path = temp_file("settings");
+    let mut manager = GameManager::with_registry(GameRegistry::builtin())
+        .with_config(Config::load_from(&path).unwrap());
+
+    // 按键设置是设置界面的最后一行
+    press_manager(&mut manager, KeyCode::Char('s'));
+    for _ in 0..10 {
+        press_manager(&mut manager, KeyCode::Down);
+    }
+    press_manager(&mut manager, KeyCode::Enter);
+    // 第五个操作是旋转
+    for _ in 0..4 {
+        press_manager(&mut manager, KeyCode::Down);
+    }
+    press_manager(&mut manager, KeyCode::Enter);
+    // 保留的键被忽略，`b` 也不会打开老板键界面
+    press_manager(&mut manager, KeyCode::Char('b'));
+    press_manager(&mut manager, KeyCode::Char('q'));
+    assert!(!manager.is_hiding());
+    press_manager(&mut manager, KeyCode::Char(' '));
+
+    let keymap = manager.keymap();
+    assert_eq!(keymap.keys(Action::Rotate), &[KeyCode::Char(' ')]);
+    // 同一个游戏里的直接落下让出空格，其他游戏不受影响
+    assert!(keymap.keys(Action::HardDrop).is_empty());
+    assert_eq!(keymap.keys(Action::Reveal), &[KeyCode::Char(' ')]);
+    assert_eq!(Config::load_from(&path).unwrap().keymap(), *keymap);
+
+    // 退格恢复默认按键
+    press_manager(&mut manager, KeyCode::Backspace);
+    assert_eq!(
+        manager.keymap().keys(Action::Rotate),
+        Action::Rotate.default_keys()
+    );
+}
+
+#[test]
+fn captured_separator_keys_survive_the_config_file() {
+    for (index, code) in [KeyCode::Char(','), KeyCode::Char('"')]
+        .into_iter()
+        .enumerate()
+    {
+        let path = temp_file(&format!("separator-{}", index));
+        let mut manager = GameManager::with_registry(GameRegistry::builtin())
+            .with_config(Config::load_from(&path).unwrap());
+        press_manager(&mut manager, KeyCode::Char('s'));
+        for _ in 0..10 {
+            press_manager(&mut manager, KeyCode::Down);
+        }
+        press_manager(&mut manager, KeyCode::Enter);
+        for _ in 0..4 {
+            press_manager(&mut manager, KeyCode::Down);
+        }
+        press_manager(&mut manager, KeyCode::Enter);
+        press_manager(&mut manager, code);
+        assert_eq!(manager.keymap().keys(Action::Rotate), &[code]);
+
+        let parsed = Config::parse(&manager.config().to_text()).unwrap();
+        assert_eq!(parsed.keymap().keys(Action::Rotate), &[code]);
+        assert_eq!(
+            Config::load_from(&path).unwrap().keymap(),
+            *manager.keymap()
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use terminal_games::{
+    game_manager::Screen,
+    keymap::Action,
+    translation::{Language, TranslationTable},
+    Game, GameDescriptor, GameManager, GameRegistry, GameRequest,
+};
+use tui::{buffer::Buffer, layout::Rect};
+
+lazy_static::lazy_static! {
+    static ref NO_TRANSLATIONS: TranslationTable = TranslationTable::new();
+}
+
+// 把创建和生命周期回调记录下来的游戏，按 `s` 请求打开设置界面
+struct HookGame {
+    log: Rc<RefCell<Vec<&'static str>>>,
+    request: Option<GameRequest>,
+}
+
+impl Game for HookGame {
+    fn new() -> Self {
+        Self {
+            log: Rc::default(),
+            request: None,
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Char('s') {
+            self.request = Some(GameRequest::OpenSettings);
+        }
+    }
+
+    fn take_request(&mut self) -> Option<GameRequest> {
+        self.request.take()
+    }
+
+    fn set_language(&mut self, _language: Language) {}
+    fn render(&mut self, _buf: &mut Buffer, _area: Rect) {}
+
+    fn update(&mut self, _dt: Duration) {
+        self.log.borrow_mut().push("update");
+    }
+
+    fn on_enter(&mut self) {
+        self.log.borrow_mut().push("enter");
+    }
+
+    fn on_exit(&mut self) {
+        self.log.borrow_mut().push("exit");
+    }
+
+    fn on_suspend(&mut self) {
+        self.log.borrow_mut().push("suspend");
+    }
+
+    fn on_resume(&mut self) {
+        self.log.borrow_mut().push("resume");
+    }
+}
+
+fn manager_with_hook_game() -> (GameManager, Rc<RefCell<Vec<&'static str>>>) {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let shared = log.clone();
+    let mut registry = GameRegistry::new();
+    registry.register(GameDescriptor::new(
+        "hooks",
+        "hooks.title",
+        &NO_TRANSLATIONS,
+        move |_seed| {
+            shared.borrow_mut().push("create");
+            Box::new(HookGame {
+                log: shared.clone(),
+                request: None,
+            })
+        },
+    ));
+    (GameManager::with_registry(registry), log)
+}
+
+fn press(manager: &mut GameManager, code: KeyCode) {
+    manager.handle_input(KeyEvent::from(code));
+}
+
+fn draw(manager: &mut GameManager) -> String {
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    manager.draw(&mut buf, area);
+    buf.content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+#[test]
+fn games_are_created_on_first_entry() {
+    let (mut manager, log) = manager_with_hook_game();
+    manager.set_language(Language::Chinese);
+    manager.handle_resize(80, 32);
+    draw(&mut manager);
+    assert!(log.borrow().is_empty());
+
+    press(&mut manager, KeyCode::Enter);
+    press(&mut manager, KeyCode::Char('q'));
+    press(&mut manager, KeyCode::Enter);
+    assert_eq!(*log.borrow(), ["create", "enter", "exit", "enter"]);
+}
+
+#[test]
+fn covered_games_get_no_updates() {
+    let (mut manager, log) = manager_with_hook_game();
+    assert!(manager.start_game("hooks"));
+    press(&mut manager, KeyCode::Char('b'));
+    manager.update(Duration::from_millis(16));
+    press(&mut manager, KeyCode::Char('b'));
+    manager.update(Duration::from_millis(16));
+    press(&mut manager, KeyCode::Char('q'));
+    assert!(manager.state == Screen::MainMenu);
+    assert_eq!(
+        *log.borrow(),
+        ["create", "enter", "suspend", "resume", "update", "exit"]
+    );
+}
+
+#[test]
+fn help_and_settings_suspend_the_game() {
+    let (mut manager, log) = manager_with_hook_game();
+    assert!(manager.start_game("hooks"));
+    press(&mut manager, KeyCode::Char('?'));
+    // 帮助盖着游戏时按老板键，游戏不会再收到一次 on_suspend
+    press(&mut manager, KeyCode::Char('b'));
+    press(&mut manager, KeyCode::Char('b'));
+    press(&mut manager, KeyCode::Esc);
+    press(&mut manager, KeyCode::Char('s'));
+    assert!(manager.state == Screen::Settings);
+    press(&mut manager, KeyCode::Esc);
+    assert_eq!(
+        *log.borrow(),
+        ["create", "enter", "suspend", "resume", "suspend", "resume"]
+    );
+
+    // 在帮助中直接返回主菜单时只收到 on_exit
+    log.borrow_mut().clear();
+    press(&mut manager, KeyCode::Char('?'));
+    press(&mut manager, KeyCode::Char('q'));
+    assert!(manager.state == Screen::MainMenu);
+    assert_eq!(*log.borrow(), ["suspend", "exit"]);
+}
+
+#[test]
+fn settings_and_rebinding_do_not_create_games() {
+    let created = Rc::new(RefCell::new(0));
+    let mut registry = GameRegistry::builtin();
+    for id in registry.iter().map(|d| d.id).collect::<Vec<_>>() {
+        let descriptor = registry.find(id).unwrap();
+        let (title_key, translations, actions) = (
+            descriptor.title_key,
+            descriptor.translations,
+            descriptor.actions.clone(),
+        );
+        let counter = created.clone();
+        registry.register(
+            GameDescriptor::new(id, title_key, translations, move |_seed| {
+                *counter.borrow_mut() += 1;
+                Box::new(HookGame::new())
+            })
+            .with_actions(actions),
+        );
+    }
+    let mut manager = GameManager::with_registry(registry);
+
+    // 打开按键设置，把旋转改成空格，与直接落下冲突
+    press(&mut manager, KeyCode::Char('s'));
+    for _ in 0..10 {
+        press(&mut manager, KeyCode::Down);
+    }
+    press(&mut manager, KeyCode::Enter);
+    for _ in 0..4 {
+        press(&mut manager, KeyCode::Down);
+    }
+    press(&mut manager, KeyCode::Enter);
+    press(&mut manager, KeyCode::Char(' '));
+    draw(&mut manager);
+
+    assert_eq!(manager.keymap().keys(Action::Rotate), &[KeyCode::Char(' ')]);
+    assert!(manager.keymap().keys(Action::HardDrop).is_empty());
+    assert_eq!(*created.borrow(), 0);
+}
+
+#[test]
+fn leaving_a_game_pauses_it() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    manager.set_seed(1);
+    assert!(manager.start_game("goldminer"));
+    press(&mut manager, KeyCode::Enter);
+    press(&mut manager, KeyCode::Char('q'));
+    for _ in 0..100 {
+        manager.update(Duration::from_millis(16));
+    }
+
+    press(&mut manager, KeyCode::Char('1'));
+    let screen = draw(&mut manager);
+    assert!(screen.contains("Paused") && screen.contains("Resume"));
+}
@@ -0,0 +1,55 @@
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use terminal_games::{
+    games::minesweeper::MineSweeper, translation::Language, GameManager, GameRegistry, GameShell,
+};
+use tui::{backend::TestBackend, buffer::Buffer, layout::Rect, Terminal};
+
+fn click(button: MouseButton, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind: MouseEventKind::Down(button),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn row_text(buffer: &Buffer, row: u16) -> String {
+    (0..buffer.area.width)
+        .map(|x| buffer.get(x, row).symbol.clone())
+        .collect()
+}
+
+#[test]
+fn minesweeper_right_click_flags_cell() {
+    let area = Rect::new(0, 0, 80, 32);
+    let mut game = GameShell::<MineSweeper>::new();
+    game.handle_input(KeyCode::Enter.into());
+
+    // 80 列宽时棋盘从第 15 列开始，左上角格子占第 16-19 列、第 2 行
+    game.handle_mouse(click(MouseButton::Right, 17, 2), area);
+
+    let mut buffer = Buffer::empty(area);
+    game.render(&mut buffer, area);
+    assert!(row_text(&buffer, 2).starts_with("│              │ 🚩"));
+
+    // 点在分隔线上不会有任何效果
+    game.handle_mouse(click(MouseButton::Right, 20, 2), area);
+    let mut buffer = Buffer::empty(area);
+    game.render(&mut buffer, area);
+    assert_eq!(row_text(&buffer, 2).matches('🚩').count(), 1);
+}
+
+#[test]
+fn main_menu_click_starts_game() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    let mut terminal = Terminal::new(TestBackend::new(80, 32)).unwrap();
+    terminal.draw(|f| manager.render(f, f.size())).unwrap();
+
+    // 第二个游戏（俄罗斯方块）位于边框内第 6 行
+    manager.handle_mouse(click(MouseButton::Left, 40, 6));
+    terminal.draw(|f| manager.render(f, f.size())).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    assert!(row_text(buffer, 1).contains("Welcome to Tetris!"));
+}
@@ -0,0 +1,286 @@
+//! 把每个游戏渲染到 `TestBackend`，并与 `tests/snapshots/` 下的快照比较。
+//!
+//! 设置 `UPDATE_SNAPSHOTS=1` 重新生成快照文件，没有设置时缺少快照文件会失败。
+
+use crossterm::event::KeyCode;
+use std::{env, fs, path::PathBuf, time::Duration};
+use terminal_games::{
+    games::{
+        goldminer::GoldMiner, minesweeper::MineSweeper, snake::Snake, tetris::Tetris,
+        twenty_forty_eight::TwentyFortyEight,
+    },
+    translation::Language,
+    Game, GameManager, GameRegistry, GameShell,
+};
+use tui::{backend::TestBackend, buffer::Buffer, Terminal};
+use unicode_width::UnicodeWidthStr;
+
+const WIDTH: u16 = 80;
+const HEIGHT: u16 = 32;
+
+fn buffer_to_string(buffer: &Buffer) -> String {
+    let mut view = String::new();
+    for cells in buffer.content.chunks(buffer.area.width as usize) {
+        let mut skip = 0;
+        let mut line = String::new();
+        for cell in cells {
+            if skip == 0 {
+                line.push_str(&cell.symbol);
+            }
+            skip = std::cmp::max(skip, cell.symbol.width()).saturating_sub(1);
+        }
+        view.push_str(line.trim_end());
+        view.push('\n');
+    }
+    view
+}
+
+fn render_game(game: &mut dyn Game) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal
+        .draw(|f| {
+            let area = f.size();
+            f.render_widget(GameView(game), area);
+        })
+        .unwrap();
+    buffer_to_string(terminal.backend().buffer())
+}
+
+struct GameView<'a>(&'a mut dyn Game);
+
+impl tui::widgets::Widget for GameView<'_> {
+    fn render(self, area: tui::layout::Rect, buf: &mut Buffer) {
+        self.0.render(buf, area);
+    }
+}
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "snapshots"]
+        .iter()
+        .collect::<PathBuf>()
+        .join(format!("{}.txt", name));
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "snapshot `{}` is missing, rerun with UPDATE_SNAPSHOTS=1 to create it",
+            name
+        )
+    });
+    assert_eq!(
+        expected, actual,
+        "snapshot `{}` changed, rerun with UPDATE_SNAPSHOTS=1 to accept",
+        name
+    );
+}
+
+fn english<G: Game>(mut game: G) -> G {
+    game.set_language(Language::English);
+    game
+}
+
+fn press(game: &mut dyn Game, keys: &[KeyCode]) {
+    for &key in keys {
+        game.handle_input(key.into());
+    }
+}
+
+#[test]
+fn main_menu() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal.draw(|f| manager.render(f, f.size())).unwrap();
+    assert_snapshot("main_menu", &buffer_to_string(terminal.backend().buffer()));
+}
+
+#[test]
+fn settings_screen() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    manager.handle_input(KeyCode::Char('s').into());
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal.draw(|f| manager.render(f, f.size())).unwrap();
+    assert_snapshot("settings", &buffer_to_string(terminal.backend().buffer()));
+}
+
+#[test]
+fn key_bindings_screen() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    manager.handle_input(KeyCode::Char('s').into());
+    for _ in 0..10 {
+        manager.handle_input(KeyCode::Down.into());
+    }
+    manager.handle_input(KeyCode::Enter.into());
+    manager.handle_input(KeyCode::Down.into());
+    manager.handle_input(KeyCode::Enter.into());
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal.draw(|f| manager.render(f, f.size())).unwrap();
+    assert_snapshot(
+        "key_bindings",
+        &buffer_to_string(terminal.backend().buffer()),
+    );
+}
+
+#[test]
+fn welcome_screens() {
+    assert_snapshot(
+        "goldminer_welcome",
+        &render_game(&mut english(GameShell::<GoldMiner>::new())),
+    );
+    assert_snapshot(
+        "tetris_welcome",
+        &render_game(&mut english(GameShell::<Tetris>::new())),
+    );
+    assert_snapshot(
+        "snake_welcome",
+        &render_game(&mut english(GameShell::<Snake>::new())),
+    );
+    assert_snapshot(
+        "2048_welcome",
+        &render_game(&mut english(GameShell::<TwentyFortyEight>::new())),
+    );
+    assert_snapshot(
+        "minesweeper_welcome",
+        &render_game(&mut english(GameShell::<MineSweeper>::new())),
+    );
+}
+
+#[test]
+fn paused_screens() {
+    let games: Vec<(&str, Box<dyn Game>)> = vec![
+        (
+            "goldminer_paused",
+            Box::new(english(GameShell::<GoldMiner>::with_seed(SEED))),
+        ),
+        (
+            "tetris_paused",
+            Box::new(english(GameShell::<Tetris>::with_seed(SEED))),
+        ),
+        (
+            "snake_paused",
+            Box::new(english(GameShell::<Snake>::with_seed(SEED))),
+        ),
+        (
+            "2048_paused",
+            Box::new(english(GameShell::<TwentyFortyEight>::with_seed(SEED))),
+        ),
+        (
+            "minesweeper_paused",
+            Box::new(english(GameShell::<MineSweeper>::with_seed(SEED))),
+        ),
+    ];
+    for (name, mut game) in games {
+        press(game.as_mut(), &[KeyCode::Enter, KeyCode::Char('p')]);
+        assert_snapshot(name, &render_game(game.as_mut()));
+    }
+}
+
+#[test]
+fn boss_key_screen() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.handle_input(KeyCode::Char('b').into());
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal.draw(|f| manager.render(f, f.size())).unwrap();
+    assert_snapshot("compiling", &buffer_to_string(terminal.backend().buffer()));
+}
+
+// 固定随机数种子，棋盘在每次运行中都相同
+const SEED: u64 = 42;
+
+#[test]
+fn playing_screens() {
+    let mut tetris = english(GameShell::<Tetris>::with_seed(SEED));
+    press(&mut tetris, &[KeyCode::Enter, KeyCode::Left, KeyCode::Up]);
+    assert_snapshot("tetris_playing", &render_game(&mut tetris));
+
+    let mut snake = english(GameShell::<Snake>::with_seed(SEED));
+    press(&mut snake, &[KeyCode::Enter]);
+    assert_snapshot("snake_playing", &render_game(&mut snake));
+
+    let mut twenty_forty_eight = english(GameShell::<TwentyFortyEight>::with_seed(SEED));
+    press(
+        &mut twenty_forty_eight,
+        &[KeyCode::Enter, KeyCode::Left, KeyCode::Up],
+    );
+    assert_snapshot("2048_playing", &render_game(&mut twenty_forty_eight));
+
+    let mut minesweeper = english(GameShell::<MineSweeper>::with_seed(SEED));
+    press(&mut minesweeper, &[KeyCode::Enter, KeyCode::Char(' ')]);
+    assert_snapshot("minesweeper_playing", &render_game(&mut minesweeper));
+
+    // 黄金矿工按终端大小布局，先告诉它快照的大小
+    let mut goldminer = english(GameShell::<GoldMiner>::with_seed(SEED));
+    goldminer.resize(WIDTH, HEIGHT);
+    press(&mut goldminer, &[KeyCode::Enter]);
+    assert_snapshot("goldminer_playing", &render_game(&mut goldminer));
+}
+
+#[test]
+fn game_over_screens() {
+    // 所有方块都在同一列附近生成，不断硬降必然堆满
+    let mut tetris = english(GameShell::<Tetris>::with_seed(SEED));
+    press(&mut tetris, &[KeyCode::Enter]);
+    press(&mut tetris, &[KeyCode::Char(' '); 100]);
+    assert_snapshot("tetris_game_over", &render_game(&mut tetris));
+
+    // 一直向右走会撞墙
+    let mut snake = english(GameShell::<Snake>::with_seed(SEED));
+    press(&mut snake, &[KeyCode::Enter]);
+    for _ in 0..200 {
+        snake.update(Duration::from_millis(100));
+    }
+    assert_snapshot("snake_game_over", &render_game(&mut snake));
+
+    let mut twenty_forty_eight = english(GameShell::<TwentyFortyEight>::with_seed(SEED));
+    press(&mut twenty_forty_eight, &[KeyCode::Enter]);
+    let moves = [KeyCode::Up, KeyCode::Right, KeyCode::Down, KeyCode::Left];
+    for key in moves.iter().cycle().take(20_000) {
+        twenty_forty_eight.handle_input((*key).into());
+    }
+    assert_snapshot("2048_game_over", &render_game(&mut twenty_forty_eight));
+
+    // 逐个翻开所有格子，最终一定踩雷或获胜
+    let mut minesweeper = english(GameShell::<MineSweeper>::with_seed(SEED));
+    press(&mut minesweeper, &[KeyCode::Enter]);
+    for _ in 0..10 {
+        for _ in 0..10 {
+            press(&mut minesweeper, &[KeyCode::Char(' '), KeyCode::Right]);
+        }
+        press(&mut minesweeper, &[KeyCode::Down]);
+        press(&mut minesweeper, &[KeyCode::Left; 10]);
+    }
+    assert_snapshot("minesweeper_game_over", &render_game(&mut minesweeper));
+
+    // 黄金矿工没有结束画面：没有时间限制，也不会失败，抓完金子就进入下一关，
+    // 所以只有欢迎、进行中和暂停三个快照
+}
+
+#[test]
+fn same_seed_replays_the_same_game() {
+    let play = |seed: u64| {
+        let mut manager = GameManager::with_registry(GameRegistry::builtin());
+        manager.set_language(Language::English);
+        manager.set_seed(seed);
+        manager.start_game("tetris");
+        for key in [
+            KeyCode::Enter,
+            KeyCode::Char(' '),
+            KeyCode::Left,
+            KeyCode::Char(' '),
+        ] {
+            manager.handle_input(key.into());
+        }
+        let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+        terminal.draw(|f| manager.render(f, f.size())).unwrap();
+        buffer_to_string(terminal.backend().buffer())
+    };
+
+    assert_eq!(play(7), play(7));
+    assert_ne!(play(7), play(8));
+}
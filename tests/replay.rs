@@ -0,0 +1,201 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use std::time::Duration;
+use terminal_games::config::Config;
+use terminal_games::replay::{InputEvent, Player, Recorder, Recording, ReplayError};
+use terminal_games::{GameManager, GameRegistry};
+use tui::{buffer::Buffer, layout::Rect};
+
+const SEED: u64 = 7;
+const TICK: Duration = Duration::from_millis(16);
+
+fn manager() -> GameManager {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_seed(SEED);
+    manager
+}
+
+fn draw(manager: &mut GameManager) -> Buffer {
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    manager.draw(&mut buf, area);
+    buf
+}
+
+fn key(code: KeyCode) -> InputEvent {
+    InputEvent::Key(KeyEvent::from(code))
+}
+
+// 模拟主循环录制一段 Tetris：每隔一段时间按一个键
+fn record_session(manager: &mut GameManager) -> Recording {
+    let script = [
+        (0, key(KeyCode::Char('1'))),
+        (5, key(KeyCode::Enter)),
+        (40, key(KeyCode::Left)),
+        (80, key(KeyCode::Up)),
+        (120, key(KeyCode::Char(' '))),
+        (200, key(KeyCode::Right)),
+    ];
+    record_script(manager, Config::default(), &script)
+}
+
+fn record_script(
+    manager: &mut GameManager,
+    settings: Config,
+    script: &[(u64, InputEvent)],
+) -> Recording {
+    let mut recording = Recording::new(SEED, TICK, 80, 32);
+    recording.settings = settings;
+    let mut recorder = Recorder::new(recording);
+    for tick in 0..300 {
+        for (_, event) in script.iter().filter(|(at, _)| *at == tick) {
+            recorder.record(*event);
+            if let InputEvent::Key(key) = event {
+                manager.handle_input(*key);
+            }
+        }
+        manager.update(TICK);
+        recorder.advance();
+    }
+    recorder.finish()
+}
+
+#[test]
+fn recording_round_trips_through_text() {
+    let mut recording = Recording::new(SEED, TICK, 100, 40);
+    recording.game = Some("snake".to_string());
+    recording.end = 12;
+    recording.settings = Config::parse(
+        "glyphs = \"ascii\"\ntheme = \"monochrome\"\n\n[difficulty]\nsnake = \"hard\"\n\n[keys]\nrotate = \"x, w\"\n",
+    )
+    .unwrap();
+    recording.events = vec![
+        (0, key(KeyCode::Char(' '))),
+        (1, key(KeyCode::Char('x'))),
+        (
+            2,
+            InputEvent::Key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+        ),
+        (3, key(KeyCode::F(5))),
+        (4, key(KeyCode::Esc)),
+        (
+            5,
+            InputEvent::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: 10,
+                row: 4,
+                modifiers: KeyModifiers::NONE,
+            }),
+        ),
+        (
+            6,
+            InputEvent::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollUp,
+                column: 1,
+                row: 2,
+                modifiers: KeyModifiers::SHIFT,
+            }),
+        ),
+        (9, InputEvent::Resize(120, 50)),
+    ];
+
+    let parsed = Recording::parse(&recording.to_text()).unwrap();
+    assert_eq!(parsed, recording);
+    assert_eq!(parsed.duration(), TICK * 12);
+}
+
+#[test]
+fn version_one_recordings_use_default_settings() {
+    let text = "# terminal-games replay v1\nseed=1\ntick_ms=16\nsize=80x24\nend=1\n0\tkey\t1\t0\n";
+    let recording = Recording::parse(text).unwrap();
+    assert_eq!(recording.settings, Config::default());
+    assert_eq!(recording.events.len(), 1);
+}
+
+#[test]
+fn replay_uses_the_recorded_settings() {
+    let settings = Config::parse("[keys]\nmove_left = \"a\"\nhard_drop = \"s\"\n").unwrap();
+    let script = [
+        (0, key(KeyCode::Char('1'))),
+        (5, key(KeyCode::Enter)),
+        (40, key(KeyCode::Char('a'))),
+        (60, key(KeyCode::Char('a'))),
+        (120, key(KeyCode::Char('s'))),
+    ];
+    let mut recorded = manager().with_config(settings.clone());
+    let recording = record_script(&mut recorded, settings, &script);
+    let expected = draw(&mut recorded);
+
+    let recording = Recording::parse(&recording.to_text()).unwrap();
+    let mut replayed = manager().with_config(recording.settings.clone());
+    let mut player = Player::new(recording.clone());
+    player.advance(Duration::from_secs(60), &mut replayed);
+    assert_eq!(draw(&mut replayed), expected);
+
+    // 按默认按键回放时 `a` 和 `s` 不起作用，结果不同
+    let mut default_keys = manager();
+    let mut player = Player::new(recording);
+    player.advance(Duration::from_secs(60), &mut default_keys);
+    assert_ne!(draw(&mut default_keys), expected);
+}
+
+#[test]
+fn invalid_recordings_are_rejected() {
+    assert!(matches!(Recording::parse(""), Err(ReplayError::Header)));
+    assert!(matches!(
+        Recording::parse("# terminal-games replay v99\nseed=1\n"),
+        Err(ReplayError::Header)
+    ));
+    assert!(matches!(
+        Recording::parse("# terminal-games replay v1\nseed=1\ntick_ms=16\nend=1\n"),
+        Err(ReplayError::MissingField("size"))
+    ));
+    let bad_event =
+        "# terminal-games replay v1\nseed=1\ntick_ms=16\nsize=80x24\nend=1\n0\tkey\tnope\t0\n";
+    assert!(matches!(
+        Recording::parse(bad_event),
+        Err(ReplayError::Line(6))
+    ));
+}
+
+#[test]
+fn replay_reproduces_the_recorded_session() {
+    let mut recorded = manager();
+    let recording = record_session(&mut recorded);
+    let expected = draw(&mut recorded);
+
+    let recording = Recording::parse(&recording.to_text()).unwrap();
+    let mut replayed = manager();
+    let mut player = Player::new(recording);
+    player.advance(Duration::from_secs(60), &mut replayed);
+    assert!(player.is_finished());
+    assert_eq!(draw(&mut replayed), expected);
+}
+
+#[test]
+fn speed_pause_and_step_control_playback() {
+    let recording = record_session(&mut manager());
+    let mut replayed = manager();
+    let mut player = Player::new(recording);
+
+    player.set_speed(3.5);
+    assert_eq!(player.speed(), 4.0);
+    assert!(player.handle_input(KeyEvent::from(KeyCode::Char('-')), &mut replayed));
+    assert_eq!(player.speed(), 2.0);
+
+    // 两倍速下 1.6 秒真实时间正好回放 200 个 tick
+    player.advance(Duration::from_millis(1600), &mut replayed);
+    assert!(!player.is_finished());
+
+    player.handle_input(KeyEvent::from(KeyCode::Char(' ')), &mut replayed);
+    assert!(player.is_paused());
+    let paused = draw(&mut replayed);
+    player.advance(Duration::from_secs(60), &mut replayed);
+    assert_eq!(draw(&mut replayed), paused);
+
+    // 暂停时单步，逐个 tick 推进直到结束
+    for _ in 0..100 {
+        player.handle_input(KeyEvent::from(KeyCode::Char('.')), &mut replayed);
+    }
+    assert!(player.is_finished());
+    assert!(!player.handle_input(KeyEvent::from(KeyCode::Char('q')), &mut replayed));
+}
@@ -0,0 +1,122 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::time::Duration;
+use terminal_games::games::goldminer::GoldMiner;
+use terminal_games::games::shell::GameState;
+use terminal_games::translation::Language;
+use terminal_games::{GameManager, GameRegistry, GameShell};
+use tui::{buffer::Buffer, layout::Rect};
+
+fn press(manager: &mut GameManager, code: KeyCode) {
+    manager.handle_input(KeyEvent::from(code));
+}
+
+fn draw(manager: &mut GameManager, width: u16, height: u16) -> String {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    manager.draw(&mut buf, area);
+    buf.content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+// 进入俄罗斯方块并开始一局
+fn playing_tetris() -> GameManager {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    manager.set_seed(1);
+    manager.handle_resize(80, 32);
+    assert!(manager.start_game("tetris"));
+    press(&mut manager, KeyCode::Enter);
+    manager
+}
+
+#[test]
+fn small_terminal_shows_the_resize_screen() {
+    let mut manager = playing_tetris();
+    manager.handle_resize(20, 10);
+    let screen = draw(&mut manager, 20, 10);
+    assert!(screen.contains("Terminal too small"));
+    assert!(screen.contains("Needed: 24x24"));
+    assert!(screen.contains("Current: 20x10"));
+
+    manager.handle_resize(80, 32);
+    assert!(!draw(&mut manager, 80, 32).contains("Terminal too small"));
+}
+
+#[test]
+fn game_is_paused_while_the_terminal_is_too_small() {
+    let mut manager = playing_tetris();
+    manager.handle_resize(20, 10);
+    manager.update(Duration::from_millis(16));
+    // 窗口太小时按键不会交给游戏，放大后停在暂停菜单
+    press(&mut manager, KeyCode::Esc);
+    manager.handle_resize(80, 32);
+    let screen = draw(&mut manager, 80, 32);
+    assert!(screen.contains("Paused") && screen.contains("Resume"));
+
+    // q 在窗口太小时仍然可以返回菜单
+    manager.handle_resize(20, 10);
+    press(&mut manager, KeyCode::Char('q'));
+    assert!(!draw(&mut manager, 20, 10).contains("Terminal too small"));
+}
+
+#[test]
+fn gold_miner_keeps_items_inside_a_smaller_field() {
+    let mut shell = GameShell::<GoldMiner>::with_seed(3);
+    shell.game_mut().resize(200, 60);
+    shell.set_seed(3);
+    shell.handle_input(KeyEvent::from(KeyCode::Enter));
+    shell.game_mut().resize(40, 20);
+
+    let game = shell.game();
+    assert_eq!(game.window_width, 40.0);
+    assert_eq!(game.hook_x, 20.0);
+    assert!(!game.items.is_empty());
+    for item in &game.items {
+        assert!(item.x + item.size < 40.0 && item.y + item.size < 20.0);
+    }
+    assert_eq!(shell.state(), GameState::Playing);
+}
+
+#[test]
+fn gold_miner_fills_the_field_after_starting_too_small() {
+    let mut shell = GameShell::<GoldMiner>::with_seed(3);
+    shell.game_mut().resize(10, 5);
+    shell.set_seed(3);
+    assert!(shell.game().items.is_empty());
+
+    shell.game_mut().resize(80, 24);
+    assert!(!shell.game().items.is_empty());
+}
+
+// 物品布局只由种子和最后收到的大小决定，与创建游戏时的终端大小无关，回放才能得到同样的关卡
+#[test]
+fn gold_miner_layout_follows_the_last_size_before_catching() {
+    let layout = |sizes: &[(u16, u16)]| {
+        let mut shell = GameShell::<GoldMiner>::with_seed(5);
+        for &(width, height) in sizes {
+            shell.game_mut().resize(width, height);
+        }
+        let items: Vec<String> = shell.game().items.iter().map(|i| i.to_string()).collect();
+        items
+    };
+    assert_eq!(
+        layout(&[(120, 40)]),
+        layout(&[(80, 24), (60, 30), (120, 40)])
+    );
+    assert_ne!(layout(&[(120, 40)]), layout(&[(80, 24)]));
+
+    let screen = |resize_first: bool| {
+        let mut manager = GameManager::with_registry(GameRegistry::builtin());
+        manager.set_seed(7);
+        if resize_first {
+            manager.handle_resize(120, 40);
+        }
+        assert!(manager.start_game("goldminer"));
+        manager.handle_resize(120, 40);
+        press(&mut manager, KeyCode::Enter);
+        draw(&mut manager, 120, 40)
+    };
+    assert_eq!(screen(true), screen(false));
+}
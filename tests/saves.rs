@@ -0,0 +1,185 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::{env, fs, path::PathBuf};
+use terminal_games::games::{minesweeper::MineSweeper, snake::Snake};
+use terminal_games::keymap::Action;
+use terminal_games::saves::{SaveData, SaveStore, SAVE_VERSION};
+use terminal_games::{GameManager, GameRegistry, ShellGame};
+use tui::{buffer::Buffer, layout::Rect};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!(
+        "terminal-games-saves-{}-{}",
+        std::process::id(),
+        name
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn manager(dir: &PathBuf) -> GameManager {
+    GameManager::with_registry(GameRegistry::builtin()).with_save_store(SaveStore::in_dir(dir))
+}
+
+fn press(manager: &mut GameManager, code: KeyCode) {
+    manager.handle_input(KeyEvent::from(code));
+}
+
+fn draw(manager: &mut GameManager) -> Buffer {
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    manager.draw(&mut buf, area);
+    buf
+}
+
+fn screen_text(buf: &Buffer) -> String {
+    buf.content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+#[test]
+fn save_data_round_trip() {
+    let mut data = SaveData::new();
+    data.set("score", 42);
+    data.set_list("items", ["a", "b"]);
+    data.set_grid("board", &[vec![1, 2], vec![3, 4]]);
+
+    let parsed = SaveData::parse(&data.to_text()).unwrap();
+    assert_eq!(parsed, data);
+    assert_eq!(parsed.version(), SAVE_VERSION);
+    assert_eq!(parsed.get::<u32>("score"), Some(42));
+    assert_eq!(parsed.get_list::<String>("items").unwrap(), vec!["a", "b"]);
+    assert_eq!(
+        parsed.get_grid::<u32>("board", 2, 2),
+        Some(vec![vec![1, 2], vec![3, 4]])
+    );
+    assert_eq!(parsed.get_grid::<u32>("board", 4, 4), None);
+    assert_eq!(parsed.get::<u32>("missing"), None);
+}
+
+#[test]
+fn unknown_or_newer_saves_are_rejected() {
+    assert!(SaveData::parse("").is_none());
+    assert!(SaveData::parse("not a save\nscore=1\n").is_none());
+    assert!(SaveData::parse("# terminal-games save v1\nno separator\n").is_none());
+    let newer = format!("# terminal-games save v{}\nscore=1\n", SAVE_VERSION + 1);
+    assert!(SaveData::parse(&newer).is_none());
+}
+
+// 把当前格式的存档改写成旧版本：换掉文件头，并对每一行做 `change`
+fn downgrade(data: &SaveData, version: u32, change: impl Fn(&str) -> Option<String>) -> SaveData {
+    let text = data.to_text();
+    let mut lines = text.lines();
+    let header = lines
+        .next()
+        .unwrap()
+        .replace(&format!("v{}", SAVE_VERSION), &format!("v{}", version));
+    let body: Vec<String> = lines.filter_map(change).collect();
+    SaveData::parse(&format!("{}\n{}\n", header, body.join("\n"))).unwrap()
+}
+
+#[test]
+fn older_save_versions_still_load() {
+    // v1 的贪吃蛇存档用 next_direction 记录唯一一个待执行的转向
+    let mut snake = Snake::with_seed(1);
+    snake.handle_action(Action::MoveUp);
+    let current = ShellGame::save_state(&snake).unwrap();
+    let v1 = downgrade(&current, 1, |line| {
+        Some(line.replace("turns=up", "next_direction=up"))
+    });
+    let mut loaded = Snake::with_seed(2);
+    assert!(ShellGame::load_state(&mut loaded, &v1));
+    assert_eq!(ShellGame::save_state(&loaded), Some(current));
+
+    // v3 之前的扫雷存档没有记录难度，按地雷数推断
+    let minesweeper = MineSweeper::with_seed(1);
+    let current = ShellGame::save_state(&minesweeper).unwrap();
+    let v2 = downgrade(&current, 2, |line| {
+        (!line.starts_with("difficulty=")).then(|| line.to_string())
+    });
+    let mut loaded = MineSweeper::with_seed(2);
+    assert!(ShellGame::load_state(&mut loaded, &v2));
+    assert_eq!(ShellGame::save_state(&loaded), Some(current.clone()));
+
+    // 当前版本的存档缺少字段时不能载入
+    let incomplete = downgrade(&current, SAVE_VERSION, |line| {
+        (!line.starts_with("difficulty=")).then(|| line.to_string())
+    });
+    assert!(!ShellGame::load_state(&mut loaded, &incomplete));
+}
+
+#[test]
+fn resume_restores_an_in_progress_game() {
+    let dir = temp_dir("resume");
+
+    let mut first = manager(&dir);
+    press(&mut first, KeyCode::Char('4'));
+    press(&mut first, KeyCode::Enter);
+    press(&mut first, KeyCode::Left);
+    press(&mut first, KeyCode::Up);
+    let before = draw(&mut first);
+    first.save_games().unwrap();
+    assert!(dir.join("2048.save").exists());
+
+    let mut second = manager(&dir);
+    press(&mut second, KeyCode::Char('4'));
+    assert!(screen_text(&draw(&mut second)).contains("Press C to continue your saved game"));
+    press(&mut second, KeyCode::Char('c'));
+    assert_eq!(draw(&mut second), before);
+
+    // 没开始的游戏不会留下存档
+    assert!(!dir.join("tetris.save").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn starting_a_new_game_ignores_the_save() {
+    let dir = temp_dir("new-game");
+
+    let mut first = manager(&dir);
+    press(&mut first, KeyCode::Char('4'));
+    press(&mut first, KeyCode::Enter);
+    press(&mut first, KeyCode::Left);
+    first.save_games().unwrap();
+
+    let mut second = manager(&dir);
+    press(&mut second, KeyCode::Char('4'));
+    press(&mut second, KeyCode::Enter);
+    second.save_games().unwrap();
+
+    // 新的一局只有开局的两个方块
+    let text = fs::read_to_string(dir.join("2048.save")).unwrap();
+    let data = SaveData::parse(&text).unwrap();
+    let board = data.get_grid::<u32>("board", 4, 4).unwrap();
+    assert_eq!(board.iter().flatten().filter(|&&n| n != 0).count(), 2);
+    assert_eq!(data.get::<u32>("score"), Some(0));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn corrupt_saves_are_set_aside() {
+    let dir = temp_dir("corrupt");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("2048.save"), "garbage").unwrap();
+    fs::write(
+        dir.join("tetris.save"),
+        "# terminal-games save v1\nboard=1,2,3\n",
+    )
+    .unwrap();
+
+    // 存档在第一次进入游戏时才载入
+    let mut manager = manager(&dir);
+    assert!(dir.join("2048.save").exists());
+
+    press(&mut manager, KeyCode::Char('4'));
+    assert!(dir.join("2048.bad").exists());
+    assert!(!dir.join("2048.save").exists());
+    assert!(!screen_text(&draw(&mut manager)).contains("saved game"));
+
+    press(&mut manager, KeyCode::Char('q'));
+    press(&mut manager, KeyCode::Char('2'));
+    assert!(dir.join("tetris.bad").exists());
+    assert!(!dir.join("tetris.save").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,158 @@
+use std::{env, fs, path::PathBuf};
+use terminal_games::scores::{self, HighScores, ScoreDetail, ScoreEntry, MAX_ENTRIES};
+use terminal_games::theme::Theme;
+use terminal_games::translation::{Language, Translations};
+
+fn entry(name: &str, score: i64, timestamp: u64) -> ScoreEntry {
+    ScoreEntry {
+        name: name.to_string(),
+        score,
+        timestamp,
+        detail: ScoreDetail::None,
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("terminal-games-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+#[test]
+fn record_keeps_table_sorted_and_bounded() {
+    let mut table = HighScores::default();
+    assert_eq!(table.record("tetris", entry("a", 100, 1)), Some(1));
+    assert_eq!(table.record("tetris", entry("b", 300, 2)), Some(1));
+    assert_eq!(table.record("tetris", entry("c", 200, 3)), Some(2));
+
+    let scores: Vec<i64> = table.top("tetris").iter().map(|e| e.score).collect();
+    assert_eq!(scores, vec![300, 200, 100]);
+    assert!(table.top("snake").is_empty());
+
+    for i in 0..MAX_ENTRIES as u64 {
+        table.record("tetris", entry("d", 1000 + i as i64, 10 + i));
+    }
+    assert_eq!(table.top("tetris").len(), MAX_ENTRIES);
+    assert_eq!(table.record("tetris", entry("e", 1, 100)), None);
+}
+
+#[test]
+fn same_run_keeps_only_its_best_score() {
+    let mut table = HighScores::default();
+    assert_eq!(table.record("goldminer", entry("a", 50, 7)), Some(1));
+    assert_eq!(table.record("goldminer", entry("a", 120, 7)), Some(1));
+    assert_eq!(table.record("goldminer", entry("a", 80, 7)), Some(1));
+
+    let top = table.top("goldminer");
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].score, 120);
+}
+
+#[test]
+fn save_and_load_round_trip() {
+    let path = temp_path("round_trip.txt");
+    let mut table = HighScores::load_from(&path);
+    table.record(
+        "2048",
+        ScoreEntry {
+            name: "tab\tname".to_string(),
+            score: 2048,
+            timestamp: 1_700_000_000,
+            detail: ScoreDetail::MaxTile(256),
+        },
+    );
+    table.save().unwrap();
+
+    let loaded = HighScores::load_from(&path);
+    let top = loaded.top("2048");
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].name, "tab name");
+    assert_eq!(top[0].score, 2048);
+    assert_eq!(top[0].detail, ScoreDetail::MaxTile(256));
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn corrupt_lines_are_skipped() {
+    let path = temp_path("corrupt.txt");
+    fs::write(
+        &path,
+        "# terminal-games scores v1\nsnake\t40\tbob\t5\tlength 6\ngarbage\nsnake\tNaN\tx\t1\t\n",
+    )
+    .unwrap();
+
+    let loaded = HighScores::load_from(&path);
+    assert_eq!(
+        loaded.top("snake"),
+        &[ScoreEntry {
+            name: "bob".to_string(),
+            score: 40,
+            timestamp: 5,
+            detail: ScoreDetail::Text("length 6".to_string()),
+        }]
+    );
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn details_are_translated_when_shown() {
+    let entries = [ScoreEntry {
+        detail: ScoreDetail::Lines(12),
+        ..entry("a", 100, 1_700_000_000)
+    }];
+    let mut translations = Translations::new();
+    let line = |translations: &Translations| {
+        scores::table_lines(&entries, translations, &Theme::default())[1]
+            .0
+            .iter()
+            .map(|span| span.content.to_string())
+            .collect::<String>()
+    };
+    translations.set_language(Language::English);
+    assert!(line(&translations).contains("12 lines"));
+    // 切换语言后旧的记录也按新的语言显示
+    translations.set_language(Language::Chinese);
+    assert!(line(&translations).contains("12 行"));
+}
+
+#[test]
+fn details_are_stored_as_data() {
+    let path = temp_path("details.txt");
+    let mut table = HighScores::load_from(&path);
+    let details = [
+        ScoreDetail::Lines(3),
+        ScoreDetail::Level(2),
+        ScoreDetail::Seconds(42),
+        ScoreDetail::Text("old text".to_string()),
+        ScoreDetail::None,
+    ];
+    for (index, detail) in details.iter().enumerate() {
+        table.record(
+            "tetris",
+            ScoreEntry {
+                detail: detail.clone(),
+                ..entry("a", 100 - index as i64, index as u64)
+            },
+        );
+    }
+    table.save().unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.starts_with("# terminal-games scores v2\n"));
+    assert!(content.contains("\tlines:3\n"));
+    let loaded = HighScores::load_from(&path);
+    let loaded: Vec<_> = loaded
+        .top("tetris")
+        .iter()
+        .map(|e| e.detail.clone())
+        .collect();
+    assert_eq!(loaded, details);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn dates_are_formatted_in_utc() {
+    assert_eq!(scores::format_date(0), "1970-01-01");
+    assert_eq!(scores::format_date(951_782_400), "2000-02-29");
+    assert_eq!(scores::format_date(1_700_000_000), "2023-11-14");
+}
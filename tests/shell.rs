@@ -0,0 +1,189 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::time::Duration;
+use terminal_games::game_manager::Screen;
+use terminal_games::games::{
+    goldminer::GoldMiner,
+    minesweeper::{Action, Cell, MineSweeper},
+    pause::PauseView,
+    shell::{GameShell, GameState, ShellGame},
+    snake::Snake,
+    tetris::Tetris,
+    twenty_forty_eight::TwentyFortyEight,
+};
+use terminal_games::translation::Language;
+use terminal_games::{Game, GameEnv, GameManager, GameRegistry, GameRequest};
+use tui::{buffer::Buffer, layout::Rect};
+
+fn press<G: ShellGame>(shell: &mut GameShell<G>, code: KeyCode) {
+    shell.handle_input(KeyEvent::from(code));
+}
+
+fn check_pause<G: ShellGame>(mut shell: GameShell<G>) {
+    assert_eq!(shell.state(), GameState::Welcome);
+    press(&mut shell, KeyCode::Char('p'));
+    assert_eq!(shell.state(), GameState::Welcome);
+    press(&mut shell, KeyCode::Enter);
+    assert_eq!(shell.state(), GameState::Playing);
+
+    for key in [KeyCode::Char('p'), KeyCode::Esc] {
+        press(&mut shell, key);
+        assert_eq!(shell.state(), GameState::Paused);
+        // 暂停时其他按键不会传给游戏
+        press(&mut shell, KeyCode::Char(' '));
+        assert_eq!(shell.state(), GameState::Paused);
+        press(&mut shell, key);
+        assert_eq!(shell.state(), GameState::Playing);
+    }
+}
+
+#[test]
+fn every_game_pauses_the_same_way() {
+    check_pause(GameShell::<GoldMiner>::with_seed(1));
+    check_pause(GameShell::<Tetris>::with_seed(1));
+    check_pause(GameShell::<Snake>::with_seed(1));
+    check_pause(GameShell::<TwentyFortyEight>::with_seed(1));
+    check_pause(GameShell::<MineSweeper>::with_seed(1));
+}
+
+#[test]
+fn paused_games_do_not_advance() {
+    let mut shell = GameShell::<Snake>::with_seed(1);
+    press(&mut shell, KeyCode::Enter);
+    let start = shell.game().observe();
+    press(&mut shell, KeyCode::Char('p'));
+    shell.update(Duration::from_secs(10));
+    assert_eq!(shell.game().observe(), start);
+
+    press(&mut shell, KeyCode::Char('p'));
+    shell.update(Duration::from_millis(160));
+    assert_ne!(shell.game().observe(), start);
+}
+
+#[test]
+fn r_restarts_only_after_game_over() {
+    let mut shell = GameShell::<MineSweeper>::with_seed(1);
+    press(&mut shell, KeyCode::Enter);
+    press(&mut shell, KeyCode::Char('f'));
+    press(&mut shell, KeyCode::Char('r'));
+    assert_eq!(shell.game().observe().cells[0][0], Cell::Flagged);
+
+    // 逐个翻开所有格子，最终一定踩雷或获胜
+    for y in 0..10 {
+        for x in 0..10 {
+            shell.game_mut().step(Action::Reveal(x, y));
+        }
+    }
+    assert!(shell.game().is_done());
+
+    // 结束后暂停键不起作用，r 开始新的一局
+    press(&mut shell, KeyCode::Esc);
+    assert_eq!(shell.state(), GameState::Playing);
+    press(&mut shell, KeyCode::Char('r'));
+    assert_eq!(shell.state(), GameState::Playing);
+    assert!(!shell.game().is_done());
+    let cells = shell.game().observe().cells;
+    assert!(cells.iter().flatten().all(|&cell| cell == Cell::Hidden));
+}
+
+#[test]
+fn pause_menu_restarts_and_shows_controls() {
+    let mut shell = GameShell::<MineSweeper>::with_seed(1);
+    press(&mut shell, KeyCode::Enter);
+    press(&mut shell, KeyCode::Char('f'));
+    press(&mut shell, KeyCode::Esc);
+    assert_eq!(shell.pause_menu().view(), PauseView::Menu);
+
+    // 操作说明页按 Esc 回到菜单，再按一次才继续游戏
+    press(&mut shell, KeyCode::Down);
+    press(&mut shell, KeyCode::Down);
+    press(&mut shell, KeyCode::Enter);
+    assert_eq!(shell.pause_menu().view(), PauseView::Controls);
+    press(&mut shell, KeyCode::Esc);
+    assert_eq!(shell.pause_menu().view(), PauseView::Menu);
+    assert_eq!(shell.state(), GameState::Paused);
+
+    press(&mut shell, KeyCode::Up);
+    press(&mut shell, KeyCode::Enter);
+    assert_eq!(shell.state(), GameState::Playing);
+    assert_eq!(shell.game().observe().cells[0][0], Cell::Hidden);
+
+    // 再次暂停时从第一项开始
+    press(&mut shell, KeyCode::Char('p'));
+    assert_eq!(shell.pause_menu().selected(), 0);
+}
+
+#[test]
+fn pause_menu_opens_settings_and_returns_to_the_game() {
+    let mut shell = GameShell::<Snake>::with_seed(1);
+    press(&mut shell, KeyCode::Enter);
+    press(&mut shell, KeyCode::Char('p'));
+    for _ in 0..3 {
+        press(&mut shell, KeyCode::Down);
+    }
+    press(&mut shell, KeyCode::Enter);
+    assert_eq!(shell.take_request(), Some(GameRequest::OpenSettings));
+    assert_eq!(shell.take_request(), None);
+    assert_eq!(shell.state(), GameState::Paused);
+
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    assert!(manager.start_game("snake"));
+    let keys = [
+        KeyCode::Enter,
+        KeyCode::Char('p'),
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Enter,
+    ];
+    for key in keys {
+        manager.handle_input(KeyEvent::from(key));
+    }
+    assert!(manager.state == Screen::Settings);
+    manager.handle_input(KeyEvent::from(KeyCode::Right));
+    assert_eq!(manager.game_titles()[2].1, "贪吃蛇");
+
+    // 关闭设置后回到暂停的游戏，再从暂停菜单返回主菜单
+    manager.handle_input(KeyEvent::from(KeyCode::Esc));
+    assert!(manager.state == Screen::Game(2));
+    for _ in 0..4 {
+        manager.handle_input(KeyEvent::from(KeyCode::Down));
+    }
+    manager.handle_input(KeyEvent::from(KeyCode::Enter));
+    assert!(manager.state == Screen::MainMenu);
+}
+
+#[test]
+fn boss_key_hides_any_screen_and_restores_it() {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    let b = KeyEvent::from(KeyCode::Char('b'));
+
+    // 主菜单中也可以使用，其他按键在假装编译时都被忽略
+    manager.handle_input(b);
+    assert!(manager.is_hiding());
+    manager.handle_input(KeyEvent::from(KeyCode::Char('q')));
+    assert!(!manager.should_quit());
+    manager.handle_input(b);
+    assert!(!manager.is_hiding());
+    assert!(manager.state == Screen::MainMenu);
+
+    assert!(manager.start_game("snake"));
+    manager.handle_input(KeyEvent::from(KeyCode::Enter));
+    let area = Rect::new(0, 0, 80, 32);
+    let mut before = Buffer::empty(area);
+    manager.draw(&mut before, area);
+
+    // 假装编译时游戏不会推进，再按一次回到原来的画面
+    manager.handle_input(b);
+    for _ in 0..100 {
+        manager.update(Duration::from_millis(16));
+    }
+    let mut hidden = Buffer::empty(area);
+    manager.draw(&mut hidden, area);
+    assert_ne!(hidden, before);
+
+    manager.handle_input(b);
+    let mut after = Buffer::empty(area);
+    manager.draw(&mut after, area);
+    assert_eq!(after, before);
+}
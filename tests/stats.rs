@@ -0,0 +1,201 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::{env, fs, path::PathBuf, time::Duration};
+use terminal_games::achievements::{Achievements, ACHIEVEMENTS};
+use terminal_games::games::env::GameEnv;
+use terminal_games::games::minesweeper::{Action, MineSweeper};
+use terminal_games::games::{snake, tetris};
+use terminal_games::stats::{self, Counter, GameEvent, Stats};
+use terminal_games::translation::Language;
+use terminal_games::{Difficulty, GameManager, GameRegistry, ShellGame};
+use tui::{buffer::Buffer, layout::Rect};
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!(
+        "terminal-games-stats-{}-{}",
+        std::process::id(),
+        name
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("stats.txt")
+}
+
+fn press(manager: &mut GameManager, code: KeyCode) {
+    manager.handle_input(KeyEvent::from(code));
+}
+
+fn draw(manager: &mut GameManager) -> String {
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    manager.draw(&mut buf, area);
+    buf.content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+fn manager() -> GameManager {
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_language(Language::English);
+    manager.set_seed(1);
+    manager
+}
+
+#[test]
+fn events_update_counters() {
+    let mut stats = Stats::default();
+    stats.record_event("tetris", GameEvent::Started);
+    stats.record_event("tetris", GameEvent::LinesCleared(2));
+    stats.record_event("tetris", GameEvent::LinesCleared(4));
+    stats.record_event("2048", GameEvent::TileMerged(64));
+    stats.record_event("2048", GameEvent::TileMerged(8));
+    stats.record_event("minesweeper", GameEvent::Won(Difficulty::Normal));
+    stats.record_event("minesweeper", GameEvent::Won(Difficulty::Hard));
+
+    assert_eq!(stats.game("tetris").played, 1);
+    assert_eq!(stats.counter("tetris", Counter::LinesCleared), 6);
+    assert_eq!(stats.counter("tetris", Counter::Tetrises), 1);
+    // 最大方块记录最大值而不是累加
+    assert_eq!(stats.counter("2048", Counter::MaxTile), 64);
+    assert_eq!(stats.counter("minesweeper", Counter::Wins), 2);
+    assert_eq!(stats.counter("minesweeper", Counter::HardWins), 1);
+    assert_eq!(stats.counter("snake", Counter::FoodEaten), 0);
+    assert_eq!(stats.total_played(), 1);
+}
+
+#[test]
+fn stats_round_trip_through_the_file() {
+    let path = temp_file("round-trip");
+    let mut stats = Stats::load_from(&path);
+    stats.record_event("snake", GameEvent::Started);
+    stats.record_event("snake", GameEvent::FoodEaten);
+    stats.add_time("snake", Duration::from_millis(1500));
+    stats.save().unwrap();
+
+    let loaded = Stats::load_from(&path);
+    assert_eq!(loaded.game("snake"), stats.game("snake"));
+
+    // 不认识的行被忽略，其它统计照常读取
+    let content = fs::read_to_string(&path).unwrap() + "snake\tfuture_counter\t3\nbroken\n";
+    fs::write(&path, content).unwrap();
+    assert_eq!(Stats::load_from(&path).game("snake"), stats.game("snake"));
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn durations_are_formatted_compactly() {
+    assert_eq!(stats::format_duration(Duration::from_secs(42)), "42s");
+    assert_eq!(stats::format_duration(Duration::from_secs(185)), "3m 05s");
+    assert_eq!(stats::format_duration(Duration::from_secs(3900)), "1h 05m");
+}
+
+#[test]
+fn minesweeper_reports_the_difficulty_of_the_board() {
+    let mut game = MineSweeper::with_seed(1);
+    let mines: Vec<Vec<bool>> = ShellGame::save_state(&game)
+        .unwrap()
+        .get_grid("mines", 10, 10)
+        .unwrap();
+    let safe: Vec<(usize, usize)> = (0..10)
+        .flat_map(|y| (0..10).map(move |x| (x, y)))
+        .filter(|&(x, y)| !mines[y][x])
+        .collect();
+
+    // 翻开格子之后修改难度，只影响下一局
+    game.step(Action::Reveal(safe[0].0, safe[0].1));
+    ShellGame::set_difficulty(&mut game, Difficulty::Hard);
+    for &(x, y) in &safe {
+        if !game.is_done() {
+            game.step(Action::Reveal(x, y));
+        }
+    }
+    assert!(game.observe().is_win);
+    let events = ShellGame::take_events(&mut game);
+    assert!(events.contains(&GameEvent::Won(Difficulty::Normal)));
+    assert!(!events.contains(&GameEvent::Won(Difficulty::Hard)));
+}
+
+#[test]
+fn achievements_unlock_once() {
+    let mut stats = Stats::default();
+    let mut achievements = Achievements::new(&stats, vec!["tetris"]);
+    assert_eq!(achievements.unlocked_count(), 0);
+
+    stats.record_event("tetris", GameEvent::LinesCleared(4));
+    let unlocked: Vec<_> = achievements.update(&stats).iter().map(|a| a.id).collect();
+    assert_eq!(unlocked, vec!["tetris"]);
+    assert!(achievements.update(&stats).is_empty());
+
+    // 载入时已经满足的成就不会再次提示
+    let mut loaded = Achievements::new(&stats, vec!["tetris"]);
+    assert!(loaded.is_unlocked("tetris"));
+    assert!(loaded.update(&stats).is_empty());
+}
+
+#[test]
+fn playing_every_game_shows_a_toast() {
+    let mut manager = manager();
+    for id in manager.game_ids() {
+        assert!(manager.start_game(id));
+        press(&mut manager, KeyCode::Enter);
+        press(&mut manager, KeyCode::Char('q'));
+    }
+    assert_eq!(manager.stats().total_played(), 5);
+    assert_eq!(manager.current_toast().map(|a| a.id), Some("explorer"));
+    assert!(draw(&mut manager).contains("Achievement unlocked: Explorer"));
+
+    manager.update(Duration::from_secs(3));
+    assert!(manager.current_toast().is_none());
+    assert!(!draw(&mut manager).contains("Achievement unlocked"));
+}
+
+#[test]
+fn explorer_follows_the_registered_games() {
+    let mut registry = GameRegistry::new();
+    registry
+        .register(snake::descriptor())
+        .register(tetris::descriptor());
+    let mut manager = GameManager::with_registry(registry);
+    for id in ["snake", "tetris"] {
+        assert!(manager.start_game(id));
+        press(&mut manager, KeyCode::Enter);
+        press(&mut manager, KeyCode::Char('q'));
+    }
+    assert_eq!(manager.current_toast().map(|a| a.id), Some("explorer"));
+
+    // 没有注册任何游戏时不会直接解锁
+    let empty = GameManager::with_registry(GameRegistry::new());
+    assert!(!empty.achievements().is_unlocked("explorer"));
+}
+
+#[test]
+fn time_in_a_game_is_counted() {
+    let mut manager = manager();
+    assert!(manager.start_game("tetris"));
+    // 欢迎界面和暂停菜单停留的时间不算
+    manager.update(Duration::from_millis(100));
+    press(&mut manager, KeyCode::Enter);
+    manager.update(Duration::from_millis(100));
+    manager.update(Duration::from_millis(100));
+    press(&mut manager, KeyCode::Char('p'));
+    manager.update(Duration::from_millis(100));
+    assert_eq!(
+        manager.stats().game("tetris").time_played,
+        Duration::from_millis(200)
+    );
+}
+
+#[test]
+fn menu_shows_achievements_and_stats() {
+    let mut manager = manager();
+    press(&mut manager, KeyCode::Char('a'));
+    let screen = draw(&mut manager);
+    assert!(screen.contains(&format!("Unlocked: 0/{}", ACHIEVEMENTS.len())));
+    assert!(screen.contains("[ ] Win Expert Minesweeper"));
+    assert!(screen.contains("Games played: 0"));
+
+    // 成就页面打开时数字键不会进入游戏
+    press(&mut manager, KeyCode::Char('1'));
+    assert!(manager.current_game().is_none());
+    press(&mut manager, KeyCode::Esc);
+    assert!(draw(&mut manager).contains("A: Achievements and statistics"));
+}
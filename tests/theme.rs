@@ -0,0 +1,111 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use std::{env, fs, path::PathBuf};
+use terminal_games::config::{Config, ConfigError};
+use terminal_games::games::{snake::Snake, tetris::Tetris};
+use terminal_games::theme::{Theme, ThemeName};
+use terminal_games::{GameManager, GameRegistry, GameShell, ShellGame};
+use tui::{buffer::Buffer, layout::Rect, style::Color};
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!(
+        "terminal-games-theme-{}-{}",
+        std::process::id(),
+        name
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("config.toml")
+}
+
+fn press(manager: &mut GameManager, code: KeyCode) {
+    manager.handle_input(KeyEvent::from(code));
+}
+
+// 开始一局并绘制一帧
+fn render_playing<G: ShellGame>(theme: ThemeName) -> Buffer {
+    let mut shell = GameShell::<G>::with_seed(1);
+    shell.set_theme(&Theme::new(theme));
+    shell.handle_input(KeyEvent::from(KeyCode::Enter));
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    shell.render(&mut buf, area);
+    buf
+}
+
+fn has_color(buf: &Buffer) -> bool {
+    buf.content
+        .iter()
+        .any(|cell| cell.fg != Color::Reset || cell.bg != Color::Reset)
+}
+
+#[test]
+fn theme_names_round_trip_through_the_config() {
+    for name in ThemeName::ALL {
+        assert_eq!(ThemeName::from_id(name.id()), Some(name));
+    }
+
+    let config = Config::parse("theme = \"high-contrast\"\n").unwrap();
+    assert_eq!(config.theme, Some(ThemeName::HighContrast));
+    assert!(config.to_text().contains("theme = \"high-contrast\"\n"));
+    assert_eq!(
+        Config::parse("theme = \"neon\"\n"),
+        Err(vec![ConfigError::InvalidValue {
+            line: 1,
+            key: "theme".to_string(),
+            value: "neon".to_string(),
+            expected: "default, high-contrast, monochrome, solarized",
+        }])
+    );
+}
+
+#[test]
+fn monochrome_theme_draws_no_colors() {
+    assert!(has_color(&render_playing::<Tetris>(ThemeName::Default)));
+    assert!(!has_color(&render_playing::<Tetris>(ThemeName::Monochrome)));
+    assert!(!has_color(&render_playing::<Snake>(ThemeName::Monochrome)));
+
+    let mut manager = GameManager::with_registry(GameRegistry::builtin());
+    manager.set_theme(ThemeName::Monochrome);
+    let area = Rect::new(0, 0, 80, 32);
+    let mut buf = Buffer::empty(area);
+    manager.draw(&mut buf, area);
+    assert!(!has_color(&buf));
+}
+
+#[test]
+fn falling_pieces_and_settled_blocks_use_different_styles() {
+    let theme = Theme::default();
+    let buf = render_playing::<Tetris>(ThemeName::Default);
+    let block_colors: Vec<Color> = buf
+        .content
+        .iter()
+        .filter(|cell| cell.symbol == "█")
+        .map(|cell| cell.fg)
+        .collect();
+    assert!(!block_colors.is_empty());
+    // 刚开始时棋盘上只有正在下落的方块
+    assert!(block_colors.iter().all(|&fg| Some(fg) == theme.player.fg));
+    assert_ne!(theme.player, theme.block);
+}
+
+#[test]
+fn settings_screen_switches_the_theme() {
+    let path = temp_file("settings");
+    let mut manager = GameManager::with_registry(GameRegistry::builtin())
+        .with_config(Config::load_from(&path).unwrap());
+    manager.set_theme(ThemeName::Default);
+
+    // 配色在按键设置的上一行
+    press(&mut manager, KeyCode::Char('s'));
+    for _ in 0..10 {
+        press(&mut manager, KeyCode::Down);
+    }
+    press(&mut manager, KeyCode::Up);
+    press(&mut manager, KeyCode::Right);
+    assert_eq!(manager.theme().name, ThemeName::HighContrast);
+    press(&mut manager, KeyCode::Left);
+    press(&mut manager, KeyCode::Left);
+    assert_eq!(manager.theme().name, ThemeName::Solarized);
+
+    let saved = Config::load_from(&path).unwrap();
+    assert_eq!(saved.theme, Some(ThemeName::Solarized));
+}